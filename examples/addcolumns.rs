@@ -0,0 +1,29 @@
+use prettytable::format::Alignment;
+use prettytable::{row, table, Cell};
+
+/*
+    Following main function will print :
+    +-------+------+-------+-------+
+    | name  | team | score | total |
+    +=======+======+=======+=======+
+    | Alice | red  | 42    |    84 |
+    +-------+------+-------+-------+
+    | Bob   | blue | 37    |    74 |
+    +-------+------+-------+-------+
+*/
+fn main() {
+    let mut table = table!(["Alice", "42"], ["Bob", "37"]);
+    table.set_titles(row!["name", "score"]);
+    table.insert_column(
+        1,
+        Some(Cell::new("team")),
+        vec![Cell::new("red"), Cell::new("blue")],
+    );
+
+    let totals: Vec<Cell> = table
+        .column_iter(2)
+        .map(|c| Cell::new(&(c.get_content().parse::<u32>().unwrap() * 2).to_string()))
+        .collect();
+    table.add_column(Some(Cell::new("total")), totals, Some(Alignment::RIGHT));
+    table.printstd();
+}
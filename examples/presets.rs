@@ -0,0 +1,15 @@
+use prettytable::{format, row, table};
+
+fn main() {
+    let mut table = table!(["a", "bc", "def"], ["def", "bc", "a"]);
+    table.set_titles(row!["t1", "t2", "t3"]);
+    table.set_footer(row!["f1", "f2", "f3"]);
+
+    // Print the same table in every predefined format
+    for (name, format) in format::all_presets() {
+        println!("{} :", name);
+        table.set_format(format);
+        table.printstd();
+        println!();
+    }
+}
@@ -1,55 +1,127 @@
 //! This module contains definition of table/row cells stuff
 
+use super::escape::{Escaper, HtmlEscaper};
 use super::format::Alignment;
-use super::utils::{display_width, print_align, HtmlEscape};
+use super::html::HtmlOptions;
+use super::utils::{display_width, display_width_with, print_align, truncate};
 use super::{color, Attr, Terminal};
+use std::collections::HashMap;
 use std::io::{Error, Write};
 use std::str::FromStr;
 use std::string::ToString;
+use std::sync::{Arc, RwLock};
 
 /// Represent a table cell containing a string.
 ///
 /// Once created, a cell's content cannot be modified.
-/// The cell would have to be replaced by another one
+/// The cell would have to be replaced by another one.
+///
+/// Cells are equal when they have the same content, alignment, style attributes
+/// (in the same order) and settings, like the span or the link
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "CellData")
+)]
 pub struct Cell {
     content: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     width: usize,
-    align: Alignment,
+    /// Width with East Asian ambiguous width characters 2 columns wide
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    wide_width: usize,
+    align: Option<Alignment>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::attrs"))]
     style: Vec<Attr>,
     hspan: usize,
+    max_lines: Option<(usize, String)>,
+    /// The `max_lines` marker formatted with the current number of hidden lines, if any
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    more_lines: Option<String>,
+    shortened: Option<Vec<String>>,
+    link: Option<String>,
 }
 
 impl Cell {
     /// Create a new `Cell` initialized with content from `string`.
     /// Text alignment in cell is configurable with the `align` argument
     pub fn new_align(string: &str, align: Alignment) -> Cell {
-        let content: Vec<String> = string.lines().map(|x| x.to_string()).collect();
-        let mut width = 0;
-        for cont in &content {
-            let l = display_width(&cont[..]);
-            if l > width {
-                width = l;
+        let mut cell = Cell::new(string);
+        cell.align(align);
+        cell
+    }
+
+    /// Recompute the display width of the cell from the lines actually printed
+    fn update_width(&mut self) {
+        self.more_lines = match self.max_lines {
+            Some((max, ref marker)) if max < self.content.len() => {
+                let hidden = self.content.len() - max;
+                Some(marker.replace("{}", &hidden.to_string()))
             }
+            _ => None,
+        };
+        let (mut width, mut wide_width) = (0, 0);
+        for line in self.visible_lines() {
+            width = width.max(display_width(line));
+            wide_width = wide_width.max(display_width_with(line, true));
         }
-        Cell {
-            content,
-            width,
-            align,
-            style: Vec::new(),
-            hspan: 1,
+        if let Some(ref marker) = self.more_lines {
+            width = width.max(display_width(marker));
+            wide_width = wide_width.max(display_width_with(marker, true));
+        }
+        self.width = width;
+        self.wide_width = wide_width;
+    }
+
+    /// Return the content lines which are printed, taking `max_lines` and `max_width` into account
+    fn visible_lines(&self) -> &[String] {
+        let lines = self.shortened.as_ref().unwrap_or(&self.content);
+        match self.max_lines {
+            Some((max, _)) if max < lines.len() => &lines[..max],
+            _ => lines,
         }
     }
 
     /// Create a new `Cell` initialized with content from `string`.
-    /// By default, content is align to `LEFT`
+    /// By default, content is align to `LEFT`, unless the row it belongs to
+    /// defines another alignment
     pub fn new(string: &str) -> Cell {
-        Cell::new_align(string, Alignment::LEFT)
+        let content: Vec<String> = string.lines().map(|x| x.to_string()).collect();
+        let mut cell = Cell {
+            content,
+            width: 0,
+            wide_width: 0,
+            align: None,
+            style: Vec::new(),
+            hspan: 1,
+            max_lines: None,
+            more_lines: None,
+            shortened: None,
+            link: None,
+        };
+        cell.update_width();
+        cell
     }
 
     /// Set text alignment in the cell
     pub fn align(&mut self, align: Alignment) {
-        self.align = align;
+        self.align = Some(align);
+    }
+
+    /// Get text alignment of the cell. Defaults to `LEFT` when not explicitly set
+    pub fn get_alignment(&self) -> Alignment {
+        self.align.unwrap_or(Alignment::LEFT)
+    }
+
+    /// Get text alignment of the cell, or `None` if it inherits the alignment of its row
+    pub(crate) fn get_explicit_alignment(&self) -> Option<Alignment> {
+        self.align
+    }
+
+    /// Get the style attributes of the cell
+    pub fn get_style(&self) -> &[Attr] {
+        &self.style
     }
 
     /// Add a style attribute to the cell
@@ -69,10 +141,108 @@ impl Cell {
         self
     }
 
+    /// Limit the number of printed lines to `max`. When the content has more lines than that,
+    /// the remaining ones are replaced by a single line made of `marker`,
+    /// where `{}` is substituted with the number of hidden lines. Can be chained
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::Cell;
+    /// let trace = (1..=500).map(|i| format!("frame {}", i)).collect::<Vec<_>>().join("\n");
+    /// let cell = Cell::new(&trace).max_lines(5, "… {} more lines");
+    /// ```
+    pub fn max_lines(mut self, max: usize, marker: &str) -> Cell {
+        self.max_lines = Some((max, marker.to_string()));
+        self.update_width();
+        self
+    }
+
+    /// Limit the printed width of each line to `width`. Longer lines are shortened
+    /// and end with an ellipsis. The full content is still returned by `get_content()`. Can be chained
+    pub fn max_width(self, width: usize) -> Cell {
+        self.max_width_with(width, |line, width| truncate(line, width, false))
+    }
+
+    /// Limit the printed width of each line to `width`. Longer lines are replaced
+    /// by the result of `shorten`, called with the full line and `width`,
+    /// instead of being cut with an ellipsis like with `max_width()`.
+    /// It's up to `shorten` to return a string fitting in `width`. Can be chained
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::Cell;
+    /// let commit = "3f2c8a1b9d0e4f5a6b7c8d9e0f1a2b3c4d5e6f7a";
+    /// // Abbreviate commit hashes like git does
+    /// let cell = Cell::new(commit).max_width_with(10, |hash, _| hash[..7].to_owned());
+    /// assert_eq!(cell.get_content(), commit);
+    /// ```
+    pub fn max_width_with<F>(self, width: usize, shorten: F) -> Cell
+    where
+        F: Fn(&str, usize) -> String,
+    {
+        self.shorten_lines(width, false, shorten)
+    }
+
+    /// Replace the lines wider than `width` by the result of `shorten`, ambiguous width
+    /// characters being 2 columns wide if `wide_ambiguous` is `true`
+    pub(crate) fn shorten_lines<F>(mut self, width: usize, wide_ambiguous: bool, shorten: F) -> Cell
+    where
+        F: Fn(&str, usize) -> String,
+    {
+        let too_wide = |l: &String| display_width_with(l, wide_ambiguous) > width;
+        self.shortened = if self.content.iter().any(too_wide) {
+            Some(
+                self.content
+                    .iter()
+                    .map(|l| {
+                        if too_wide(l) {
+                            shorten(l, width)
+                        } else {
+                            l.clone()
+                        }
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        self.update_width();
+        self
+    }
+
+    /// Attach an URL to the cell. Can be chained.
+    ///
+    /// When the cell is part of the table titles, it is printed as a hyperlink
+    /// to terminals supporting OSC 8 escape sequences, by `print_term()` and `print_tty()`.
+    /// It is ignored anywhere else
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::{Cell, Row, Table};
+    /// # fn main() {
+    /// let mut table = table!(["42", "0.5"]);
+    /// table.set_titles(Row::new(vec![
+    ///     Cell::new("requests").with_link("https://example.com/docs#requests"),
+    ///     Cell::new("cpu").with_link("https://example.com/docs#cpu"),
+    /// ]));
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn with_link(mut self, url: &str) -> Cell {
+        self.link = Some(url.to_owned());
+        self
+    }
+
+    /// Get the URL attached to the cell, if any
+    pub fn get_link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
     /// Remove all style attributes and reset alignment to default (LEFT)
     pub fn reset_style(&mut self) {
         self.style.clear();
-        self.align(Alignment::LEFT);
+        self.align = None;
     }
 
     /// Set the cell's style by applying the given specifier string
@@ -93,6 +263,7 @@ impl Cell {
     /// * **c** : Align **c**enter
     /// * **l** : Align **l**eft
     /// * **r** : Align **r**ight
+    /// * **j** : **J**ustify
     /// * **d** : **d**efault style
     ///
     /// ### List of color specifiers :
@@ -112,6 +283,8 @@ impl Cell {
     /// * **R** : Bright Red
     /// * **B** : Bright Blue
     /// * ... and so on ...
+    ///
+    /// Additional specifiers can be defined with [`register_style_spec`](fn.register_style_spec.html)
     pub fn style_spec(mut self, spec: &str) -> Cell {
         self.reset_style();
         let mut foreground = false;
@@ -160,6 +333,7 @@ impl Cell {
                     'c' => self.align(Alignment::CENTER),
                     'l' => self.align(Alignment::LEFT),
                     'r' => self.align(Alignment::RIGHT),
+                    'j' => self.align(Alignment::JUSTIFY),
                     'H' => {
                         let mut span_s = String::new();
                         while let Some('0'..='9') = it.peek() {
@@ -168,7 +342,13 @@ impl Cell {
                         let span = usize::from_str(&span_s).unwrap();
                         self.set_hspan(span);
                     }
-                    _ => { /* Silently ignore unknown tags */ }
+                    _ => {
+                        // Registered custom tags. Silently ignore unknown ones
+                        let handler = CUSTOM_SPECS.read().ok().and_then(|s| s.get(&c).cloned());
+                        if let Some(handler) = handler {
+                            handler(&mut self);
+                        }
+                    }
                 }
             }
         }
@@ -178,7 +358,10 @@ impl Cell {
     /// Return the height of the cell
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
     pub(crate) fn get_height(&self) -> usize {
-        self.content.len()
+        match (&self.max_lines, &self.more_lines) {
+            (Some((max, _)), Some(_)) => max + 1,
+            _ => self.content.len(),
+        }
     }
 
     /// Return the width of the cell
@@ -187,6 +370,16 @@ impl Cell {
         self.width
     }
 
+    /// Return the width of the cell, ambiguous width characters being 2 columns wide
+    /// if `wide_ambiguous` is `true`
+    pub(crate) fn get_width_with(&self, wide_ambiguous: bool) -> usize {
+        if wide_ambiguous {
+            self.wide_width
+        } else {
+            self.get_width()
+        }
+    }
+
     /// Set horizontal span for this cell (must be > 0)
     pub fn set_hspan(&mut self, hspan: usize) {
         self.hspan = if hspan == 0 { 1 } else { hspan };
@@ -197,11 +390,31 @@ impl Cell {
         self.hspan
     }
 
+    /// Replace the content of the cell, keeping its style
+    pub(crate) fn set_content(&mut self, string: &str) {
+        self.content = string.lines().map(|x| x.to_string()).collect();
+        self.shortened = None;
+        self.update_width();
+    }
+
     /// Return a copy of the full string contained in the cell
     pub fn get_content(&self) -> String {
         self.content.join("\n")
     }
 
+    /// Return the line `idx` of the cell as printed, or an empty string if the cell
+    /// is not that high
+    pub(crate) fn get_line(&self, idx: usize) -> &str {
+        match (&self.max_lines, &self.more_lines) {
+            (Some((max, _)), Some(marker)) if idx == *max => marker.as_str(),
+            _ => self
+                .visible_lines()
+                .get(idx)
+                .map(|s| s.as_ref())
+                .unwrap_or(""),
+        }
+    }
+
     /// Print a partial cell to `out`. Since the cell may be multi-lined,
     /// `idx` is the line index to print. `col_width` is the column width used to
     /// fill the cells with blanks so it fits in the table.
@@ -213,12 +426,26 @@ impl Cell {
         idx: usize,
         col_width: usize,
         skip_right_fill: bool,
+        inherited: &Inherited,
     ) -> Result<(), Error> {
-        let c = self.content.get(idx).map(|s| s.as_ref()).unwrap_or("");
-        print_align(out, self.align, c, ' ', col_width, skip_right_fill)
+        let align = match self.align.or(inherited.align).unwrap_or(Alignment::LEFT) {
+            // The last line of a justified cell is not stretched
+            Alignment::JUSTIFY if idx + 1 >= self.visible_lines().len() => Alignment::LEFT,
+            align => align,
+        };
+        print_align(
+            out,
+            align,
+            self.get_line(idx),
+            inherited.fill.unwrap_or(' '),
+            col_width,
+            skip_right_fill,
+            inherited.wide_ambiguous,
+        )
     }
 
-    /// Apply style then call `print` to print the cell into a terminal
+    /// Apply style then call `print` to print the cell into a terminal.
+    /// Inherited style attributes are applied first, so that the cell's own ones take precedence
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
     pub(crate) fn print_term<T: Terminal + ?Sized>(
         &self,
@@ -226,15 +453,23 @@ impl Cell {
         idx: usize,
         col_width: usize,
         skip_right_fill: bool,
+        inherited: &Inherited,
     ) -> Result<(), Error> {
-        for a in &self.style {
+        for a in inherited.style().chain(&self.style) {
             match out.attr(*a) {
                 Ok(..) | Err(::term::Error::NotSupported) | Err(::term::Error::ColorOutOfRange) => {
                 } // Ignore unsupported attributes
                 Err(e) => return Err(term_error_to_io_error(e)),
             };
         }
-        self.print(out, idx, col_width, skip_right_fill)?;
+        match self.link {
+            Some(ref url) if inherited.links => {
+                write!(out, "\x1b]8;;{}\x1b\\", url)?;
+                self.print(out, idx, col_width, skip_right_fill, inherited)?;
+                out.write_all(b"\x1b]8;;\x1b\\")?;
+            }
+            _ => self.print(out, idx, col_width, skip_right_fill, inherited)?,
+        }
         match out.reset() {
             Ok(..) | Err(::term::Error::NotSupported) | Err(::term::Error::ColorOutOfRange) => {
                 Ok(())
@@ -245,31 +480,27 @@ impl Cell {
 
     /// Print the cell in HTML format to `out`.
     pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
-        /// Convert the color to a hex value useful in CSS
-        fn color2hex(color: color::Color) -> &'static str {
-            match color {
-                color::BLACK => "#000000",
-                color::RED => "#aa0000",
-                color::GREEN => "#00aa00",
-                color::YELLOW => "#aa5500",
-                color::BLUE => "#0000aa",
-                color::MAGENTA => "#aa00aa",
-                color::CYAN => "#00aaaa",
-                color::WHITE => "#aaaaaa",
-                color::BRIGHT_BLACK => "#555555",
-                color::BRIGHT_RED => "#ff5555",
-                color::BRIGHT_GREEN => "#55ff55",
-                color::BRIGHT_YELLOW => "#ffff55",
-                color::BRIGHT_BLUE => "#5555ff",
-                color::BRIGHT_MAGENTA => "#ff55ff",
-                color::BRIGHT_CYAN => "#55ffff",
-                color::BRIGHT_WHITE => "#ffffff",
-
-                // Unknown colors, fallback to blakc
-                _ => "#000000",
-            }
-        }
+        self.print_html_with_escaper(out, &HtmlEscaper)
+    }
+
+    /// Print the cell in HTML format to `out`, escaping its content with `escaper`.
+    pub fn print_html_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+    ) -> Result<usize, Error> {
+        self.print_html_inherited(out, escaper, &Inherited::default(), &HtmlOptions::new())
+    }
 
+    /// Print the cell in HTML format to `out`, taking into account the style inherited from its row
+    /// and the HTML `options`
+    pub(crate) fn print_html_inherited<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+        inherited: &Inherited,
+        options: &HtmlOptions,
+    ) -> Result<usize, Error> {
         let colspan = if self.hspan > 1 {
             format!(" colspan=\"{}\"", self.hspan)
         } else {
@@ -278,7 +509,11 @@ impl Cell {
 
         // Process style properties like color
         let mut styles = String::new();
-        for style in &self.style {
+        let attrs = inherited
+            .style()
+            .chain(&self.style)
+            .filter(|_| options.has_inline_styles());
+        for style in attrs {
             match style {
                 Attr::Bold => styles += "font-weight: bold;",
                 Attr::Italic(true) => styles += "font-style: italic;",
@@ -297,17 +532,27 @@ impl Cell {
             }
         }
         // Process alignment
-        match self.align {
+        match self.align.or(inherited.align).unwrap_or(Alignment::LEFT) {
             Alignment::LEFT => styles += "text-align: left;",
             Alignment::CENTER => styles += "text-align: center;",
             Alignment::RIGHT => styles += "text-align: right;",
+            Alignment::JUSTIFY => styles += "text-align: justify;",
         }
 
-        let content = self.content.join("<br />");
+        let mut lines: Vec<_> = self
+            .visible_lines()
+            .iter()
+            .map(|l| escaper.escape(l))
+            .collect();
+        if let Some(ref marker) = self.more_lines {
+            lines.push(escaper.escape(marker));
+        }
+        let content = lines.join("<br />");
         out.write_all(
             format!(
-                "<td{1} style=\"{2}\">{0}</td>",
-                HtmlEscape(&content),
+                "<td{1}{2} style=\"{3}\">{0}</td>",
+                content,
+                options.cell_attr(),
                 colspan,
                 styles
             )
@@ -317,19 +562,109 @@ impl Cell {
     }
 }
 
-fn term_error_to_io_error(te: ::term::Error) -> Error {
+type SpecHandler = Arc<dyn Fn(&mut Cell) + Send + Sync>;
+
+lazy_static! {
+    /// Style specifiers registered by the application
+    static ref CUSTOM_SPECS: RwLock<HashMap<char, SpecHandler>> = RwLock::new(HashMap::new());
+}
+
+/// Register a custom style specifier letter, applied by `handler` to the cell being styled.
+/// It is consulted by [`Cell::style_spec`](struct.Cell.html#method.style_spec) and the macros
+/// supporting style specifiers, for letters which are not already built-in specifiers.
+/// Registering an already registered letter replaces its handler.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// use prettytable::{color, register_style_spec, Attr};
+/// # fn main() {
+/// // Brand color
+/// register_style_spec('k', |cell| cell.style(Attr::ForegroundColor(color::MAGENTA)));
+/// let cell = cell!(kb->"value");
+/// # drop(cell);
+/// # }
+/// ```
+pub fn register_style_spec<F>(spec: char, handler: F)
+where
+    F: Fn(&mut Cell) + Send + Sync + 'static,
+{
+    if let Ok(mut specs) = CUSTOM_SPECS.write() {
+        specs.insert(spec, Arc::new(handler));
+    }
+}
+
+/// Unregister a custom style specifier letter previously registered with
+/// [`register_style_spec`](fn.register_style_spec.html)
+pub fn unregister_style_spec(spec: char) {
+    if let Ok(mut specs) = CUSTOM_SPECS.write() {
+        specs.remove(&spec);
+    }
+}
+
+/// Convert the color to a hex value useful in CSS
+pub(crate) fn color2hex(color: color::Color) -> &'static str {
+    match color {
+        color::BLACK => "#000000",
+        color::RED => "#aa0000",
+        color::GREEN => "#00aa00",
+        color::YELLOW => "#aa5500",
+        color::BLUE => "#0000aa",
+        color::MAGENTA => "#aa00aa",
+        color::CYAN => "#00aaaa",
+        color::WHITE => "#aaaaaa",
+        color::BRIGHT_BLACK => "#555555",
+        color::BRIGHT_RED => "#ff5555",
+        color::BRIGHT_GREEN => "#55ff55",
+        color::BRIGHT_YELLOW => "#ffff55",
+        color::BRIGHT_BLUE => "#5555ff",
+        color::BRIGHT_MAGENTA => "#ff55ff",
+        color::BRIGHT_CYAN => "#55ffff",
+        color::BRIGHT_WHITE => "#ffffff",
+
+        // Unknown colors, fallback to blakc
+        _ => "#000000",
+    }
+}
+
+/// Alignment and style attributes inherited by a cell from the table, column and row it belongs to
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Inherited<'a> {
+    /// Alignment used if the cell does not define its own one
+    pub(crate) align: Option<Alignment>,
+    /// Default style attributes of the table, the column and the row,
+    /// applied in this order before the cell's own ones
+    pub(crate) style: [&'a [Attr]; 3],
+    /// Whether the cell's link must be printed as a hyperlink
+    pub(crate) links: bool,
+    /// Whether ambiguous width characters are 2 columns wide, as given by the table format
+    pub(crate) wide_ambiguous: bool,
+    /// Character filling the cell, as given by the table format, or a space if not set
+    pub(crate) fill: Option<char>,
+}
+
+impl<'a> Inherited<'a> {
+    /// Iterate over the inherited style attributes, in the order they must be applied
+    pub(crate) fn style(&self) -> impl Iterator<Item = &'a Attr> {
+        IntoIterator::into_iter(self.style).flatten()
+    }
+}
+
+pub(crate) fn term_error_to_io_error(te: ::term::Error) -> Error {
     match te {
         ::term::Error::Io(why) => why,
         _ => Error::new(::std::io::ErrorKind::Other, te),
     }
 }
 
-impl<'a, T: ToString> From<&'a T> for Cell {
+impl<T: ToString> From<&T> for Cell {
     fn from(f: &T) -> Cell {
         Cell::new(&f.to_string())
     }
 }
 
+// Cells are not `Display`, which would be a public API change
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Cell {
     fn to_string(&self) -> String {
         self.get_content()
@@ -342,9 +677,14 @@ impl Default for Cell {
         Cell {
             content: vec!["".to_string(); 1],
             width: 0,
-            align: Alignment::LEFT,
+            wide_width: 0,
+            align: None,
             style: Vec::new(),
             hspan: 1,
+            max_lines: None,
+            more_lines: None,
+            shortened: None,
+            link: None,
         }
     }
 }
@@ -389,11 +729,47 @@ macro_rules! cell {
     };
 }
 
+/// Serialized fields of a `Cell`, from which the display width is computed again
+#[cfg(feature = "serde")]
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct CellData {
+    content: Vec<String>,
+    align: Option<Alignment>,
+    #[serde(with = "crate::serialize::attrs")]
+    style: Vec<Attr>,
+    hspan: usize,
+    max_lines: Option<(usize, String)>,
+    shortened: Option<Vec<String>>,
+    link: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<CellData> for Cell {
+    fn from(data: CellData) -> Cell {
+        let mut cell = Cell {
+            content: data.content,
+            width: 0,
+            wide_width: 0,
+            align: data.align,
+            style: data.style,
+            hspan: data.hspan.max(1),
+            max_lines: data.max_lines,
+            more_lines: None,
+            shortened: data.shortened,
+            link: data.link,
+        };
+        cell.update_width();
+        cell
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Cell;
+    use super::{Cell, Inherited};
     use crate::format::Alignment;
     use crate::utils::StringWriter;
+    use std::io::Write;
     use term::{color, Attr};
 
     #[test]
@@ -408,7 +784,7 @@ mod tests {
         assert_eq!(ascii_cell.get_width(), 5);
 
         let mut out = StringWriter::new();
-        let _ = ascii_cell.print(&mut out, 0, 10, false);
+        let _ = ascii_cell.print(&mut out, 0, 10, false, &Inherited::default());
         assert_eq!(out.as_string(), "hello     ");
     }
 
@@ -418,7 +794,7 @@ mod tests {
         assert_eq!(unicode_cell.get_width(), 6);
 
         let mut out = StringWriter::new();
-        let _ = unicode_cell.print(&mut out, 0, 10, false);
+        let _ = unicode_cell.print(&mut out, 0, 10, false, &Inherited::default());
         assert_eq!(out.as_string(), "привет    ");
     }
 
@@ -427,7 +803,7 @@ mod tests {
         let unicode_cell = Cell::new("由系统自动更新");
         assert_eq!(unicode_cell.get_width(), 14);
         let mut out = StringWriter::new();
-        let _ = unicode_cell.print(&mut out, 0, 20, false);
+        let _ = unicode_cell.print(&mut out, 0, 20, false, &Inherited::default());
         assert_eq!(out.as_string(), "由系统自动更新      ");
     }
 
@@ -456,11 +832,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn print_html_multiline_custom_escaper() {
+        let cell = Cell::new("a<b\nc");
+        let mut out = StringWriter::new();
+        let _ = cell.print_html(&mut out);
+        assert_eq!(
+            out.as_string(),
+            r#"<td style="text-align: left;">a&lt;b<br />c</td>"#
+        );
+
+        let mut out = StringWriter::new();
+        let _ = cell.print_html_with_escaper(&mut out, &|s: &str| s.replace('<', "[lt]"));
+        assert_eq!(
+            out.as_string(),
+            r#"<td style="text-align: left;">a[lt]b<br />c</td>"#
+        );
+    }
+
     #[test]
     fn align_left() {
         let cell = Cell::new_align("test", Alignment::LEFT);
         let mut out = StringWriter::new();
-        let _ = cell.print(&mut out, 0, 10, false);
+        let _ = cell.print(&mut out, 0, 10, false, &Inherited::default());
         assert_eq!(out.as_string(), "test      ");
     }
 
@@ -468,7 +862,7 @@ mod tests {
     fn align_center() {
         let cell = Cell::new_align("test", Alignment::CENTER);
         let mut out = StringWriter::new();
-        let _ = cell.print(&mut out, 0, 10, false);
+        let _ = cell.print(&mut out, 0, 10, false, &Inherited::default());
         assert_eq!(out.as_string(), "   test   ");
     }
 
@@ -476,7 +870,7 @@ mod tests {
     fn align_right() {
         let cell = Cell::new_align("test", Alignment::RIGHT);
         let mut out = StringWriter::new();
-        let _ = cell.print(&mut out, 0, 10, false);
+        let _ = cell.print(&mut out, 0, 10, false, &Inherited::default());
         assert_eq!(out.as_string(), "      test");
     }
 
@@ -491,7 +885,7 @@ mod tests {
         assert!(cell
             .style
             .contains(&Attr::BackgroundColor(color::BRIGHT_BLUE)));
-        assert_eq!(cell.align, Alignment::CENTER);
+        assert_eq!(cell.get_alignment(), Alignment::CENTER);
 
         cell = cell.style_spec("FDBwr");
         assert_eq!(cell.style.len(), 2);
@@ -499,7 +893,7 @@ mod tests {
             .style
             .contains(&Attr::ForegroundColor(color::BRIGHT_BLACK)));
         assert!(cell.style.contains(&Attr::BackgroundColor(color::WHITE)));
-        assert_eq!(cell.align, Alignment::RIGHT);
+        assert_eq!(cell.get_alignment(), Alignment::RIGHT);
 
         // Test with invalid sepcifier chars
         cell = cell.clone();
@@ -513,6 +907,23 @@ mod tests {
         assert_eq!(cell.get_hspan(), 3);
     }
 
+    #[test]
+    fn custom_style_spec() {
+        super::register_style_spec('k', |c| c.style(Attr::ForegroundColor(color::MAGENTA)));
+        super::register_style_spec('x', |c| *c = Cell::new(&c.get_content().to_uppercase()));
+        let cell = Cell::new("test").style_spec("xkbr");
+        assert_eq!(cell.get_content(), "TEST");
+        assert_eq!(cell.style.len(), 2);
+        assert!(cell.style.contains(&Attr::ForegroundColor(color::MAGENTA)));
+        // Built-in specifiers can't be overridden
+        super::register_style_spec('b', |c| c.style(Attr::Blink));
+        assert_eq!(Cell::new("test").style_spec("b").style, vec![Attr::Bold]);
+        super::unregister_style_spec('k');
+        super::unregister_style_spec('x');
+        super::unregister_style_spec('b');
+        assert!(Cell::new("test").style_spec("k").style.is_empty());
+    }
+
     #[test]
     fn reset_style() {
         let mut cell = Cell::new("test")
@@ -522,16 +933,69 @@ mod tests {
 
         //style_spec("FDBwr");
         assert_eq!(cell.style.len(), 2);
-        assert_eq!(cell.align, Alignment::RIGHT);
+        assert_eq!(cell.get_alignment(), Alignment::RIGHT);
         cell.reset_style();
         assert_eq!(cell.style.len(), 0);
-        assert_eq!(cell.align, Alignment::LEFT);
+        assert_eq!(cell.get_alignment(), Alignment::LEFT);
+    }
+
+    #[test]
+    fn max_lines() {
+        let cell = Cell::new("a\nb\nc\nd\ne").max_lines(2, "… {} more lines");
+        assert_eq!(cell.get_height(), 3);
+        assert_eq!(cell.get_width(), 14);
+        assert_eq!(cell.get_content(), "a\nb\nc\nd\ne");
+        let mut out = StringWriter::new();
+        for i in 0..cell.get_height() {
+            let _ = cell.print(&mut out, i, 14, true, &Inherited::default());
+            out.write_all(b"|").unwrap();
+        }
+        assert_eq!(out.as_string(), "a|b|… 3 more lines|");
+
+        let cell = Cell::new("a\nb").max_lines(2, "… {} more lines");
+        assert_eq!(cell.get_height(), 2);
+        assert_eq!(cell.get_width(), 1);
+
+        // The marker follows the content when it is replaced, e.g. by wrapping
+        let mut cell = Cell::new("a\nb").max_lines(2, "… {} more lines");
+        cell.set_content("a\nb\nc\nd");
+        assert_eq!(cell.get_height(), 3);
+        assert_eq!(cell.get_line(2), "… 2 more lines");
+        cell.set_content("a");
+        assert_eq!(cell.get_height(), 1);
+        assert_eq!(cell.get_width(), 1);
+    }
+
+    #[test]
+    fn max_width() {
+        let cell = Cell::new("abcdef\nab").max_width(4);
+        assert_eq!(cell.get_width(), 4);
+        assert_eq!(cell.get_content(), "abcdef\nab");
+        let mut out = StringWriter::new();
+        for i in 0..cell.get_height() {
+            let _ = cell.print(&mut out, i, 4, true, &Inherited::default());
+            out.write_all(b"|").unwrap();
+        }
+        assert_eq!(out.as_string(), "abc…|ab|");
+
+        let cell = Cell::new("src/very_long_name.rs").max_width_with(10, |full, width| {
+            let ext = &full[full.rfind('.').unwrap()..];
+            let keep = width - ext.len() - 1;
+            format!("{}…{}", &full[..keep], ext)
+        });
+        assert_eq!(cell.get_width(), 10);
+        let mut out = StringWriter::new();
+        let _ = cell.print(&mut out, 0, 10, true, &Inherited::default());
+        assert_eq!(out.as_string(), "src/ve….rs");
+
+        let cell = Cell::new("short").max_width_with(10, |_, _| unreachable!());
+        assert_eq!(cell.get_width(), 5);
     }
 
     #[test]
     fn default_empty_cell() {
         let cell = Cell::default();
-        assert_eq!(cell.align, Alignment::LEFT);
+        assert_eq!(cell.get_alignment(), Alignment::LEFT);
         assert!(cell.style.is_empty());
         assert_eq!(cell.get_content(), "");
         assert_eq!(cell.to_string(), "");
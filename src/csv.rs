@@ -3,86 +3,169 @@
 use csv;
 
 pub use self::csv::{Reader, ReaderBuilder, Result, Writer};
-use crate::AsTableSlice;
+use crate::escape::Escaper;
+use crate::{AsTableSlice, Error};
 use std::io::{Read, Write};
 use std::path::Path;
 
+/// Write the content of the cells in `row` as a CSV record, each field escaped with `escaper`
+fn write_record<W: Write + ?Sized, E: Escaper + ?Sized>(
+    out: &mut W,
+    row: &super::Row,
+    escaper: &E,
+) -> std::io::Result<()> {
+    for (i, cell) in row.iter().enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        out.write_all(escaper.escape(&cell.get_content()).as_bytes())?;
+    }
+    out.write_all(b"\n")
+}
+
 impl<'a> super::TableSlice<'a> {
     /// Write the table to the specified writer.
-    pub fn to_csv<W: Write>(&self, w: W) -> Result<Writer<W>> {
+    pub fn to_csv<W: Write>(&self, w: W) -> std::result::Result<Writer<W>, Error> {
         self.to_csv_writer(Writer::from_writer(w))
     }
 
     /// Write the table to the specified writer.
     ///
     /// This allows for format customisation.
-    pub fn to_csv_writer<W: Write>(&self, mut writer: Writer<W>) -> Result<Writer<W>> {
-        for title in self.titles {
+    pub fn to_csv_writer<W: Write>(
+        &self,
+        mut writer: Writer<W>,
+    ) -> std::result::Result<Writer<W>, Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).to_csv_writer(writer);
+        }
+        if let Some(title) = self.titles {
             writer.write_record(title.iter().map(|c| c.get_content()))?;
         }
-        for row in self.rows {
+        for row in self.exported_rows() {
             writer.write_record(row.iter().map(|c| c.get_content()))?;
         }
+        if let Some(footer) = self.footer {
+            writer.write_record(footer.iter().map(|c| c.get_content()))?;
+        }
 
         writer.flush()?;
         Ok(writer)
     }
+
+    /// Write the table to the specified writer, each field being escaped with `escaper`
+    /// instead of being quoted by a CSV `Writer`. Fields are separated by commas,
+    /// and records end with `\n`. With [`CsvEscaper`](escape/struct.CsvEscaper.html),
+    /// the output is the same as with `to_csv()`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// use prettytable::escape::{CsvEscaper, Escaper};
+    /// # fn main() {
+    /// let table = table!(["=1+1", "a,b"]);
+    /// // Prevent formula injection in spreadsheets
+    /// let escaper = |s: &str| match s.strip_prefix('=') {
+    ///     Some(formula) => format!("'={}", formula),
+    ///     None => CsvEscaper.escape(s).into_owned(),
+    /// };
+    /// let out = table.to_csv_with_escaper(Vec::new(), &escaper).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "'=1+1,\"a,b\"\n");
+    /// # }
+    /// ```
+    pub fn to_csv_with_escaper<W: Write, E: Escaper + ?Sized>(
+        &self,
+        mut w: W,
+        escaper: &E,
+    ) -> std::result::Result<W, Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).to_csv_with_escaper(w, escaper);
+        }
+        if let Some(title) = self.titles {
+            write_record(&mut w, title, escaper)?;
+        }
+        for row in self.exported_rows() {
+            write_record(&mut w, &row, escaper)?;
+        }
+        if let Some(footer) = self.footer {
+            write_record(&mut w, footer, escaper)?;
+        }
+        w.flush()?;
+        Ok(w)
+    }
 }
 
 impl super::Table {
     /// Create a table from a CSV string
     ///
-    /// For more customisability use `from_csv()`
-    pub fn from_csv_string(csv_s: &str) -> Result<Self> {
-        Ok(Self::from_csv(
+    /// For more customisability use `try_from_csv()`
+    pub fn from_csv_string(csv_s: &str) -> std::result::Result<Self, Error> {
+        Self::try_from_csv(
             &mut ReaderBuilder::new()
                 .has_headers(false)
                 .from_reader(csv_s.as_bytes()),
-        ))
+        )
     }
 
     /// Create a table from a CSV file
     ///
-    /// For more customisability use `from_csv()`
-    pub fn from_csv_file<P: AsRef<Path>>(csv_p: P) -> Result<Self> {
-        Ok(Self::from_csv(
-            &mut ReaderBuilder::new().has_headers(false).from_path(csv_p)?,
-        ))
+    /// For more customisability use `try_from_csv()`
+    pub fn from_csv_file<P: AsRef<Path>>(csv_p: P) -> std::result::Result<Self, Error> {
+        Self::try_from_csv(&mut ReaderBuilder::new().has_headers(false).from_path(csv_p)?)
     }
 
     /// Create a table from a CSV reader
+    ///
+    /// # Panics
+    /// Panics if a record can't be read. Use `try_from_csv()` to handle such errors
     pub fn from_csv<R: Read>(reader: &mut Reader<R>) -> Self {
-        Self::init(
-            reader
-                .records()
-                .map(|row| {
-                    super::Row::new(
-                        row.unwrap()
-                            .into_iter()
-                            .map(super::Cell::new)
-                            .collect(),
-                    )
-                })
-                .collect(),
-        )
+        Self::try_from_csv(reader).unwrap()
+    }
+
+    /// Create a table from a CSV reader, failing if a record can't be read
+    pub fn try_from_csv<R: Read>(reader: &mut Reader<R>) -> std::result::Result<Self, Error> {
+        let rows = reader
+            .records()
+            .map(|row| {
+                Ok(super::Row::new(
+                    row?.into_iter().map(super::Cell::new).collect(),
+                ))
+            })
+            .collect::<std::result::Result<Vec<_>, Error>>()?;
+        Ok(Self::init(rows))
     }
 
     /// Write the table to the specified writer.
-    pub fn to_csv<W: Write>(&self, w: W) -> Result<Writer<W>> {
+    pub fn to_csv<W: Write>(&self, w: W) -> std::result::Result<Writer<W>, Error> {
         self.as_slice().to_csv(w)
     }
 
     /// Write the table to the specified writer.
     ///
     /// This allows for format customisation.
-    pub fn to_csv_writer<W: Write>(&self, writer: Writer<W>) -> Result<Writer<W>> {
+    pub fn to_csv_writer<W: Write>(
+        &self,
+        writer: Writer<W>,
+    ) -> std::result::Result<Writer<W>, Error> {
         self.as_slice().to_csv_writer(writer)
     }
+
+    /// Write the table to the specified writer, each field being escaped with `escaper`.
+    /// See [`TableSlice::to_csv_with_escaper`](struct.TableSlice.html#method.to_csv_with_escaper)
+    /// for details
+    pub fn to_csv_with_escaper<W: Write, E: Escaper + ?Sized>(
+        &self,
+        w: W,
+        escaper: &E,
+    ) -> std::result::Result<W, Error> {
+        self.as_slice().to_csv_with_escaper(w, escaper)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Cell, Row, Table};
+    use crate::escape::CsvEscaper;
+    use crate::{row, table, Aggregate, Cell, Row, Slice, Table};
 
     static CSV_S: &str = "ABC,DEFG,HIJKLMN\n\
                         foobar,bar,foo\n\
@@ -174,4 +257,79 @@ mod tests {
             "DEFG"
         );
     }
+
+    #[test]
+    fn hidden_columns() {
+        let mut table = Table::new();
+        table.set_titles(Row::new(vec![Cell::new("a"), Cell::new("b")]));
+        table.add_row(Row::new(vec![Cell::new("1"), Cell::new("2")]));
+        table.set_column_hidden(0, true);
+        assert_eq!(
+            String::from_utf8(table.to_csv(Vec::new()).unwrap().into_inner().unwrap()).unwrap(),
+            "b\n2\n"
+        );
+    }
+
+    #[test]
+    fn with_escaper() {
+        let mut table = table!(["a,b", "say \"hi\""], ["c\nd", "e"]);
+        table.set_titles(row!["name", "n"]);
+        table.set_footer(row!["f", ""]);
+        let csv = table.to_csv(Vec::new()).unwrap().into_inner().unwrap();
+        let escaped = table.to_csv_with_escaper(Vec::new(), &CsvEscaper).unwrap();
+        assert_eq!(
+            String::from_utf8(escaped).unwrap(),
+            String::from_utf8(csv).unwrap()
+        );
+
+        let upper = |s: &str| s.to_uppercase();
+        let escaped = table
+            .slice(1..)
+            .to_csv_with_escaper(Vec::new(), &upper)
+            .unwrap();
+        assert_eq!(String::from_utf8(escaped).unwrap(), "NAME,N\nC\nD,E\nF,\n");
+    }
+
+    #[test]
+    fn hide_empty_columns() {
+        let mut table = table!(["a", "", "-", ""], ["b", "", "", "x"]);
+        table.set_titles(row!["name", "opt1", "opt2", "opt3"]);
+        table.set_hide_empty_columns(true);
+        table.set_empty_placeholder("-");
+        let mut csv = Vec::new();
+        table.to_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "name,opt3\na,\nb,x\n");
+    }
+
+    #[test]
+    fn slice_columns() {
+        let mut table = table!(["a", "1", "x", "10"], ["b", "2", "y", "20"]);
+        table.set_titles(row!["name", "n", "c", "m"]);
+        table.set_aggregate_footer(&[Aggregate::Sum(1)]);
+        let mut csv = Vec::new();
+        table
+            .slice_columns(..=1)
+            .slice_columns(1..3)
+            .to_csv(&mut csv)
+            .unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "n\n1\n2\n3\n");
+    }
+
+    #[test]
+    fn row_numbers() {
+        let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+        table.set_titles(row!["name", "n"]);
+        table[1].set_hidden(true);
+        table.set_aggregate_footer(&[Aggregate::Sum(1)]);
+        table.set_row_numbers(true);
+        let mut csv = Vec::new();
+        table.to_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "name,n\na,1\nc,3\n,4\n");
+    }
+
+    #[test]
+    fn invalid_csv() {
+        let err = Table::from_csv_string("a,b\nc\n").unwrap_err();
+        assert!(matches!(err, crate::Error::Csv(_)));
+    }
 }
@@ -0,0 +1,99 @@
+//! This module contains the error type of the crate
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Error returned by the fallible operations on tables.
+///
+/// Operations only writing to an output, like `Table::print()`, return an `io::Error` instead,
+/// to be easily used along with other writes
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A row or a cell was accessed at a position which doesn't exist
+    OutOfBounds {
+        /// The position accessed
+        index: usize,
+        /// The number of elements at the time of the access
+        len: usize,
+    },
+    /// An I/O error
+    Io(io::Error),
+    /// A CSV reading or writing error
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    /// An invalid format or style specification
+    Format(String),
+    /// A rendered table would exceed the maximum size allowed, in bytes
+    TooLarge {
+        /// The maximum size, in bytes
+        max_size: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::OutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds, the length is {}", index, len)
+            }
+            Error::Io(ref e) => write!(f, "I/O error: {}", e),
+            #[cfg(feature = "csv")]
+            Error::Csv(ref e) => write!(f, "CSV error: {}", e),
+            Error::Format(ref spec) => write!(f, "invalid specification: {}", spec),
+            Error::TooLarge { max_size } => {
+                write!(
+                    f,
+                    "rendered table exceeds the maximum size of {} bytes",
+                    max_size
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(ref e) => Some(e),
+            #[cfg(feature = "csv")]
+            Error::Csv(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(e: csv::Error) -> Error {
+        Error::Csv(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use std::error::Error as _;
+    use std::io;
+
+    #[test]
+    fn display() {
+        let e = Error::OutOfBounds { index: 3, len: 2 };
+        assert_eq!(e.to_string(), "index 3 is out of bounds, the length is 2");
+        assert!(e.source().is_none());
+        let e = Error::from(io::Error::new(io::ErrorKind::Other, "disk full"));
+        assert_eq!(e.to_string(), "I/O error: disk full");
+        assert!(e.source().is_some());
+        let e = Error::TooLarge { max_size: 10 };
+        assert_eq!(
+            e.to_string(),
+            "rendered table exceeds the maximum size of 10 bytes"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Escaping strategies used when exporting tables to other formats
+//!
+//! Every exporter escapes cell content through the [`Escaper`](trait.Escaper.html) trait,
+//! using the matching escaper from this module by default.
+//! The HTML, Markdown, LaTeX and CSV exporters also accept a user provided one through
+//! their `*_with_escaper` variants, so custom rules can be plugged without rewriting the exporter.
+//! The JSON exporter always uses `JsonEscaper`, since other rules could produce invalid JSON.
+use std::borrow::Cow;
+
+/// Escape text content before it is written by an exporter
+pub trait Escaper {
+    /// Return the escaped version of `text`
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str>;
+}
+
+impl<F> Escaper for F
+where
+    F: Fn(&str) -> String,
+{
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self(text))
+    }
+}
+
+/// Replace characters for which `f` returns a substitute.
+/// The input is borrowed as is if nothing needs to be replaced
+fn replace_chars<F>(text: &str, f: F) -> Cow<'_, str>
+where
+    F: Fn(char) -> Option<&'static str>,
+{
+    let mut out = String::new();
+    let mut last = 0;
+    for (i, ch) in text.char_indices() {
+        if let Some(s) = f(ch) {
+            out.push_str(&text[last..i]);
+            out.push_str(s);
+            last = i + ch.len_utf8();
+        }
+    }
+    if last == 0 {
+        return Cow::Borrowed(text);
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// Do not escape anything
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoEscaper;
+
+impl Escaper for NoEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Escape HTML special characters `<`, `>`, `&`, `'` and `"` into entities
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HtmlEscaper;
+
+impl Escaper for HtmlEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        // Because the internet is always right, turns out there's not that many
+        // characters to escape: http://stackoverflow.com/questions/7381974
+        replace_chars(text, |c| match c {
+            '>' => Some("&gt;"),
+            '<' => Some("&lt;"),
+            '&' => Some("&amp;"),
+            '\'' => Some("&#39;"),
+            '"' => Some("&quot;"),
+            _ => None,
+        })
+    }
+}
+
+/// Escape characters which would break a markdown table cell, ie. `|` and `\`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MarkdownEscaper;
+
+impl Escaper for MarkdownEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        replace_chars(text, |c| match c {
+            '\\' => Some("\\\\"),
+            '|' => Some("\\|"),
+            _ => None,
+        })
+    }
+}
+
+/// Escape LaTeX special characters `& % $ # _ { } ~ ^ \`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatexEscaper;
+
+impl Escaper for LatexEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        replace_chars(text, |c| match c {
+            '&' => Some("\\&"),
+            '%' => Some("\\%"),
+            '$' => Some("\\$"),
+            '#' => Some("\\#"),
+            '_' => Some("\\_"),
+            '{' => Some("\\{"),
+            '}' => Some("\\}"),
+            '~' => Some("\\textasciitilde{}"),
+            '^' => Some("\\textasciicircum{}"),
+            '\\' => Some("\\textbackslash{}"),
+            _ => None,
+        })
+    }
+}
+
+/// Quote a CSV field if it contains a comma, a double quote or a line break.
+/// Double quotes inside the field are doubled, like `to_csv()` does
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CsvEscaper;
+
+impl Escaper for CsvEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.contains(&[',', '"', '\r', '\n'][..]) {
+            return Cow::Borrowed(text);
+        }
+        Cow::Owned(format!("\"{}\"", text.replace('"', "\"\"")))
+    }
+}
+
+/// Escape a JSON string content : `"`, `\` and control characters.
+/// Surrounding quotes are not added
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonEscaper;
+
+impl Escaper for JsonEscaper {
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text
+            .chars()
+            .any(|c| c == '"' || c == '\\' || (c as u32) < 0x20)
+        {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len() + 2);
+        for c in text.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html() {
+        assert_eq!(
+            HtmlEscaper.escape("<abc\">&'"),
+            "&lt;abc&quot;&gt;&amp;&#39;"
+        );
+        assert!(matches!(HtmlEscaper.escape("abc"), Cow::Borrowed("abc")));
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(MarkdownEscaper.escape("a|b\\c"), "a\\|b\\\\c");
+    }
+
+    #[test]
+    fn latex() {
+        assert_eq!(
+            LatexEscaper.escape("50% of $x_1 & {y}"),
+            "50\\% of \\$x\\_1 \\& \\{y\\}"
+        );
+        assert_eq!(
+            LatexEscaper.escape("~^\\"),
+            "\\textasciitilde{}\\textasciicircum{}\\textbackslash{}"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(CsvEscaper.escape("abc"), "abc");
+        assert_eq!(CsvEscaper.escape("a,b"), "\"a,b\"");
+        assert_eq!(CsvEscaper.escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(JsonEscaper.escape("abc"), "abc");
+        assert_eq!(
+            JsonEscaper.escape("a\"b\\c\nd\u{1}"),
+            "a\\\"b\\\\c\\nd\\u0001"
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let upper = |s: &str| s.to_uppercase();
+        assert_eq!(upper.escape("abc"), "ABC");
+        assert_eq!(NoEscaper.escape("<a>"), "<a>");
+    }
+}
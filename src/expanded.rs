@@ -0,0 +1,160 @@
+//! This module contains the expanded printing of tables, one record per row
+use std::io::{Error, Write};
+
+use super::utils::display_width;
+use super::{AsTableSlice, Cell, Table, TableSlice};
+
+/// Get the lines of `cell` as printed, or a single empty line if there's no cell
+fn cell_lines(cell: Option<&Cell>) -> Vec<&str> {
+    match cell {
+        Some(cell) => (0..cell.get_height().max(1))
+            .map(|i| cell.get_line(i))
+            .collect(),
+        None => vec![""],
+    }
+}
+
+impl<'a> TableSlice<'a> {
+    /// Print the table to `out` in expanded mode, like `\x` in `psql`: each row is printed
+    /// as a block of `field | value` lines, headed by a `-[ RECORD n ]-` line.
+    /// Field names are taken from the titles, or are the column positions starting from 1
+    /// when the table has no titles. Multi-line values are continued on the next lines
+    /// with an empty field name. It's well suited to wide rows printed in narrow terminals.
+    /// Returns the number of printed lines
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["Alice", "30"], ["Bob", "4"]);
+    /// table.set_titles(row!["name", "age"]);
+    /// let mut out = Vec::new();
+    /// table.print_expanded(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap().replace("\r\n", "\n"),
+    ///     "\
+    /// -[ RECORD 1 ]-
+    /// name | Alice
+    /// age  | 30
+    /// -[ RECORD 2 ]-
+    /// name | Bob
+    /// age  | 4
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn print_expanded<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).print_expanded(out);
+        }
+        let column_num = self.get_column_num();
+        let fields: Vec<Vec<String>> = (0..column_num)
+            .map(|c| match *self.titles {
+                Some(ref titles) => cell_lines(titles.get_cell_at_column(c))
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                None => vec![(c + 1).to_string()],
+            })
+            .collect();
+        let rows = self.exported_rows().collect::<Vec<_>>();
+        let records: Vec<Vec<Vec<&str>>> = rows
+            .iter()
+            .map(|r| {
+                (0..column_num)
+                    .map(|c| cell_lines(r.get_cell_at_column(c)))
+                    .collect()
+            })
+            .collect();
+        let field_width = fields
+            .iter()
+            .flatten()
+            .map(|f| display_width(f))
+            .max()
+            .unwrap_or(0);
+        let value_width = records
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|v| display_width(v))
+            .max()
+            .unwrap_or(0);
+        let newline = self.format.newline_bytes();
+        let mut height = 0;
+        for (i, record) in records.iter().enumerate() {
+            // The header is continued with dashes, with a `+` above the field separator
+            let mut header = format!("-[ RECORD {} ]", i + 1);
+            let header_width = display_width(&header);
+            if header_width <= field_width + 1 {
+                header.push_str(&"-".repeat(field_width + 1 - header_width));
+                header.push('+');
+                header.push_str(&"-".repeat(value_width + 1));
+            } else {
+                let width = field_width + 3 + value_width;
+                header.push_str(&"-".repeat(width.saturating_sub(header_width).max(1)));
+            }
+            out.write_all(header.as_bytes())?;
+            out.write_all(newline)?;
+            height += 1;
+            for (field, value) in fields.iter().zip(record) {
+                for l in 0..field.len().max(value.len()) {
+                    let name = field.get(l).map(String::as_str).unwrap_or("");
+                    let value = value.get(l).copied().unwrap_or("");
+                    let fill = field_width - display_width(name);
+                    write!(out, "{}{} |", name, " ".repeat(fill))?;
+                    if !value.is_empty() {
+                        write!(out, " {}", value)?;
+                    }
+                    out.write_all(newline)?;
+                    height += 1;
+                }
+            }
+        }
+        out.flush()?;
+        Ok(height)
+    }
+}
+
+impl Table {
+    /// Print the table to `out` in expanded mode, with a `field | value` block per row.
+    /// See [`TableSlice::print_expanded`](struct.TableSlice.html#method.print_expanded)
+    /// for details
+    pub fn print_expanded<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
+        self.as_slice().print_expanded(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::StringWriter;
+    use crate::{row, table};
+
+    #[test]
+    fn print_expanded() {
+        let mut table = table!(["1", "a\nb"], ["22"]);
+        let mut out = StringWriter::new();
+        assert_eq!(table.print_expanded(&mut out).unwrap(), 7);
+        let expected = "\
+-[ RECORD 1 ]-
+1 | 1
+2 | a
+  | b
+-[ RECORD 2 ]-
+1 | 22
+2 |
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        table.set_titles(row!["a long title", "b"]);
+        table.set_column_hidden("b", true);
+        let mut out = StringWriter::new();
+        assert_eq!(table.print_expanded(&mut out).unwrap(), 4);
+        let expected = "\
+-[ RECORD 1 ]+---
+a long title | 1
+-[ RECORD 2 ]+---
+a long title | 22
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+    }
+}
@@ -3,11 +3,15 @@
 use std::io::{Error, Write};
 
 use encode_unicode::Utf8Char;
+use unicode_width::UnicodeWidthChar;
 
-use super::utils::NEWLINE;
+use super::cell::term_error_to_io_error;
+use super::utils::{self, NEWLINE};
+use super::{Attr, Terminal};
 
 /// Alignment for cell's content
 #[derive(Clone, Debug, PartialEq, Copy, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     /// Align left
     LEFT,
@@ -15,6 +19,69 @@ pub enum Alignment {
     CENTER,
     /// Align right
     RIGHT,
+    /// Stretch the spaces between words so that lines fill the whole width,
+    /// except the last line of the cell, which is aligned left
+    JUSTIFY,
+}
+
+impl Alignment {
+    /// Exchange left and right, for right-to-left layouts
+    pub(crate) fn mirror(self) -> Alignment {
+        match self {
+            Alignment::LEFT => Alignment::RIGHT,
+            Alignment::RIGHT => Alignment::LEFT,
+            align => align,
+        }
+    }
+}
+
+/// Width of the East Asian ambiguous width characters, like `°`, `±` or `…`,
+/// which terminals print either 1 or 2 columns wide
+#[derive(Clone, Debug, PartialEq, Copy, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters are 1 column wide, like in most terminals
+    Narrow,
+    /// Ambiguous characters are 2 columns wide, like in most CJK terminals
+    Wide,
+    /// Ambiguous characters are 2 columns wide if the locale is a Chinese, Japanese or Korean one,
+    /// as given by the `LC_ALL`, `LC_CTYPE` or `LANG` environment variables, and 1 otherwise
+    Auto,
+}
+
+impl Default for AmbiguousWidth {
+    fn default() -> AmbiguousWidth {
+        AmbiguousWidth::Narrow
+    }
+}
+
+/// Line ending printed at the end of each line of a table
+#[derive(Clone, Debug, PartialEq, Copy, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Newline {
+    /// `\r\n` on Windows when the `win_crlf` feature is enabled, and `\n` otherwise
+    Platform,
+    /// `\n`, like on unix
+    Lf,
+    /// `\r\n`, like on Windows
+    CrLf,
+}
+
+impl Default for Newline {
+    fn default() -> Newline {
+        Newline::Platform
+    }
+}
+
+impl Newline {
+    /// Get the bytes of this line ending
+    pub(crate) fn as_bytes(self) -> &'static [u8] {
+        match self {
+            Newline::Platform => NEWLINE,
+            Newline::Lf => b"\n",
+            Newline::CrLf => b"\r\n",
+        }
+    }
 }
 
 /// Position of a line separator in a table
@@ -22,11 +89,19 @@ pub enum Alignment {
 pub enum LinePosition {
     /// Table's border on top
     Top,
+    /// Line separator between the heading row,
+    /// and the titles row or the first data row
+    Heading,
     /// Line separator between the titles row,
     /// and the first data row
     Title,
     /// Line separator between data rows
     Intern,
+    /// Line separator between the last data row,
+    /// and the footer row
+    Footer,
+    /// Line separator before a group header row
+    Group,
     /// Bottom table's border
     Bottom,
 }
@@ -42,11 +117,19 @@ pub enum ColumnPosition {
     Right,
 }
 
+/// Maximum number of characters in the repeated pattern of a line separator
+const MAX_PATTERN_LEN: usize = 8;
+
 /// Contains the character used for printing a line separator
 #[derive(Clone, Debug, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineSeparator {
-    /// Line separator
-    line: char,
+    /// Line separator pattern, made of the first `line_len` characters,
+    /// the other ones being `'\0'`
+    line: [char; MAX_PATTERN_LEN],
+    /// Number of characters in the line separator pattern,
+    /// or 0 for a blank line without junctions
+    line_len: usize,
     /// Internal junction separator
     junc: char,
     /// Left junction separator
@@ -59,32 +142,152 @@ impl LineSeparator {
     /// Create a new line separator instance where `line` is the character used to separate 2 lines
     /// and `junc` is the one used for junctions between columns and lines
     pub fn new(line: char, junc: char, ljunc: char, rjunc: char) -> LineSeparator {
+        let mut pattern = ['\0'; MAX_PATTERN_LEN];
+        pattern[0] = line;
         LineSeparator {
-            line,
+            line: pattern,
+            line_len: 1,
             junc,
             ljunc,
             rjunc,
         }
     }
 
-    /// Print a full line separator to `out`. `col_width` is a slice containing the width of each column.
-    /// Returns the number of printed lines
+    /// Create a line separator printing an empty line, without junctions nor borders,
+    /// for airy layouts where rows are separated by blank lines
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::{table, format::{LinePosition, LineSeparator, TableFormat}};
+    /// let mut format = TableFormat::new();
+    /// format.column_separator('|');
+    /// format.separator(LinePosition::Intern, LineSeparator::blank());
+    /// let mut table = table!(["a", "b"], ["c", "d"]);
+    /// table.set_format(format);
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), "a|b\n\nc|d\n");
+    /// ```
+    pub fn blank() -> LineSeparator {
+        LineSeparator {
+            line: ['\0'; MAX_PATTERN_LEN],
+            line_len: 0,
+            junc: '\0',
+            ljunc: '\0',
+            rjunc: '\0',
+        }
+    }
+
+    /// Check whether this separator prints an empty line, like the one created with `blank()`
+    pub fn is_blank(&self) -> bool {
+        self.line_len == 0
+    }
+
+    /// Get a copy of this separator with its box drawing characters replaced with
+    /// their ASCII equivalents. See `TableFormat::to_ascii()`
+    pub fn to_ascii(&self) -> LineSeparator {
+        let mut line = self.line;
+        for c in &mut line[..self.line_len] {
+            *c = ascii_border(*c);
+        }
+        LineSeparator {
+            line,
+            line_len: self.line_len,
+            junc: ascii_border(self.junc),
+            ljunc: ascii_border(self.ljunc),
+            rjunc: ascii_border(self.rjunc),
+        }
+    }
+
+    /// Create a new line separator instance where the line is made of `pattern` repeated,
+    /// like `"- "` for a dashed line, and `junc` is the character used for junctions between
+    /// columns and lines. The pattern is restarted in each column, and cut at the column's end,
+    /// wide characters which don't fit anymore being replaced with spaces.
+    ///
+    /// Fails if `pattern` is empty, longer than 8 characters,
+    /// or contains characters without any width
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::{table, format::{LinePosition, LineSeparator, TableFormat}};
+    /// let mut format = TableFormat::new();
+    /// format.column_separator('|');
+    /// let dashed = LineSeparator::with_pattern("- ", '+', '+', '+').unwrap();
+    /// format.separator(LinePosition::Intern, dashed);
+    /// let mut table = table!(["abcd", "e"], ["f", "g"]);
+    /// table.set_format(format);
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), "abcd|e\n- - +-\nf   |g\n");
+    /// ```
+    pub fn with_pattern(
+        pattern: &str,
+        junc: char,
+        ljunc: char,
+        rjunc: char,
+    ) -> Result<LineSeparator, crate::Error> {
+        let mut line = ['\0'; MAX_PATTERN_LEN];
+        let mut line_len = 0;
+        for c in pattern.chars() {
+            if line_len == MAX_PATTERN_LEN {
+                return Err(crate::Error::Format(format!(
+                    "line pattern {:?} is longer than {} characters",
+                    pattern, MAX_PATTERN_LEN
+                )));
+            }
+            if c.width().unwrap_or_default() == 0 {
+                return Err(crate::Error::Format(format!(
+                    "line pattern {:?} contains a character without width",
+                    pattern
+                )));
+            }
+            line[line_len] = c;
+            line_len += 1;
+        }
+        if line_len == 0 {
+            return Err(crate::Error::Format("empty line pattern".to_string()));
+        }
+        Ok(LineSeparator {
+            line,
+            line_len,
+            junc,
+            ljunc,
+            rjunc,
+        })
+    }
+
+    /// Print a full line separator to `out`, without the line ending.
+    /// `col_width` is a slice containing the width of each column,
+    /// and `columns` the way the line is drawn in each column, if it differs
+    #[allow(clippy::too_many_arguments)]
     fn print<T: Write + ?Sized>(
         &self,
         out: &mut T,
         col_width: &[usize],
+        columns: &[ColumnLine],
         padding: (usize, usize),
         colsep: bool,
         lborder: bool,
         rborder: bool,
-    ) -> Result<usize, Error> {
+    ) -> Result<(), Error> {
         if lborder {
             out.write_all(Utf8Char::from(self.ljunc).as_bytes())?;
         }
-        let mut iter = col_width.iter().peekable();
-        while let Some(width) = iter.next() {
-            for _ in 0..width + padding.0 + padding.1 {
-                out.write_all(Utf8Char::from(self.line).as_bytes())?;
+        let mut iter = col_width.iter().enumerate().peekable();
+        while let Some((i, width)) = iter.next() {
+            let len = width + padding.0 + padding.1;
+            let column = columns.get(i).copied().unwrap_or_default();
+            let (left, right) = match column.align {
+                Some(Alignment::CENTER) => (len > 0, len > 1),
+                Some(Alignment::RIGHT) => (false, len > 0),
+                _ => (false, false),
+            };
+            if left {
+                out.write_all(b":")?;
+            }
+            let pattern = match column.line {
+                Some(ref line) => std::slice::from_ref(line),
+                None => &self.line[..self.line_len],
+            };
+            print_line(out, pattern, len - left as usize - right as usize)?;
+            if right {
+                out.write_all(b":")?;
             }
             if colsep && iter.peek().is_some() {
                 out.write_all(Utf8Char::from(self.junc).as_bytes())?;
@@ -93,9 +296,49 @@ impl LineSeparator {
         if rborder {
             out.write_all(Utf8Char::from(self.rjunc).as_bytes())?;
         }
-        out.write_all(NEWLINE)?;
-        Ok(1)
+        Ok(())
+    }
+}
+
+/// Get the ASCII equivalent of the box drawing character `c`: `-` or `=` for horizontal lines,
+/// `|` for vertical lines, and `+` for corners and junctions. Other characters are returned as is
+pub(crate) fn ascii_border(c: char) -> char {
+    match c {
+        '═' => '=',
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+            '-'
+        }
+        '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257f}' => '+',
+        c => c,
+    }
+}
+
+/// Print `pattern` repeated over `width` columns, filling with spaces
+/// when the next character is too wide to fit
+fn print_line<T: Write + ?Sized>(out: &mut T, pattern: &[char], width: usize) -> Result<(), Error> {
+    let mut filled = 0;
+    for &c in pattern.iter().cycle() {
+        // Characters without width, like control characters, take one column
+        let w = c.width().unwrap_or_default().max(1);
+        if filled + w > width {
+            break;
+        }
+        out.write_all(Utf8Char::from(c).as_bytes())?;
+        filled += w;
     }
+    out.write_all(&vec![b' '; width - filled])
+}
+
+/// The way a line separator is drawn in a single column
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ColumnLine {
+    /// Alignment marked with `:` on the sides of the line
+    pub(crate) align: Option<Alignment>,
+    /// Character used for the line instead of the separator's one
+    pub(crate) line: Option<char>,
 }
 
 impl Default for LineSeparator {
@@ -106,6 +349,7 @@ impl Default for LineSeparator {
 
 /// Contains the table formatting rules
 #[derive(Clone, Debug, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableFormat {
     /// Optional column separator character
     csep: Option<char>,
@@ -117,6 +361,12 @@ pub struct TableFormat {
     lsep: Option<LineSeparator>,
     /// Optional title line separator
     tsep: Option<LineSeparator>,
+    /// Optional footer line separator
+    fsep: Option<LineSeparator>,
+    /// Optional group header line separator
+    gsep: Option<LineSeparator>,
+    /// Optional heading line separator
+    hsep: Option<LineSeparator>,
     /// Optional top line separator
     top_sep: Option<LineSeparator>,
     /// Optional bottom line separator
@@ -125,8 +375,24 @@ pub struct TableFormat {
     pad_left: usize,
     /// Right padding
     pad_right: usize,
+    /// Character used for the padding and to fill cells
+    #[cfg_attr(feature = "serde", serde(default = "default_pad_char"))]
+    pad_char: char,
     /// Global indentation when rendering the table
     indent: usize,
+    /// Remove trailing whitespaces at the end of lines
+    trim: bool,
+    /// Optional style of the borders and separators
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::attr"))]
+    border_style: Option<Attr>,
+    /// Mark the columns alignment in the title separator
+    align_markers: bool,
+    /// Width of the East Asian ambiguous width characters
+    #[cfg_attr(feature = "serde", serde(default))]
+    ambiguous_width: AmbiguousWidth,
+    /// Line ending
+    #[cfg_attr(feature = "serde", serde(default))]
+    newline: Newline,
 }
 
 impl TableFormat {
@@ -138,11 +404,20 @@ impl TableFormat {
             rborder: None,
             lsep: None,
             tsep: None,
+            fsep: None,
+            gsep: None,
+            hsep: None,
             top_sep: None,
             bottom_sep: None,
             pad_left: 0,
             pad_right: 0,
+            pad_char: ' ',
             indent: 0,
+            trim: false,
+            border_style: None,
+            align_markers: false,
+            ambiguous_width: AmbiguousWidth::Narrow,
+            newline: Newline::Platform,
         }
     }
 
@@ -157,6 +432,66 @@ impl TableFormat {
         self.pad_right = right;
     }
 
+    /// Set the character used for the padding and to fill cells, which is a space by default.
+    /// A wide character like the ideographic space `U+3000` lines up better in fonts where
+    /// half-width spaces look wrong next to CJK characters. Each padding character then takes
+    /// its width, and cells are filled with it as much as possible, and with spaces otherwise.
+    /// Characters without width are ignored
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_DEFAULT;
+    /// # fn main() {
+    /// let mut table = table!(["表", "一二"], ["一二三"]);
+    /// let mut format = *FORMAT_DEFAULT;
+    /// format.padding_char('\u{3000}');
+    /// table.set_format(format);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +----------+--------+
+    /// |\u{3000}表\u{3000}\u{3000}\u{3000}|\u{3000}一二\u{3000}|
+    /// +----------+--------+
+    /// |\u{3000}一二三\u{3000}|\u{3000}\u{3000}\u{3000}\u{3000}|
+    /// +----------+--------+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn padding_char(&mut self, pad: char) {
+        if pad.width().unwrap_or_default() > 0 {
+            self.pad_char = pad;
+        }
+    }
+
+    /// Get the character used for the padding and to fill cells
+    pub fn get_padding_char(&self) -> char {
+        self.pad_char
+    }
+
+    /// Return a tuple with the width of the left and right padding,
+    /// taking the width of the padding character into account
+    pub(crate) fn get_padding_width(&self) -> (usize, usize) {
+        let w = self.pad_char.width().unwrap_or(1);
+        (self.pad_left * w, self.pad_right * w)
+    }
+
+    /// Print `n` padding characters to `out`
+    pub(crate) fn print_padding<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        n: usize,
+    ) -> Result<(), Error> {
+        if self.pad_char == ' ' {
+            return out.write_all(&vec![b' '; n]);
+        }
+        for _ in 0..n {
+            out.write_all(Utf8Char::from(self.pad_char).as_bytes())?;
+        }
+        Ok(())
+    }
+
     /// Set the character used for internal column separation
     pub fn column_separator(&mut self, separator: char) {
         self.csep = Some(separator);
@@ -178,13 +513,32 @@ impl TableFormat {
         self.rborder = Some(border);
     }
 
-    /// Set a line separator
+    /// Set a line separator.
+    ///
+    /// Each position has its own separator, with its own left and right junctions, so the
+    /// four corners of a table are the junctions of the top and bottom separators, and can
+    /// all be different.
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::{table, format::{LinePosition, LineSeparator, TableFormat}};
+    /// let mut format = TableFormat::new();
+    /// format.borders('|');
+    /// format.separator(LinePosition::Top, LineSeparator::new('-', '-', '/', '\\'));
+    /// format.separator(LinePosition::Bottom, LineSeparator::new('-', '-', '\\', '/'));
+    /// let mut table = table!(["abc"]);
+    /// table.set_format(format);
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), "/---\\\n|abc|\n\\---/\n");
+    /// ```
     pub fn separator(&mut self, what: LinePosition, separator: LineSeparator) {
         *match what {
             LinePosition::Top => &mut self.top_sep,
             LinePosition::Bottom => &mut self.bottom_sep,
             LinePosition::Title => &mut self.tsep,
             LinePosition::Intern => &mut self.lsep,
+            LinePosition::Footer => &mut self.fsep,
+            LinePosition::Group => &mut self.gsep,
+            LinePosition::Heading => &mut self.hsep,
         } = Some(separator);
     }
 
@@ -204,6 +558,23 @@ impl TableFormat {
                 s @ &Some(_) => s,
                 &None => &self.lsep,
             },
+            // Footer and group separators mirror the title one when not set,
+            // unless it marks the columns alignment, which can only be done once
+            LinePosition::Footer => match &self.fsep {
+                s @ &Some(_) => s,
+                &None if self.align_markers => &None,
+                &None => self.get_sep_for_line(LinePosition::Title),
+            },
+            LinePosition::Group => match &self.gsep {
+                s @ &Some(_) => s,
+                &None if self.align_markers => &None,
+                &None => self.get_sep_for_line(LinePosition::Title),
+            },
+            // The heading is separated like data rows when not set
+            LinePosition::Heading => match &self.hsep {
+                s @ &Some(_) => s,
+                &None => &self.lsep,
+            },
         }
     }
 
@@ -217,32 +588,436 @@ impl TableFormat {
         self.indent
     }
 
+    /// Set whether the padding and filling spaces at the end of lines are removed.
+    /// Without right border, lines may otherwise end with spaces, which is an issue
+    /// when the output ends up in patch files or is checked by linters
+    pub fn trim_trailing_whitespace(&mut self, trim: bool) {
+        self.trim = trim;
+    }
+
+    /// Check whether the spaces at the end of lines are removed
+    pub fn get_trim_trailing_whitespace(&self) -> bool {
+        self.trim
+    }
+
+    /// Set the style of the borders, column separators and line separators,
+    /// applied when printing to a terminal, independently of the cells' style.
+    /// It's handy to print the table frame dimmed or colored
+    pub fn border_style(&mut self, style: Attr) {
+        self.border_style = Some(style);
+    }
+
+    /// Remove the style of the borders and separators
+    pub fn unset_border_style(&mut self) {
+        self.border_style = None;
+    }
+
+    /// Get the style of the borders and separators, if any
+    pub fn get_border_style(&self) -> Option<Attr> {
+        self.border_style
+    }
+
+    /// Set whether the title separator marks the alignment of each column with `:`,
+    /// like `|:---|:---:|---:|` in Markdown tables.
+    /// A column's alignment is the one of its first data row cell.
+    /// With it, the titles are mandatory, so an empty title row is printed if the table
+    /// has none, and footer and group separators don't mirror the title separator anymore
+    pub fn alignment_markers(&mut self, markers: bool) {
+        self.align_markers = markers;
+    }
+
+    /// Check whether the title separator marks the alignment of each column
+    pub fn get_alignment_markers(&self) -> bool {
+        self.align_markers
+    }
+
+    /// Set the width of the East Asian ambiguous width characters, used to compute the width
+    /// of cells and to align them, so that tables line up in terminals printing
+    /// them 2 columns wide
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::{consts::FORMAT_DEFAULT, AmbiguousWidth};
+    /// # fn main() {
+    /// let mut table = table!(["±1", "a"]);
+    /// let mut format = *FORMAT_DEFAULT;
+    /// format.ambiguous_width(AmbiguousWidth::Wide);
+    /// table.set_format(format);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-----+---+
+    /// | ±1 | a |
+    /// +-----+---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn ambiguous_width(&mut self, width: AmbiguousWidth) {
+        self.ambiguous_width = width;
+    }
+
+    /// Get the width of the East Asian ambiguous width characters
+    pub fn get_ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
+    /// Set the line ending printed at the end of each line, instead of the platform's one.
+    /// Forcing `\n` keeps the output identical on all platforms, like for golden files
+    /// or when it's piped to tools expecting unix line endings
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::{consts::FORMAT_DEFAULT, Newline};
+    /// # fn main() {
+    /// let mut table = table!(["a"]);
+    /// let mut format = *FORMAT_DEFAULT;
+    /// format.newline(Newline::CrLf);
+    /// table.set_format(format);
+    /// assert_eq!(table.to_string(), "+---+\r\n| a |\r\n+---+\r\n");
+    /// # }
+    /// ```
+    pub fn newline(&mut self, newline: Newline) {
+        self.newline = newline;
+    }
+
+    /// Get the line ending printed at the end of each line
+    pub fn get_newline(&self) -> Newline {
+        self.newline
+    }
+
+    /// Get the bytes of the line ending printed at the end of each line
+    pub(crate) fn newline_bytes(&self) -> &'static [u8] {
+        self.newline.as_bytes()
+    }
+
+    /// Check whether the East Asian ambiguous width characters are 2 columns wide,
+    /// the `Auto` width being resolved with the locale
+    pub(crate) fn is_ambiguous_wide(&self) -> bool {
+        match self.ambiguous_width {
+            AmbiguousWidth::Narrow => false,
+            AmbiguousWidth::Wide => true,
+            AmbiguousWidth::Auto => utils::locale_is_cjk(),
+        }
+    }
+
+    /// Get a copy of this format with the box drawing characters of its borders, column separator
+    /// and line separators replaced with their ASCII equivalents, like `+`, `-` and `|`,
+    /// for outputs not supporting UTF-8
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_BOX_CHARS;
+    /// # fn main() {
+    /// let mut table = table!(["a", "b"]);
+    /// table.set_format(FORMAT_BOX_CHARS.to_ascii());
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +---+---+
+    /// | a | b |
+    /// +---+---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn to_ascii(&self) -> TableFormat {
+        let sep = |s: Option<LineSeparator>| s.map(|s| s.to_ascii());
+        TableFormat {
+            csep: self.csep.map(ascii_border),
+            lborder: self.lborder.map(ascii_border),
+            rborder: self.rborder.map(ascii_border),
+            lsep: sep(self.lsep),
+            tsep: sep(self.tsep),
+            fsep: sep(self.fsep),
+            gsep: sep(self.gsep),
+            hsep: sep(self.hsep),
+            top_sep: sep(self.top_sep),
+            bottom_sep: sep(self.bottom_sep),
+            ..*self
+        }
+    }
+
+    /// Parse a format from a compact specification, so that formats can be read from
+    /// configuration files or environment variables.
+    ///
+    /// The specification is a whitespace separated list of settings:
+    ///
+    /// * `col=C`, `left=C`, `right=C` and `borders=C` : the column separator,
+    ///   the left and right borders, or both borders
+    /// * `top=S`, `heading=S`, `title=S`, `intern=S`, `group=S`, `footer=S` and `bottom=S` :
+    ///   the line separators, where `S` is the line character or pattern,
+    ///   followed by the internal, left and right junctions, or nothing for a blank line
+    /// * `pad=N` or `pad=L,R` : the padding on both sides, or on the left and right sides
+    /// * `padchar=C` : the padding character
+    /// * `indent=N` : the indentation
+    /// * `style=SPEC` : the border style, with a single style specifier like in
+    ///   [`Cell::style_spec`](../struct.Cell.html#method.style_spec), e.g. `Fr` or `b`
+    /// * `trim` and `markers` : trimming of trailing whitespace, and alignment markers
+    /// * `ambiguous=W` : the width of East Asian ambiguous width characters,
+    ///   `narrow`, `wide` or `auto`
+    /// * `newline=N` : the line ending, `lf` or `crlf`
+    ///
+    /// Spaces and backslashes in characters are written `\s` and `\\`,
+    /// and other whitespace characters with their hexadecimal code, like `\u{3000}`.
+    /// Fails with [`Error::Format`](../enum.Error.html#variant.Format) if the specification is
+    /// not valid. [`to_spec()`](#method.to_spec) does the opposite
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::format::{consts::FORMAT_BOX_CHARS, TableFormat};
+    /// let spec = "col=│ borders=│ top=─┬┌┐ intern=─┼├┤ bottom=─┴└┘ pad=1";
+    /// assert_eq!(TableFormat::from_spec(spec).unwrap(), *FORMAT_BOX_CHARS);
+    /// assert_eq!(FORMAT_BOX_CHARS.to_spec(), spec);
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<TableFormat, crate::Error> {
+        let invalid = |msg: &str, token: &str| crate::Error::Format(format!("{} `{}`", msg, token));
+        let mut format = TableFormat::new();
+        for token in spec.split_whitespace() {
+            let (key, value) = match token.split_once('=') {
+                Some((key, value)) => (
+                    key,
+                    unescape_spec(value).ok_or_else(|| invalid("invalid escape in", token))?,
+                ),
+                None => (token, String::new()),
+            };
+            let single = || {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => Err(invalid("expected a single character in", token)),
+                }
+            };
+            let number = |n: &str| {
+                n.parse::<usize>()
+                    .map_err(|_| invalid("expected a number in", token))
+            };
+            let line = || {
+                let chars = value.chars().collect::<Vec<_>>();
+                if chars.is_empty() {
+                    return Ok(LineSeparator::blank());
+                }
+                if chars.len() < 4 {
+                    return Err(invalid("expected a line and 3 junctions in", token));
+                }
+                let (pattern, junc) = chars.split_at(chars.len() - 3);
+                let pattern = pattern.iter().collect::<String>();
+                LineSeparator::with_pattern(&pattern, junc[0], junc[1], junc[2])
+            };
+            match key {
+                "col" => format.column_separator(single()?),
+                "left" => format.left_border(single()?),
+                "right" => format.right_border(single()?),
+                "borders" => format.borders(single()?),
+                "top" => format.separator(LinePosition::Top, line()?),
+                "heading" => format.separator(LinePosition::Heading, line()?),
+                "title" => format.separator(LinePosition::Title, line()?),
+                "intern" => format.separator(LinePosition::Intern, line()?),
+                "group" => format.separator(LinePosition::Group, line()?),
+                "footer" => format.separator(LinePosition::Footer, line()?),
+                "bottom" => format.separator(LinePosition::Bottom, line()?),
+                "pad" => match value.split_once(',') {
+                    Some((l, r)) => format.padding(number(l)?, number(r)?),
+                    None => format.padding(number(&value)?, number(&value)?),
+                },
+                "padchar" => format.padding_char(single()?),
+                "indent" => format.indent(number(&value)?),
+                "style" => format.border_style(
+                    parse_attr(&value).ok_or_else(|| invalid("invalid style in", token))?,
+                ),
+                "trim" if value.is_empty() => format.trim_trailing_whitespace(true),
+                "markers" if value.is_empty() => format.alignment_markers(true),
+                "ambiguous" => format.ambiguous_width(match &value[..] {
+                    "narrow" => AmbiguousWidth::Narrow,
+                    "wide" => AmbiguousWidth::Wide,
+                    "auto" => AmbiguousWidth::Auto,
+                    _ => return Err(invalid("invalid ambiguous width in", token)),
+                }),
+                "newline" => format.newline(match &value[..] {
+                    "lf" => Newline::Lf,
+                    "crlf" => Newline::CrLf,
+                    _ => return Err(invalid("invalid newline in", token)),
+                }),
+                _ => return Err(invalid("unknown setting", token)),
+            }
+        }
+        Ok(format)
+    }
+
+    /// Write this format as a compact specification, which can be parsed back
+    /// with [`from_spec()`](#method.from_spec).
+    /// A border style which can't be written with style specifiers, like a dim style,
+    /// is left out
+    pub fn to_spec(&self) -> String {
+        let mut spec = Vec::new();
+        let chars = [
+            ("col", self.csep),
+            ("left", self.lborder),
+            ("right", self.rborder),
+        ];
+        match (self.lborder, self.rborder) {
+            (Some(l), Some(r)) if l == r => {
+                spec.extend(self.csep.map(|c| format!("col={}", escape_spec(&[c]))));
+                spec.push(format!("borders={}", escape_spec(&[l])));
+            }
+            _ => spec.extend(
+                chars
+                    .iter()
+                    .filter_map(|&(key, c)| c.map(|c| format!("{}={}", key, escape_spec(&[c])))),
+            ),
+        }
+        let lines = [
+            ("top", self.top_sep),
+            ("heading", self.hsep),
+            ("title", self.tsep),
+            ("intern", self.lsep),
+            ("group", self.gsep),
+            ("footer", self.fsep),
+            ("bottom", self.bottom_sep),
+        ];
+        for &(key, sep) in &lines {
+            match sep {
+                Some(sep) if sep.is_blank() => spec.push(format!("{}=", key)),
+                Some(sep) => {
+                    let mut chars = sep.line[..sep.line_len].to_vec();
+                    chars.extend_from_slice(&[sep.junc, sep.ljunc, sep.rjunc]);
+                    spec.push(format!("{}={}", key, escape_spec(&chars)));
+                }
+                None => (),
+            }
+        }
+        match self.get_padding() {
+            (0, 0) => (),
+            (l, r) if l == r => spec.push(format!("pad={}", l)),
+            (l, r) => spec.push(format!("pad={},{}", l, r)),
+        }
+        if self.pad_char != ' ' {
+            spec.push(format!("padchar={}", escape_spec(&[self.pad_char])));
+        }
+        if self.indent > 0 {
+            spec.push(format!("indent={}", self.indent));
+        }
+        spec.extend(
+            self.border_style
+                .and_then(attr_spec)
+                .map(|s| format!("style={}", s)),
+        );
+        if self.trim {
+            spec.push("trim".to_string());
+        }
+        if self.align_markers {
+            spec.push("markers".to_string());
+        }
+        match self.ambiguous_width {
+            AmbiguousWidth::Narrow => {}
+            AmbiguousWidth::Wide => spec.push("ambiguous=wide".to_string()),
+            AmbiguousWidth::Auto => spec.push("ambiguous=auto".to_string()),
+        }
+        match self.newline {
+            Newline::Platform => {}
+            Newline::Lf => spec.push("newline=lf".to_string()),
+            Newline::CrLf => spec.push("newline=crlf".to_string()),
+        }
+        spec.join(" ")
+    }
+
+    /// Check whether a visible line separator is drawn at `pos`, blank ones being only empty lines
+    pub(crate) fn has_line_separator(&self, pos: LinePosition) -> bool {
+        self.get_sep_for_line(pos)
+            .as_ref()
+            .map_or(false, |s| !s.is_blank())
+    }
+
     /// Print a full line separator to `out`. `col_width` is a slice containing the width of each column.
     /// Returns the number of printed lines
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
-    pub(crate) fn print_line_separator<T: Write + ?Sized>(
+    pub(crate) fn print_line_separator<T: Write + ?Sized, B: BorderPrinter<T>>(
         &self,
         out: &mut T,
         col_width: &[usize],
         pos: LinePosition,
+        borders: &B,
     ) -> Result<usize, Error> {
         match *self.get_sep_for_line(pos) {
-            Some(ref l) => {
-                //TODO: Wrap this into dedicated function one day
-                out.write_all(&vec![b' '; self.get_indent()])?;
-                l.print(
-                    out,
-                    col_width,
-                    self.get_padding(),
-                    self.csep.is_some(),
-                    self.lborder.is_some(),
-                    self.rborder.is_some(),
-                )
-            }
+            Some(ref l) => self.print_separator(out, col_width, &[], l, borders),
             None => Ok(0),
         }
     }
 
+    /// Print the title line separator to `out`, drawn in each column as given by `columns`.
+    /// Returns the number of printed lines
+    pub(crate) fn print_title_separator<T: Write + ?Sized, B: BorderPrinter<T>>(
+        &self,
+        out: &mut T,
+        col_width: &[usize],
+        columns: &[ColumnLine],
+        borders: &B,
+    ) -> Result<usize, Error> {
+        match *self.get_sep_for_line(LinePosition::Title) {
+            Some(ref l) => self.print_separator(out, col_width, columns, l, borders),
+            None => Ok(0),
+        }
+    }
+
+    /// Render the line separator for the position `pos` to `out`, for columns of the given widths.
+    /// Nothing is printed if this format has no separator for this position.
+    /// It's the building block of custom layouts, along with
+    /// [`Row::render`](../struct.Row.html#method.render).
+    /// Returns the number of printed lines
+    pub fn render_separator<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        col_width: &[usize],
+        pos: LinePosition,
+    ) -> Result<usize, Error> {
+        self.print_line_separator(out, col_width, pos, &Plain)
+    }
+
+    /// Print an internal line separator to `out`, even if this format doesn't have one.
+    /// In such case, the bottom line separator, or the default one, is used instead.
+    /// Returns the number of printed lines
+    pub(crate) fn print_forced_intern_separator<T: Write + ?Sized, B: BorderPrinter<T>>(
+        &self,
+        out: &mut T,
+        col_width: &[usize],
+        borders: &B,
+    ) -> Result<usize, Error> {
+        let sep = self.lsep.or(self.bottom_sep).unwrap_or_default();
+        self.print_separator(out, col_width, &[], &sep, borders)
+    }
+
+    fn print_separator<T: Write + ?Sized, B: BorderPrinter<T>>(
+        &self,
+        out: &mut T,
+        col_width: &[usize],
+        columns: &[ColumnLine],
+        sep: &LineSeparator,
+        borders: &B,
+    ) -> Result<usize, Error> {
+        if sep.is_blank() {
+            out.write_all(self.newline_bytes())?;
+            return Ok(1);
+        }
+        //TODO: Wrap this into dedicated function one day
+        out.write_all(&vec![b' '; self.get_indent()])?;
+        borders.print_border(out, self.border_style, |out| {
+            sep.print(
+                out,
+                col_width,
+                columns,
+                self.get_padding_width(),
+                self.csep.is_some(),
+                self.lborder.is_some(),
+                self.rborder.is_some(),
+            )
+        })?;
+        out.write_all(self.newline_bytes())?;
+        Ok(1)
+    }
+
     /// Returns the character used to separate columns.
     /// `pos` specify if the separator is left/right final or internal to the table
     pub fn get_column_separator(&self, pos: ColumnPosition) -> Option<char> {
@@ -255,18 +1030,145 @@ impl TableFormat {
 
     /// Print a column separator or a table border
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
-    pub(crate) fn print_column_separator<T: Write + ?Sized>(
+    pub(crate) fn print_column_separator<T: Write + ?Sized, B: BorderPrinter<T>>(
         &self,
         out: &mut T,
         pos: ColumnPosition,
+        borders: &B,
     ) -> Result<(), Error> {
         match self.get_column_separator(pos) {
-            Some(s) => out.write_all(Utf8Char::from(s).as_bytes()),
+            Some(s) => borders.print_border(out, self.border_style, |out| {
+                out.write_all(Utf8Char::from(s).as_bytes())
+            }),
             None => Ok(()),
         }
     }
 }
 
+/// Default padding character of deserialized formats which don't have one
+#[cfg(feature = "serde")]
+fn default_pad_char() -> char {
+    ' '
+}
+
+/// Color specifiers of the format specifications, by color number
+const SPEC_COLORS: &str = "drgybmcwDRGYBMCW";
+
+/// Parse a style specifier of a format specification
+fn parse_attr(spec: &str) -> Option<Attr> {
+    let color = |c: char| SPEC_COLORS.find(c).map(|i| i as u32);
+    let mut chars = spec.chars();
+    let attr = match (chars.next()?, chars.next()) {
+        ('b', None) => Attr::Bold,
+        ('i', None) => Attr::Italic(true),
+        ('u', None) => Attr::Underline(true),
+        ('F', Some(c)) => Attr::ForegroundColor(color(c)?),
+        ('B', Some(c)) => Attr::BackgroundColor(color(c)?),
+        _ => return None,
+    };
+    match chars.next() {
+        Some(_) => None,
+        None => Some(attr),
+    }
+}
+
+/// Write `attr` as a style specifier of a format specification, if possible
+fn attr_spec(attr: Attr) -> Option<String> {
+    let color = |c: u32| SPEC_COLORS.chars().nth(c as usize);
+    match attr {
+        Attr::Bold => Some("b".to_string()),
+        Attr::Italic(true) => Some("i".to_string()),
+        Attr::Underline(true) => Some("u".to_string()),
+        Attr::ForegroundColor(c) => color(c).map(|c| format!("F{}", c)),
+        Attr::BackgroundColor(c) => color(c).map(|c| format!("B{}", c)),
+        _ => None,
+    }
+}
+
+/// Escape the spaces and backslashes of `chars` for a format specification
+fn escape_spec(chars: &[char]) -> String {
+    let mut escaped = String::new();
+    for &c in chars {
+        match c {
+            ' ' => escaped.push_str("\\s"),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_whitespace() => escaped.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Unescape a value of a format specification, or return `None` if it has an invalid escape
+fn unescape_spec(value: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        unescaped.push(match c {
+            '\\' => match chars.next()? {
+                's' => ' ',
+                '\\' => '\\',
+                'u' => {
+                    let rest = chars.as_str().strip_prefix('{')?;
+                    let (hex, _) = rest.split_once('}')?;
+                    let c = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+                    chars = rest[hex.len() + 1..].chars();
+                    c
+                }
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(unescaped)
+}
+
+/// Printing of the borders and separators of a table, with or without their style
+pub(crate) trait BorderPrinter<T: Write + ?Sized> {
+    /// Print to `out` the border characters written by `print`, applying `style` if supported
+    fn print_border<F>(&self, out: &mut T, style: Option<Attr>, print: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut T) -> Result<(), Error>;
+}
+
+/// Prints tables without any style
+pub(crate) struct Plain;
+
+/// Prints tables to a terminal, applying styles
+pub(crate) struct Styled;
+
+impl<T: Write + ?Sized> BorderPrinter<T> for Plain {
+    fn print_border<F>(&self, out: &mut T, _: Option<Attr>, print: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut T) -> Result<(), Error>,
+    {
+        print(out)
+    }
+}
+
+impl<T: Terminal + ?Sized> BorderPrinter<T> for Styled {
+    fn print_border<F>(&self, out: &mut T, style: Option<Attr>, print: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut T) -> Result<(), Error>,
+    {
+        let style = match style {
+            Some(style) => style,
+            None => return print(out),
+        };
+        match out.attr(style) {
+            Ok(..) | Err(::term::Error::NotSupported) | Err(::term::Error::ColorOutOfRange) => (), // Ignore unsupported attributes
+            Err(e) => return Err(term_error_to_io_error(e)),
+        };
+        print(out)?;
+        match out.reset() {
+            Ok(..) | Err(::term::Error::NotSupported) | Err(::term::Error::ColorOutOfRange) => {
+                Ok(())
+            }
+            Err(e) => Err(term_error_to_io_error(e)),
+        }
+    }
+}
+
 impl Default for TableFormat {
     fn default() -> Self {
         TableFormat::new()
@@ -274,7 +1176,7 @@ impl Default for TableFormat {
 }
 
 /// A builder to create a `TableFormat`
-#[derive(Default)]
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct FormatBuilder {
     format: Box<TableFormat>,
 }
@@ -329,12 +1231,60 @@ impl FormatBuilder {
         self
     }
 
-    /// Set global indentation in spaces used when rendering a table
+    /// Set the line ending printed at the end of each line
+    pub fn newline(mut self, newline: Newline) -> Self {
+        self.format.newline(newline);
+        self
+    }
+
+    /// Set the character used for the padding and to fill cells
+    pub fn padding_char(mut self, pad: char) -> Self {
+        self.format.padding_char(pad);
+        self
+    }
+
+    /// Set global indentation in spaces used when rendering a table.
+    /// Every line is indented, including line separators, captions and summaries
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::{table, format::{consts::FORMAT_DEFAULT, FormatBuilder}};
+    /// let mut table = table!(["a", "b"]);
+    /// table.set_format(FormatBuilder::from(*FORMAT_DEFAULT).indent(2).build());
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "  +---+---+\n  | a | b |\n  +---+---+\n"
+    /// );
+    /// ```
     pub fn indent(mut self, spaces: usize) -> Self {
         self.format.indent(spaces);
         self
     }
 
+    /// Set the width of the East Asian ambiguous width characters
+    pub fn ambiguous_width(mut self, width: AmbiguousWidth) -> Self {
+        self.format.ambiguous_width(width);
+        self
+    }
+
+    /// Set whether the spaces at the end of lines are removed
+    pub fn trim_trailing_whitespace(mut self, trim: bool) -> Self {
+        self.format.trim_trailing_whitespace(trim);
+        self
+    }
+
+    /// Set the style of the borders and separators
+    pub fn border_style(mut self, style: Attr) -> Self {
+        self.format.border_style(style);
+        self
+    }
+
+    /// Set whether the title separator marks the alignment of each column
+    pub fn alignment_markers(mut self, markers: bool) -> Self {
+        self.format.alignment_markers(markers);
+        self
+    }
+
     /// Return the generated `TableFormat`
     pub fn build(&self) -> TableFormat {
         *self.format
@@ -359,6 +1309,8 @@ pub mod consts {
         static ref MINUS_PLUS_SEP: LineSeparator = LineSeparator::new('-', '+', '+', '+');
         /// A line separator made of `=` and `+`
         static ref EQU_PLUS_SEP: LineSeparator = LineSeparator::new('=', '+', '+', '+');
+        /// A line separator made of `-` and `|`
+        static ref MINUS_PIPE_SEP: LineSeparator = LineSeparator::new('-', '|', '|', '|');
 
         /// Default table format
         ///
@@ -557,5 +1509,138 @@ pub mod consts {
                                                             '┘'))
                              .padding(1, 1)
                              .build();
+
+        /// Like `FORMAT_BOX_CHARS`, with rounded corners
+        ///
+        /// # Example
+        /// ```text
+        /// ╭────┬────┬────╮
+        /// │ t1 │ t2 │ t3 │
+        /// ├────┼────┼────┤
+        /// │ 1  │ 1  │ 1  │
+        /// ├────┼────┼────┤
+        /// │ 2  │ 2  │ 2  │
+        /// ╰────┴────┴────╯
+        /// ```
+        pub static ref FORMAT_ROUNDED: TableFormat = FormatBuilder::from(*FORMAT_BOX_CHARS)
+                             .separators(&[LinePosition::Top],
+                                         LineSeparator::new('─',
+                                                            '┬',
+                                                            '╭',
+                                                            '╮'))
+                             .separators(&[LinePosition::Bottom],
+                                         LineSeparator::new('─',
+                                                            '┴',
+                                                            '╰',
+                                                            '╯'))
+                             .build();
+
+        /// A table with borders and delimiters made with double-line box characters
+        ///
+        /// # Example
+        /// ```text
+        /// ╔════╦════╦════╗
+        /// ║ t1 ║ t2 ║ t3 ║
+        /// ╠════╬════╬════╣
+        /// ║ 1  ║ 1  ║ 1  ║
+        /// ╠════╬════╬════╣
+        /// ║ 2  ║ 2  ║ 2  ║
+        /// ╚════╩════╩════╝
+        /// ```
+        pub static ref FORMAT_DOUBLE_BORDER: TableFormat = FormatBuilder::new()
+                             .column_separator('║')
+                             .borders('║')
+                             .separators(&[LinePosition::Top],
+                                         LineSeparator::new('═',
+                                                            '╦',
+                                                            '╔',
+                                                            '╗'))
+                             .separators(&[LinePosition::Title, LinePosition::Intern],
+                                         LineSeparator::new('═',
+                                                            '╬',
+                                                            '╠',
+                                                            '╣'))
+                             .separators(&[LinePosition::Bottom],
+                                         LineSeparator::new('═',
+                                                            '╩',
+                                                            '╚',
+                                                            '╝'))
+                             .padding(1, 1)
+                             .build();
+
+        /// A GitHub flavored Markdown table, with the alignment of each column
+        /// marked in the title separator
+        ///
+        /// # Example
+        /// ```text
+        /// | t1 | t2 | t3 |
+        /// |----|:--:|---:|
+        /// | 1  | 1  |  1 |
+        /// | 2  | 2  |  2 |
+        /// ```
+        pub static ref FORMAT_MARKDOWN: TableFormat = FormatBuilder::new()
+                                                                    .column_separator('|')
+                                                                    .borders('|')
+                                                                    .separator(LinePosition::Title, *MINUS_PIPE_SEP)
+                                                                    .padding(1, 1)
+                                                                    .alignment_markers(true)
+                                                                    .build();
+
+        /// The look of PostgreSQL's `psql` output, without outer border nor trailing whitespace.
+        /// Like `psql`, the number of rows can be printed below with `Table::set_summary()`
+        ///
+        /// # Example
+        /// ```text
+        ///  t1 | t2 | t3
+        /// ----+----+----
+        ///  1  | 1  | 1
+        ///  2  | 2  | 2
+        /// (2 rows)
+        /// ```
+        pub static ref FORMAT_PSQL: TableFormat = FormatBuilder::new()
+                                                                    .padding(1, 1)
+                                                                    .separator(LinePosition::Title, *MINUS_PLUS_SEP)
+                                                                    .column_separator('|')
+                                                                    .trim_trailing_whitespace(true)
+                                                                    .build();
     }
 }
+
+/// Get all the predefined formats from [`consts`](consts/index.html), along with their names.
+///
+/// This is mostly useful to render a table in every format at once, for example
+/// to compare them or to check the output after changing the renderer
+///
+/// # Example
+/// ```
+/// # use prettytable::{format, table};
+/// let mut table = table!(["a", "b"], ["c", "d"]);
+/// for (name, format) in format::all_presets() {
+///     table.set_format(format);
+///     println!("{}:\n{}", name, table);
+/// }
+/// ```
+pub fn all_presets() -> Vec<(&'static str, TableFormat)> {
+    vec![
+        ("FORMAT_DEFAULT", *consts::FORMAT_DEFAULT),
+        ("FORMAT_NO_TITLE", *consts::FORMAT_NO_TITLE),
+        (
+            "FORMAT_NO_LINESEP_WITH_TITLE",
+            *consts::FORMAT_NO_LINESEP_WITH_TITLE,
+        ),
+        ("FORMAT_NO_LINESEP", *consts::FORMAT_NO_LINESEP),
+        ("FORMAT_NO_COLSEP", *consts::FORMAT_NO_COLSEP),
+        ("FORMAT_CLEAN", *consts::FORMAT_CLEAN),
+        ("FORMAT_BORDERS_ONLY", *consts::FORMAT_BORDERS_ONLY),
+        ("FORMAT_NO_BORDER", *consts::FORMAT_NO_BORDER),
+        (
+            "FORMAT_NO_BORDER_LINE_SEPARATOR",
+            *consts::FORMAT_NO_BORDER_LINE_SEPARATOR,
+        ),
+        ("FORMAT_BOX_CHARS", *consts::FORMAT_BOX_CHARS),
+        ("FORMAT_ROUNDED", *consts::FORMAT_ROUNDED),
+        ("FORMAT_DOUBLE_BORDER", *consts::FORMAT_DOUBLE_BORDER),
+        ("FORMAT_MARKDOWN", *consts::FORMAT_MARKDOWN),
+        ("FORMAT_PSQL", *consts::FORMAT_PSQL),
+    ]
+}
@@ -0,0 +1,104 @@
+//! This module contains the options of the HTML export of tables
+use super::escape::{Escaper, HtmlEscaper};
+
+/// Options of the HTML export of tables, given to
+/// [`Table::print_html_with_options`](struct.Table.html#method.print_html_with_options),
+/// so that the output fits in existing web pages and their style sheets.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// use prettytable::HtmlOptions;
+/// # fn main() {
+/// let table = table!([Fr->"error"]);
+/// let options = HtmlOptions::new()
+///     .table_class("report")
+///     .cell_class("cell")
+///     .inline_styles(false);
+/// let mut out = Vec::new();
+/// table.print_html_with_options(&mut out, &options).unwrap();
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     "<table class=\"report\"><tr><td class=\"cell\" style=\"text-align: left;\">error</td></tr></table>"
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct HtmlOptions {
+    table_class: Option<String>,
+    row_class: Option<String>,
+    cell_class: Option<String>,
+    inline_styles: bool,
+}
+
+impl HtmlOptions {
+    /// Create the default options: no CSS class, and the style of the cells written inline
+    pub fn new() -> HtmlOptions {
+        HtmlOptions {
+            table_class: None,
+            row_class: None,
+            cell_class: None,
+            inline_styles: true,
+        }
+    }
+
+    /// Set the CSS class of the `<table>` element
+    pub fn table_class(mut self, class: &str) -> HtmlOptions {
+        self.table_class = Some(class.to_owned());
+        self
+    }
+
+    /// Set the CSS class of the `<tr>` elements of the rows
+    pub fn row_class(mut self, class: &str) -> HtmlOptions {
+        self.row_class = Some(class.to_owned());
+        self
+    }
+
+    /// Set the CSS class of the `<td>` elements of the cells
+    pub fn cell_class(mut self, class: &str) -> HtmlOptions {
+        self.cell_class = Some(class.to_owned());
+        self
+    }
+
+    /// Set whether the style attributes of the cells, like colors, bold or italic,
+    /// are written as inline CSS in their `style` attribute.
+    /// Alignment is always written, as it's part of the layout of the table
+    pub fn inline_styles(mut self, inline: bool) -> HtmlOptions {
+        self.inline_styles = inline;
+        self
+    }
+
+    /// Check whether the style attributes of the cells are written as inline CSS
+    pub(crate) fn has_inline_styles(&self) -> bool {
+        self.inline_styles
+    }
+
+    /// Get the ` class="..."` attribute of the `<table>` element, or an empty string
+    pub(crate) fn table_attr(&self) -> String {
+        class_attr(&self.table_class)
+    }
+
+    /// Get the ` class="..."` attribute of the `<tr>` elements, or an empty string
+    pub(crate) fn row_attr(&self) -> String {
+        class_attr(&self.row_class)
+    }
+
+    /// Get the ` class="..."` attribute of the `<td>` elements, or an empty string
+    pub(crate) fn cell_attr(&self) -> String {
+        class_attr(&self.cell_class)
+    }
+}
+
+impl Default for HtmlOptions {
+    fn default() -> HtmlOptions {
+        HtmlOptions::new()
+    }
+}
+
+/// Format the `class` attribute with the escaped `class`, if any
+fn class_attr(class: &Option<String>) -> String {
+    match *class {
+        Some(ref class) => format!(" class=\"{}\"", HtmlEscaper.escape(class)),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,210 @@
+//! This module contains the JSON export of tables
+use std::io::{Error, Write};
+
+use super::cell::{color2hex, Inherited};
+use super::escape::{Escaper, JsonEscaper};
+use super::format::Alignment;
+use super::{AsTableSlice, Attr, CaptionPosition, Cell, Row, Settings, Table, TableSlice};
+
+/// Write `text` as a JSON string
+fn write_str<T: Write + ?Sized>(out: &mut T, text: &str) -> Result<(), Error> {
+    write!(out, "\"{}\"", JsonEscaper.escape(text))
+}
+
+/// Write the content of the cells in `row` as a JSON array of strings
+fn write_row<T: Write + ?Sized>(out: &mut T, row: &Row) -> Result<(), Error> {
+    out.write_all(b"[")?;
+    for (i, cell) in row.iter().enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        write_str(out, &cell.get_content())?;
+    }
+    out.write_all(b"]")
+}
+
+/// Write the style of `cell` as a JSON object, taking the style inherited from its row into account
+fn write_cell_style<T: Write + ?Sized>(
+    out: &mut T,
+    cell: &Cell,
+    inherited: &Inherited,
+) -> Result<(), Error> {
+    let align = match cell
+        .get_explicit_alignment()
+        .or(inherited.align)
+        .unwrap_or(Alignment::LEFT)
+    {
+        Alignment::LEFT => "left",
+        Alignment::CENTER => "center",
+        Alignment::RIGHT => "right",
+        Alignment::JUSTIFY => "justify",
+    };
+    write!(out, "{{\"align\":\"{}\"", align)?;
+    if cell.get_hspan() > 1 {
+        write!(out, ",\"hspan\":{}", cell.get_hspan())?;
+    }
+    // Cell's own attributes override the inherited ones
+    let (mut bold, mut italic, mut underline) = (false, false, false);
+    let (mut color, mut background) = (None, None);
+    for attr in inherited.style().chain(cell.get_style()) {
+        match *attr {
+            Attr::Bold => bold = true,
+            Attr::Italic(b) => italic = b,
+            Attr::Underline(b) => underline = b,
+            Attr::ForegroundColor(c) => color = Some(color2hex(c)),
+            Attr::BackgroundColor(c) => background = Some(color2hex(c)),
+            _ => {}
+        }
+    }
+    for (name, set) in [("bold", bold), ("italic", italic), ("underline", underline)] {
+        if set {
+            write!(out, ",\"{}\":true", name)?;
+        }
+    }
+    for (name, value) in [("color", color), ("background", background)] {
+        if let Some(value) = value {
+            write!(out, ",\"{}\":\"{}\"", name, value)?;
+        }
+    }
+    out.write_all(b"}")
+}
+
+/// Write the style of the cells in `row` as a JSON array of objects,
+/// taking the table and column defaults from `settings` into account
+fn write_row_style<T: Write + ?Sized>(
+    out: &mut T,
+    row: &Row,
+    settings: &Settings,
+) -> Result<(), Error> {
+    out.write_all(b"[")?;
+    let mut column = 0;
+    for (i, cell) in row.iter().enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        write_cell_style(out, cell, &row.inherited(settings, column))?;
+        column += cell.get_hspan();
+    }
+    out.write_all(b"]")
+}
+
+impl<'a> TableSlice<'a> {
+    /// Write the table to `out` as a JSON object.
+    ///
+    /// Cells content is written as arrays of strings under the `titles`, `rows` and `footer` keys,
+    /// and the caption, if any, under the `caption` and `caption_position` keys.
+    /// If `include_style` is `true`, the style of each cell (alignment, span, colors, bold, italic
+    /// and underline) is written under a parallel `style` key, with the same structure.
+    /// Colors are written as CSS hex values, like in HTML output.
+    /// JSON being a subset of YAML, the output can also be loaded by YAML parsers.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let table = table!([Fr->"error", "disk full"]);
+    /// let mut out = Vec::new();
+    /// table.to_json(&mut out, true).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     r##"{"rows":[["error","disk full"]],"style":{"rows":[[{"align":"left","color":"#aa0000"},{"align":"left"}]]}}"##
+    /// );
+    /// # }
+    /// ```
+    pub fn to_json<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        include_style: bool,
+    ) -> Result<(), Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).to_json(out, include_style);
+        }
+        out.write_all(b"{")?;
+        self.write_json_sections(out, write_row)?;
+        if let Some((ref caption, position)) = self.settings.caption {
+            out.write_all(b",\"caption\":")?;
+            write_str(out, caption)?;
+            let position = match position {
+                CaptionPosition::Above => "above",
+                CaptionPosition::Below => "below",
+            };
+            write!(out, ",\"caption_position\":\"{}\"", position)?;
+        }
+        if include_style {
+            out.write_all(b",\"style\":{")?;
+            self.write_json_sections(out, |out, row| write_row_style(out, row, self.settings))?;
+            out.write_all(b"}")?;
+        }
+        out.write_all(b"}")?;
+        out.flush()
+    }
+
+    /// Write the `titles`, `rows` and `footer` keys of a JSON object, with `f` writing each row.
+    /// Surrounding braces are not written
+    fn write_json_sections<T: Write + ?Sized, F>(&self, out: &mut T, f: F) -> Result<(), Error>
+    where
+        F: Fn(&mut T, &Row) -> Result<(), Error>,
+    {
+        if let Some(ref titles) = *self.titles {
+            out.write_all(b"\"titles\":")?;
+            f(out, titles)?;
+            out.write_all(b",")?;
+        }
+        out.write_all(b"\"rows\":[")?;
+        for (i, row) in self.exported_rows().enumerate() {
+            if i > 0 {
+                out.write_all(b",")?;
+            }
+            f(out, &row)?;
+        }
+        out.write_all(b"]")?;
+        if let Some(ref footer) = *self.footer {
+            out.write_all(b",\"footer\":")?;
+            f(out, footer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Table {
+    /// Write the table to `out` as a JSON object, optionally including the style of each cell.
+    /// See [`TableSlice::to_json`](struct.TableSlice.html#method.to_json) for details
+    pub fn to_json<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        include_style: bool,
+    ) -> Result<(), Error> {
+        self.as_slice().to_json(out, include_style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::StringWriter;
+    use crate::{row, table};
+
+    #[test]
+    fn to_json() {
+        let mut table = table!(["a\"b", "c"], [H2->"d"]);
+        table.set_titles(row![bFg->"t1", "t2"]);
+        let mut out = StringWriter::new();
+        table.to_json(&mut out, false).unwrap();
+        assert_eq!(
+            out.as_string(),
+            r#"{"titles":["t1","t2"],"rows":[["a\"b","c"],["d"]]}"#
+        );
+        table.set_footer(row!["total"].style_spec("rBr"));
+        table[0][1].style(crate::Attr::Italic(true));
+        let mut out = StringWriter::new();
+        table.to_json(&mut out, true).unwrap();
+        assert_eq!(
+            out.as_string(),
+            concat!(
+                r#"{"titles":["t1","t2"],"rows":[["a\"b","c"],["d"]],"footer":["total"],"#,
+                r##""style":{"titles":[{"align":"left","bold":true,"color":"#00aa00"},{"align":"left"}],"##,
+                r#""rows":[[{"align":"left"},{"align":"left","italic":true}],[{"align":"left","hspan":2}]],"#,
+                r##""footer":[{"align":"right","background":"#aa0000"}]}}"##
+            )
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//! This module contains the LaTeX export of tables
+use std::io::{Error, Write};
+
+use super::escape::{Escaper, LatexEscaper};
+use super::format::{Alignment, ColumnPosition, LinePosition, TableFormat};
+use super::utils::NEWLINE;
+use super::{AsTableSlice, Cell, Row, Table, TableSlice};
+
+/// Get the LaTeX column specifier of `align`
+fn align_spec(align: Alignment) -> char {
+    match align {
+        Alignment::CENTER => 'c',
+        Alignment::RIGHT => 'r',
+        _ => 'l',
+    }
+}
+
+/// Get the content of `cell` escaped with `escaper`. Lines of multi-line cells are stacked
+/// in a nested `tabular`, aligned according to `align`
+fn cell_content<E: Escaper + ?Sized>(cell: &Cell, align: Alignment, escaper: &E) -> String {
+    let lines = (0..cell.get_height())
+        .map(|i| escaper.escape(cell.get_line(i)))
+        .collect::<Vec<_>>();
+    if lines.len() <= 1 {
+        return lines.concat();
+    }
+    format!(
+        "\\begin{{tabular}}[t]{{@{{}}{}@{{}}}}{}\\end{{tabular}}",
+        align_spec(align),
+        lines.join(" \\\\ ")
+    )
+}
+
+/// Write the rule drawn by a line separator at `pos`, with `booktabs` rules or `\hline`
+fn write_rule<T: Write + ?Sized>(
+    out: &mut T,
+    pos: LinePosition,
+    booktabs: bool,
+) -> Result<(), Error> {
+    let rule: &[u8] = match pos {
+        _ if !booktabs => b"\\hline",
+        LinePosition::Top => b"\\toprule",
+        LinePosition::Bottom => b"\\bottomrule",
+        _ => b"\\midrule",
+    };
+    out.write_all(rule)?;
+    out.write_all(NEWLINE)
+}
+
+/// Vertical rules of a LaTeX table, drawn where the format has borders and column separators
+struct VerticalRules {
+    left: bool,
+    intern: bool,
+    right: bool,
+}
+
+impl VerticalRules {
+    /// Get the vertical rules matching `format`, or none with `booktabs` rules
+    fn new(format: &TableFormat, booktabs: bool) -> VerticalRules {
+        let has = |pos| !booktabs && format.get_column_separator(pos).is_some();
+        VerticalRules {
+            left: has(ColumnPosition::Left),
+            intern: has(ColumnPosition::Intern),
+            right: has(ColumnPosition::Right),
+        }
+    }
+
+    /// Get the specifier of a column aligned with `align`, starting at column `column`
+    /// and spanning `span` columns out of `column_num`
+    fn spec(&self, align: Alignment, column: usize, span: usize, column_num: usize) -> String {
+        let mut spec = String::new();
+        if column == 0 && self.left {
+            spec.push('|');
+        }
+        spec.push(align_spec(align));
+        let last = column + span >= column_num;
+        if (last && self.right) || (!last && self.intern) {
+            spec.push('|');
+        }
+        spec
+    }
+}
+
+impl<'a> TableSlice<'a> {
+    /// Write the table to `out` as a LaTeX `tabular` environment.
+    ///
+    /// Columns are aligned according to the first data row, like alignment markers
+    /// in markdown formats, and are separated by vertical rules where the table format
+    /// has borders and column separators. Rules are drawn where the format has line separators,
+    /// as `\hline`, or as `\toprule`, `\midrule` and `\bottomrule` if `booktabs` is `true`,
+    /// in which case no vertical rule is drawn.
+    /// Spanning cells and cells aligned differently from their column are written
+    /// with `\multicolumn`, and LaTeX special characters `& % $ # _ { } ~ ^ \` are escaped.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// use prettytable::format::Alignment;
+    /// # fn main() {
+    /// let mut table = table!(["a_b", "1"], ["50%", "22"]);
+    /// table.set_titles(row!["name", "n"]);
+    /// table.set_column_alignment(1, Alignment::RIGHT);
+    /// let mut out = Vec::new();
+    /// table.to_latex(&mut out, false).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap().replace("\r\n", "\n"),
+    ///     r"\begin{tabular}{|l|r|}
+    /// \hline
+    /// name & n \\
+    /// \hline
+    /// a\_b & 1 \\
+    /// \hline
+    /// 50\% & 22 \\
+    /// \hline
+    /// \end{tabular}
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn to_latex<T: Write + ?Sized>(&self, out: &mut T, booktabs: bool) -> Result<(), Error> {
+        self.to_latex_with_escaper(out, booktabs, &LatexEscaper)
+    }
+
+    /// Write the table to `out` as a LaTeX `tabular` environment, like `to_latex()`,
+    /// escaping cells content with `escaper` instead of `LatexEscaper`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// use prettytable::escape::NoEscaper;
+    /// # fn main() {
+    /// // Cells already contain LaTeX markup
+    /// let table = table!([r"\textbf{bold}", r"$x^2$"]);
+    /// let mut out = Vec::new();
+    /// table.to_latex_with_escaper(&mut out, true, &NoEscaper).unwrap();
+    /// assert!(String::from_utf8(out).unwrap().contains(r"\textbf{bold} & $x^2$ \\"));
+    /// # }
+    /// ```
+    pub fn to_latex_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        booktabs: bool,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering
+                .slice(self)
+                .to_latex_with_escaper(out, booktabs, escaper);
+        }
+        let column_num = self.get_column_num();
+        let aligns = self.column_alignments(column_num);
+        let rules = VerticalRules::new(self.format, booktabs);
+        let spec = aligns
+            .iter()
+            .enumerate()
+            .map(|(i, &align)| rules.spec(align, i, 1, column_num))
+            .collect::<String>();
+        write!(out, "\\begin{{tabular}}{{{}}}", spec)?;
+        out.write_all(NEWLINE)?;
+        let rule = |out: &mut T, format: &TableFormat, pos| {
+            if format.has_line_separator(pos) {
+                write_rule(out, pos, booktabs)
+            } else {
+                Ok(())
+            }
+        };
+        rule(out, self.format, LinePosition::Top)?;
+        if let Some(ref h) = self.settings.heading {
+            self.write_latex_row(out, h, &aligns, &rules, escaper)?;
+            rule(out, self.format, LinePosition::Heading)?;
+        }
+        let groups = &self.settings.title_groups;
+        for (i, group) in groups.iter().enumerate() {
+            self.write_latex_row(out, group, &aligns, &rules, escaper)?;
+            // Without titles, the last title groups are separated from the rows like titles
+            match groups.get(i + 1).or(self.titles.as_ref()) {
+                Some(_) => rule(out, self.format, LinePosition::Intern)?,
+                None => rule(out, self.format, LinePosition::Title)?,
+            }
+        }
+        if let Some(ref t) = *self.titles {
+            self.write_latex_row(out, t, &aligns, &rules, escaper)?;
+            rule(out, self.format, LinePosition::Title)?;
+        }
+        let mut iter = self.rendered_rows().enumerate().peekable();
+        while let Some((i, r)) = iter.next() {
+            if r.is_separator() {
+                write_rule(out, LinePosition::Intern, booktabs)?;
+            } else {
+                self.write_latex_row(out, &r, &aligns, &rules, escaper)?;
+            }
+            if let Some((_, next)) = iter.peek() {
+                match self.row_separator(&r, i + 1, next) {
+                    (pos, None) => rule(out, next.get_format().unwrap_or(self.format), pos)?,
+                    (pos, Some(true)) => write_rule(out, pos, booktabs)?,
+                    (_, Some(false)) => {}
+                }
+            }
+        }
+        if let Some(ref f) = *self.footer {
+            rule(
+                out,
+                f.get_format().unwrap_or(self.format),
+                LinePosition::Footer,
+            )?;
+            self.write_latex_row(out, f, &aligns, &rules, escaper)?;
+        }
+        rule(out, self.format, LinePosition::Bottom)?;
+        out.write_all(b"\\end{tabular}")?;
+        out.write_all(NEWLINE)?;
+        out.flush()
+    }
+
+    /// Write `row` as a line of a LaTeX `tabular`, whose columns are aligned with `aligns`
+    /// and separated with `rules`, escaping cells content with `escaper`
+    fn write_latex_row<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        row: &Row,
+        aligns: &[Alignment],
+        rules: &VerticalRules,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        let column_num = aligns.len();
+        let mut cells = Vec::new();
+        let mut column = 0;
+        for cell in row.iter() {
+            if column >= column_num {
+                break;
+            }
+            // Full width rows are printed in their first cell
+            let span = if row.is_full_width() {
+                column_num
+            } else {
+                cell.get_hspan().min(column_num - column)
+            };
+            let align = cell
+                .get_explicit_alignment()
+                .or(row.inherited(self.settings, column).align)
+                .unwrap_or(Alignment::LEFT);
+            let content = cell_content(cell, align, escaper);
+            cells.push(if span > 1 || align != aligns[column] {
+                format!(
+                    "\\multicolumn{{{}}}{{{}}}{{{}}}",
+                    span,
+                    rules.spec(align, column, span, column_num),
+                    content
+                )
+            } else {
+                content
+            });
+            column += span;
+        }
+        write!(out, "{} \\\\", cells.join(" & "))?;
+        out.write_all(NEWLINE)
+    }
+}
+
+impl Table {
+    /// Write the table to `out` as a LaTeX `tabular` environment, optionally with `booktabs` rules.
+    /// See [`TableSlice::to_latex`](struct.TableSlice.html#method.to_latex) for details
+    pub fn to_latex<T: Write + ?Sized>(&self, out: &mut T, booktabs: bool) -> Result<(), Error> {
+        self.as_slice().to_latex(out, booktabs)
+    }
+
+    /// Write the table to `out` as a LaTeX `tabular` environment, escaping cells content
+    /// with `escaper`.
+    /// See [`TableSlice::to_latex_with_escaper`](struct.TableSlice.html#method.to_latex_with_escaper)
+    /// for details
+    pub fn to_latex_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        booktabs: bool,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        self.as_slice()
+            .to_latex_with_escaper(out, booktabs, escaper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::consts::{FORMAT_CLEAN, FORMAT_NO_LINESEP_WITH_TITLE};
+    use crate::utils::StringWriter;
+    use crate::{row, table};
+
+    #[test]
+    fn to_latex() {
+        let mut table = table!([r->"a&b", "~^\\"], [H2->"{span}"], ["x\ny", c->"#$"]);
+        table.set_titles(row!["t_1", "t2"]);
+        table.set_format(*FORMAT_NO_LINESEP_WITH_TITLE);
+        let mut out = StringWriter::new();
+        table.to_latex(&mut out, false).unwrap();
+        let expected = r"\begin{tabular}{|r|l|}
+\hline
+\multicolumn{1}{|l|}{t\_1} & t2 \\
+\hline
+a\&b & \textasciitilde{}\textasciicircum{}\textbackslash{} \\
+\multicolumn{2}{|l|}{\{span\}} \\
+\multicolumn{1}{|l|}{\begin{tabular}[t]{@{}l@{}}x \\ y\end{tabular}} & \multicolumn{1}{c|}{\#\$} \\
+\hline
+\end{tabular}
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        table.set_format(*FORMAT_CLEAN);
+        table.set_footer(row!["f1", "f2"]);
+        table[0].set_separator_after(true);
+        let mut out = StringWriter::new();
+        table.to_latex(&mut out, true).unwrap();
+        let expected = r"\begin{tabular}{rl}
+\multicolumn{1}{l}{t\_1} & t2 \\
+a\&b & \textasciitilde{}\textasciicircum{}\textbackslash{} \\
+\midrule
+\multicolumn{2}{l}{\{span\}} \\
+\multicolumn{1}{l}{\begin{tabular}[t]{@{}l@{}}x \\ y\end{tabular}} & \multicolumn{1}{c}{\#\$} \\
+\multicolumn{1}{l}{f1} & f2 \\
+\end{tabular}
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+        let mut out = StringWriter::new();
+        let upper = |s: &str| s.to_uppercase();
+        table.to_latex_with_escaper(&mut out, true, &upper).unwrap();
+        assert!(out.as_string().contains("A&B & ~^\\ \\"));
+        assert!(out.as_string().contains("\\multicolumn{1}{l}{F1} & F2 \\"));
+    }
+}
@@ -0,0 +1,382 @@
+//! This module contains the printing of several tables next to each other, or in a grid
+use std::fmt;
+use std::io::{Error, Write};
+
+use super::utils::{display_width, StringWriter, NEWLINE};
+use super::{AsTableSlice, TableSlice};
+
+/// Get the width of the widest line of `block`
+fn block_width(block: &str) -> usize {
+    block.lines().map(display_width).max().unwrap_or(0)
+}
+
+/// Get the line ending of the first of `slices`, or the platform one if there is none
+fn newline_of<'a, I: IntoIterator<Item = &'a TableSlice<'a>>>(slices: I) -> &'static [u8] {
+    slices
+        .into_iter()
+        .next()
+        .map_or(NEWLINE, |s| s.format.newline_bytes())
+}
+
+/// Print the `blocks` of text next to each other, separated by `gutter` spaces,
+/// each block being padded to its width in `widths`, and end their lines with `newline`.
+/// Blocks are aligned on their top line, and shorter blocks are completed with blanks.
+/// Returns the number of printed lines
+fn print_blocks<T: Write + ?Sized>(
+    out: &mut T,
+    blocks: &[String],
+    widths: &[usize],
+    gutter: usize,
+    newline: &[u8],
+) -> Result<usize, Error> {
+    let lines: Vec<Vec<&str>> = blocks.iter().map(|b| b.lines().collect()).collect();
+    let height = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    for i in 0..height {
+        // Blocks after the last one having this line are not padded, to avoid trailing spaces
+        let last = lines
+            .iter()
+            .rposition(|l| l.get(i).map_or(false, |s| !s.is_empty()))
+            .unwrap_or(0);
+        for (k, block) in lines.iter().enumerate().take(last + 1) {
+            let line = block.get(i).copied().unwrap_or("");
+            out.write_all(line.as_bytes())?;
+            if k < last {
+                let fill = widths[k] - display_width(line) + gutter;
+                out.write_all(&vec![b' '; fill])?;
+            }
+        }
+        out.write_all(newline)?;
+    }
+    Ok(height)
+}
+
+/// Print the `slices` next to each other, separated by `gutter` spaces.
+/// Returns the number of printed lines
+fn print_slices<T: Write + ?Sized>(
+    out: &mut T,
+    slices: &[TableSlice],
+    gutter: usize,
+) -> Result<usize, Error> {
+    let mut blocks = Vec::with_capacity(slices.len());
+    for slice in slices {
+        let mut writer = StringWriter::new();
+        slice.print(&mut writer)?;
+        blocks.push(writer.into_string());
+    }
+    let widths: Vec<usize> = blocks.iter().map(|b| block_width(b)).collect();
+    print_blocks(out, &blocks, &widths, gutter, newline_of(slices))
+}
+
+/// Print the tables `left` and `right` next to each other to `out`, separated by `gutter` spaces,
+/// like for before/after comparisons. Tables are aligned on their top line,
+/// and may have different heights. Styles are not applied, and lines end with
+/// the line ending of the format of `left`.
+///
+/// Returns the number of printed lines
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # use prettytable::print_side_by_side;
+/// # fn main() {
+/// let before = table!(["a", "1"], ["b", "2"]);
+/// let after = table!(["a", "3"]);
+/// // +---+---+  +---+---+
+/// // | a | 1 |  | a | 3 |
+/// // +---+---+  +---+---+
+/// // | b | 2 |
+/// // +---+---+
+/// print_side_by_side(&mut std::io::stdout(), &before, &after, 2).unwrap();
+/// # }
+/// ```
+pub fn print_side_by_side<T, A, B>(
+    out: &mut T,
+    left: &A,
+    right: &B,
+    gutter: usize,
+) -> Result<usize, Error>
+where
+    T: Write + ?Sized,
+    A: AsTableSlice + ?Sized,
+    B: AsTableSlice + ?Sized,
+{
+    print_slices(out, &[left.as_slice(), right.as_slice()], gutter)
+}
+
+/// Print any number of `tables` next to each other to `out`, separated by `gutter` spaces,
+/// so that several related small tables can share a screen. Tables are aligned on their
+/// top line, and may have different heights. Styles are not applied, and lines end with
+/// the line ending of the format of the first table.
+///
+/// Returns the number of printed lines
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # use prettytable::print_tables_side_by_side;
+/// # fn main() {
+/// let cpu = table!(["cpu", "12%"]);
+/// let mem = table!(["mem", "3.2G"]);
+/// let disk = table!(["disk", "71%"]);
+/// // +-----+-----+ +-----+------+ +------+-----+
+/// // | cpu | 12% | | mem | 3.2G | | disk | 71% |
+/// // +-----+-----+ +-----+------+ +------+-----+
+/// print_tables_side_by_side(&mut std::io::stdout(), &[&cpu, &mem, &disk], 1).unwrap();
+/// # }
+/// ```
+pub fn print_tables_side_by_side<T: Write + ?Sized>(
+    out: &mut T,
+    tables: &[&dyn AsTableSlice],
+    gutter: usize,
+) -> Result<usize, Error> {
+    let slices: Vec<TableSlice> = tables.iter().map(|t| t.as_slice()).collect();
+    print_slices(out, &slices, gutter)
+}
+
+/// Several tables arranged in rows and columns, for dashboards made of small tables.
+///
+/// Tables are added from left to right, starting a new row of the grid once it has
+/// the given number of columns. Tables in a grid column start at the same position,
+/// and the rows of the grid are aligned on their top line. Each table may have
+/// a caption printed above it, the other tables of its row being shifted down
+/// to keep them aligned. Styles are not applied, and lines end with the line ending
+/// of the format of the first table.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// use prettytable::Grid;
+/// # fn main() {
+/// let cpu = table!(["cpu", "12%"]);
+/// let mem = table!(["mem", "3.2G"]);
+/// let disk = table!(["disk", "71%"]);
+/// let mut grid = Grid::new(2);
+/// grid.add_with_caption(&cpu, "CPU").add(&mem).add(&disk);
+/// assert_eq!(
+///     grid.to_string().replace("\r\n", "\n"),
+///     "\
+/// CPU
+/// +-----+-----+   +-----+------+
+/// | cpu | 12% |   | mem | 3.2G |
+/// +-----+-----+   +-----+------+
+///
+/// +------+-----+
+/// | disk | 71% |
+/// +------+-----+
+/// "
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Grid<'a> {
+    tables: Vec<(TableSlice<'a>, Option<String>)>,
+    columns: usize,
+    gutter: usize,
+    spacing: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// Create an empty grid with `columns` tables per row, and at least 1.
+    /// Tables are separated by 2 spaces, and rows by 1 blank line
+    pub fn new(columns: usize) -> Grid<'a> {
+        Grid {
+            tables: Vec::new(),
+            columns: columns.max(1),
+            gutter: 2,
+            spacing: 1,
+        }
+    }
+
+    /// Add `table` after the last table of the grid
+    pub fn add<A: AsTableSlice + ?Sized>(&mut self, table: &'a A) -> &mut Grid<'a> {
+        self.tables.push((table.as_slice(), None));
+        self
+    }
+
+    /// Add `table` after the last table of the grid, with `caption` printed above it
+    pub fn add_with_caption<A: AsTableSlice + ?Sized>(
+        &mut self,
+        table: &'a A,
+        caption: &str,
+    ) -> &mut Grid<'a> {
+        self.tables
+            .push((table.as_slice(), Some(caption.to_owned())));
+        self
+    }
+
+    /// Set the number of spaces between the columns of the grid
+    pub fn set_gutter(&mut self, gutter: usize) {
+        self.gutter = gutter;
+    }
+
+    /// Set the number of blank lines between the rows of the grid
+    pub fn set_spacing(&mut self, spacing: usize) {
+        self.spacing = spacing;
+    }
+
+    /// Print the grid to `out`, and return the number of printed lines
+    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
+        let newline = newline_of(self.tables.iter().map(|(t, _)| t));
+        let mut blocks = Vec::with_capacity(self.tables.len());
+        for row in self.tables.chunks(self.columns) {
+            // Captions are completed with blank lines so that tables in a row start on the same line
+            let caption_height = row
+                .iter()
+                .filter_map(|(_, c)| c.as_ref())
+                .map(|c| c.lines().count())
+                .max()
+                .unwrap_or(0);
+            for (table, caption) in row {
+                let mut writer = StringWriter::new();
+                let caption = caption.as_deref().unwrap_or("");
+                for line in caption.lines() {
+                    writer.write_all(line.as_bytes())?;
+                    writer.write_all(newline)?;
+                }
+                for _ in caption.lines().count()..caption_height {
+                    writer.write_all(newline)?;
+                }
+                table.print(&mut writer)?;
+                blocks.push(writer.into_string());
+            }
+        }
+        let mut widths = vec![0; self.columns];
+        for (i, block) in blocks.iter().enumerate() {
+            let column = i % self.columns;
+            widths[column] = widths[column].max(block_width(block));
+        }
+        let mut height = 0;
+        for (i, row) in blocks.chunks(self.columns).enumerate() {
+            if i > 0 {
+                for _ in 0..self.spacing {
+                    out.write_all(newline)?;
+                }
+                height += self.spacing;
+            }
+            height += print_blocks(out, row, &widths, self.gutter, newline)?;
+        }
+        out.flush()?;
+        Ok(height)
+    }
+}
+
+impl<'a> fmt::Display for Grid<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut writer = StringWriter::new();
+        if self.print(&mut writer).is_err() {
+            return Err(fmt::Error);
+        }
+        fmt.write_str(writer.as_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_side_by_side, print_tables_side_by_side, Grid};
+    use crate::format::Newline;
+    use crate::utils::StringWriter;
+    use crate::{table, Slice};
+
+    #[test]
+    fn side_by_side() {
+        let left = table!(["a", "1"], ["bcd", "2"]);
+        let right = table!(["e"]);
+        let mut out = StringWriter::new();
+        assert_eq!(
+            print_side_by_side(&mut out, &left, &right.slice(..), 1).unwrap(),
+            5
+        );
+        let expected = "\
++-----+---+ +---+
+| a   | 1 | | e |
++-----+---+ +---+
+| bcd | 2 |
++-----+---+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        let mut out = StringWriter::new();
+        assert_eq!(print_side_by_side(&mut out, &right, &left, 0).unwrap(), 5);
+        let expected = "\
++---++-----+---+
+| e || a   | 1 |
++---++-----+---+
+     | bcd | 2 |
+     +-----+---+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+    }
+
+    #[test]
+    fn tables_side_by_side() {
+        let a = table!(["a"], ["b"]);
+        let b = table!(["cd"]);
+        let c = table!(["e"], ["f"], ["g"]);
+        let mut out = StringWriter::new();
+        assert_eq!(
+            print_tables_side_by_side(&mut out, &[&a, &b.slice(..), &c], 2).unwrap(),
+            7
+        );
+        let expected = "\
++---+  +----+  +---+
+| a |  | cd |  | e |
++---+  +----+  +---+
+| b |          | f |
++---+          +---+
+               | g |
+               +---+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        let mut out = StringWriter::new();
+        assert_eq!(print_tables_side_by_side(&mut out, &[], 2).unwrap(), 0);
+        assert_eq!(out.as_string(), "");
+    }
+
+    #[test]
+    fn grid() {
+        let a = table!(["a"]);
+        let b = table!(["bcd"], ["e"]);
+        let mut grid = Grid::new(2);
+        grid.add(&a).add_with_caption(&b, "b").add(&b).add(&a);
+        grid.set_gutter(1);
+        grid.set_spacing(0);
+        let mut out = StringWriter::new();
+        assert_eq!(grid.print(&mut out).unwrap(), 11);
+        let expected = "        b
++---+   +-----+
+| a |   | bcd |
++---+   +-----+
+        | e   |
+        +-----+
++-----+ +---+
+| bcd | | a |
++-----+ +---+
+| e   |
++-----+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+        assert_eq!(Grid::new(0).to_string(), "");
+    }
+    #[test]
+    fn newline() {
+        let mut a = table!(["a"]);
+        a.get_format().newline(Newline::CrLf);
+        let b = table!(["b"], ["c"]);
+        let mut out = StringWriter::new();
+        print_side_by_side(&mut out, &a, &b, 1).unwrap();
+        let expected = "\
++---+ +---+\r
+| a | | b |\r
++---+ +---+\r
+      | c |\r
+      +---+\r
+";
+        assert_eq!(out.as_string(), expected);
+
+        let mut grid = Grid::new(1);
+        grid.add_with_caption(&a, "a").add(&a);
+        let expected = "a\r\n+---+\r\n| a |\r\n+---+\r\n\r\n+---+\r\n| a |\r\n+---+\r\n";
+        assert_eq!(grid.to_string(), expected);
+    }
+}
@@ -9,19 +9,34 @@
 #[macro_use]
 extern crate lazy_static;
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
-use std::io::{self, Error, Write};
+use std::io::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, AddAssign, Bound, Index, IndexMut, RangeBounds};
 use std::slice::{Iter, IterMut};
 
 pub use term::{color, Attr};
 pub(crate) use term::{stdout, Terminal};
 
 mod cell;
+mod error;
+pub mod escape;
+mod expanded;
 pub mod format;
+mod html;
+mod json;
+mod latex;
+mod layout;
+mod live;
+mod markdown;
+mod render;
 mod row;
 mod utils;
+mod validate;
+mod view;
+mod writer;
 
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -29,17 +44,218 @@ pub mod csv;
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
-pub use cell::Cell;
-use format::{consts, LinePosition, TableFormat};
-pub use row::Row;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use cell::{register_style_spec, unregister_style_spec, Cell};
+pub use error::Error;
+use escape::{Escaper, HtmlEscaper};
+use format::{
+    consts, Alignment, BorderPrinter, ColumnLine, ColumnPosition, LinePosition, Plain, Styled,
+    TableFormat,
+};
+pub use html::HtmlOptions;
+pub use layout::{print_side_by_side, print_tables_side_by_side, Grid};
+pub use live::LiveTable;
+use render::{Rendering, RowView};
+pub use row::{NamedRow, Row, RowBuilder};
 use utils::StringWriter;
+pub use validate::Diagnostic;
+pub use view::TableView;
+pub use writer::TableWriter;
 
-/// An owned printable table
+/// An owned printable table.
+///
+/// Tables are equal when they have the same format, titles, rows, footer and settings,
+/// compared cell by cell, so they can be compared directly instead of their rendering
 #[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Table {
     format: Box<TableFormat>,
     titles: Box<Option<Row>>,
     rows: Vec<Row>,
+    footer: Box<Option<Row>>,
+    settings: Box<Settings>,
+}
+
+/// Strategy used by `Table::sample` to select the rows to keep
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SampleStrategy {
+    /// Keep the first rows
+    Head,
+    /// Keep the first and the last rows, eliding the ones in the middle
+    HeadTail,
+    /// Keep rows picked at random with the given seed, in their original order.
+    /// The same seed always selects the same rows
+    Random(u64),
+}
+
+/// Aggregation of the numbers in a column, printed in the footer.
+/// It's set with `Table::set_aggregate_footer`, and each variant holds the position of the column.
+/// Cells not containing a number are ignored
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Aggregate {
+    /// Sum of the numbers
+    Sum(usize),
+    /// Average of the numbers
+    Avg(usize),
+    /// Count of the numbers
+    Count(usize),
+    /// Smallest number
+    Min(usize),
+    /// Largest number
+    Max(usize),
+}
+
+impl Aggregate {
+    /// Get the position of the aggregated column
+    pub fn column(self) -> usize {
+        match self {
+            Aggregate::Sum(c)
+            | Aggregate::Avg(c)
+            | Aggregate::Count(c)
+            | Aggregate::Min(c)
+            | Aggregate::Max(c) => c,
+        }
+    }
+
+    /// Compute the aggregate of `values`, and format it with `precision` decimal places.
+    /// Averages get at least 2 decimal places.
+    /// An empty string is returned for the average, minimum or maximum of no value
+    fn compute(self, values: &[f64], precision: usize) -> String {
+        let fold = |f: fn(f64, f64) -> f64| values.iter().copied().reduce(f);
+        let value = match self {
+            Aggregate::Count(_) => return values.len().to_string(),
+            Aggregate::Sum(_) => Some(values.iter().fold(0.0, |sum, v| sum + v)),
+            Aggregate::Avg(_) if values.is_empty() => None,
+            Aggregate::Avg(_) => {
+                let avg = values.iter().sum::<f64>() / values.len() as f64;
+                return format!("{:.*}", precision.max(2), avg);
+            }
+            Aggregate::Min(_) => fold(f64::min),
+            Aggregate::Max(_) => fold(f64::max),
+        };
+        value
+            .map(|v| format!("{:.*}", precision, v))
+            .unwrap_or_default()
+    }
+}
+
+/// Position of the caption set with `Table::set_caption`
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaptionPosition {
+    /// The caption is printed above the table
+    Above,
+    /// The caption is printed below the table
+    Below,
+}
+
+/// Width constraint of a column, set with `Table::set_column_width`
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnWidth {
+    /// The column is exactly this wide
+    Fixed(usize),
+    /// The column is at least this wide
+    Min(usize),
+    /// The column is at most this wide
+    Max(usize),
+}
+
+/// Table settings which are not part of the `TableFormat`
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+struct Settings {
+    max_render_size: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "serialize::attrs"))]
+    style: Vec<Attr>,
+    heading: Option<Row>,
+    title_groups: Vec<Row>,
+    caption: Option<(String, CaptionPosition)>,
+    aggregates: Vec<Aggregate>,
+    columns: Vec<ColumnSettings>,
+    empty_placeholder: Option<String>,
+    max_width: Option<usize>,
+    no_auto_fit: bool,
+    row_numbers: bool,
+    summary: bool,
+    hide_empty_columns: bool,
+    titles_align: Option<Alignment>,
+    right_to_left: bool,
+    repeat_titles: Option<usize>,
+    separator_every: Option<usize>,
+    ascii_borders: bool,
+    #[cfg_attr(feature = "serde", serde(with = "serialize::attr"))]
+    stripe_style: Option<Attr>,
+}
+
+/// Settings of a single column
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+struct ColumnSettings {
+    hidden: bool,
+    hide_if_empty: bool,
+    #[cfg_attr(feature = "serde", serde(with = "serialize::attrs"))]
+    style: Vec<Attr>,
+    align: Option<Alignment>,
+    min_width: usize,
+    max_width: Option<usize>,
+    fixed_width: bool,
+    wrap: bool,
+    title_line: Option<char>,
+}
+
+/// Printing of the rows and borders of a table to an output of type `T`
+trait Printer<T: Write + ?Sized>: BorderPrinter<T> {
+    /// Print `row` to `out`, returning the number of printed lines
+    fn print_row(
+        &self,
+        row: &Row,
+        out: &mut T,
+        format: &TableFormat,
+        col_width: &[usize],
+        settings: &Settings,
+    ) -> Result<usize, io::Error>;
+}
+
+impl<T: Write + ?Sized> Printer<T> for Plain {
+    fn print_row(
+        &self,
+        row: &Row,
+        out: &mut T,
+        format: &TableFormat,
+        col_width: &[usize],
+        settings: &Settings,
+    ) -> Result<usize, io::Error> {
+        row.print(out, format, col_width, settings)
+    }
+}
+
+impl<T: Terminal + ?Sized> Printer<T> for Styled {
+    fn print_row(
+        &self,
+        row: &Row,
+        out: &mut T,
+        format: &TableFormat,
+        col_width: &[usize],
+        settings: &Settings,
+    ) -> Result<usize, io::Error> {
+        row.print_term(out, format, col_width, settings)
+    }
 }
 
 /// A borrowed immutable `Table` slice
@@ -66,17 +282,22 @@ pub struct TableSlice<'a> {
     format: &'a TableFormat,
     titles: &'a Option<Row>,
     rows: &'a [Row],
+    footer: &'a Option<Row>,
+    settings: &'a Settings,
+    /// Sorted indices of the rows selected by a `FilteredView`, or `None` to select all of them
+    selection: Option<&'a [usize]>,
+    /// Range of the columns selected with `slice_columns()`, or `None` to select all of them
+    columns: Option<(usize, usize)>,
+    /// Transformations applied to the rows while they're rendered, or `None` to render them as is
+    view: Option<&'a RowView>,
 }
 
 impl<'a> TableSlice<'a> {
     /// Compute and return the number of column
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
     fn get_column_num(&self) -> usize {
-        let mut cnum = match *self.titles {
-            Some(ref t) => t.column_count(),
-            None => 0,
-        };
-        for r in self.rows {
+        let mut cnum = 0;
+        for r in self.all_rows() {
             let l = r.column_count();
             if l > cnum {
                 cnum = l;
@@ -85,6 +306,135 @@ impl<'a> TableSlice<'a> {
         cnum
     }
 
+    /// Iterate over title groups, titles, rendered rows and footer
+    fn all_rows(&self) -> impl Iterator<Item = Cow<'a, Row>> {
+        self.settings
+            .title_groups
+            .iter()
+            .chain(self.titles.iter())
+            .map(Cow::Borrowed)
+            .chain(self.rendered_rows())
+            .chain(self.footer.iter().map(Cow::Borrowed))
+    }
+
+    /// Iterate over the selected rows which are not hidden
+    fn visible_rows(&self) -> impl Iterator<Item = &'a Row> {
+        let selection = self.selection;
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| !matches!(selection, Some(s) if s.binary_search(i).is_err()))
+            .map(|(_, r)| r)
+            .filter(|r| !r.is_hidden())
+    }
+
+    /// Iterate over the visible rows, with the number of rows which are not full width
+    /// up to each of them, which is the number printed for them in the row numbers column
+    fn numbered_rows(&self) -> impl Iterator<Item = (usize, &'a Row)> {
+        let mut count = 0;
+        self.visible_rows().map(move |r| {
+            if !r.is_full_width() {
+                count += 1;
+            }
+            (count, r)
+        })
+    }
+
+    /// Get `row`, which is the visible row `count` as given by `numbered_rows()`,
+    /// transformed as it must be rendered
+    fn render_row(&self, count: usize, row: &'a Row) -> Cow<'a, Row> {
+        match self.view {
+            Some(view) => Cow::Owned(view.apply(row, count, &self.settings.columns)),
+            None => Cow::Borrowed(row),
+        }
+    }
+
+    /// Iterate over the visible rows, transformed as they must be rendered
+    fn rendered_rows(&self) -> impl Iterator<Item = Cow<'a, Row>> {
+        let slice = *self;
+        self.numbered_rows()
+            .map(move |(count, r)| slice.render_row(count, r))
+    }
+
+    /// Iterate over the rendered rows, without the separator rows, for the exports
+    /// having no horizontal rules
+    fn exported_rows(&self) -> impl Iterator<Item = Cow<'a, Row>> {
+        self.rendered_rows().filter(|r| !r.is_separator())
+    }
+
+    /// Get a slice restricted to the columns in `range`, like `table.slice_columns(1..3)`.
+    /// Positions are the ones of the columns in the table, and slicing a slice again
+    /// keeps the columns in both ranges. Other columns are hidden when printing or exporting
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Slice;
+    /// # fn main() {
+    /// let table = table!(["a", "b", "c"], ["d", "e", "f"]);
+    /// let out = table.slice(1..).slice_columns(1..).to_string().replace("\r\n", "\n");
+    /// assert_eq!(out, "+---+---+\n| e | f |\n+---+---+\n");
+    /// # }
+    /// ```
+    pub fn slice_columns<R: RangeBounds<usize>>(&self, range: R) -> TableSlice<'a> {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.saturating_add(1),
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => usize::MAX,
+        };
+        let columns = match self.columns {
+            Some((s, e)) => (s.max(start), e.min(end)),
+            None => (start, end),
+        };
+        TableSlice {
+            columns: Some(columns),
+            ..*self
+        }
+    }
+
+    /// Get a slice printed with `format` instead of the format of the table, without cloning it.
+    /// The other settings of the table still apply
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Slice;
+    /// # use prettytable::format::consts::FORMAT_CLEAN;
+    /// # fn main() {
+    /// let table = table!(["a", "b"], ["c", "d"]);
+    /// let out = table.slice(1..).with_format(&FORMAT_CLEAN).to_string();
+    /// assert_eq!(out.replace("\r\n", "\n"), " c  d \n");
+    /// # }
+    /// ```
+    pub fn with_format(&self, format: &'a TableFormat) -> TableSlice<'a> {
+        TableSlice { format, ..*self }
+    }
+
+    /// Check if the column at position `col_idx` is outside the range selected
+    /// with `slice_columns()`
+    fn is_column_sliced_out(&self, col_idx: usize) -> bool {
+        matches!(self.columns, Some((start, end)) if col_idx < start || col_idx >= end)
+    }
+
+    /// Clone the settings of this slice for a table built from it,
+    /// hiding the columns outside the range selected with `slice_columns()`
+    fn owned_settings(&self) -> Settings {
+        let mut settings = self.settings.clone();
+        if self.columns.is_some() {
+            let column_num = self.get_column_num();
+            settings.columns.resize_with(column_num, Default::default);
+            for (i, column) in settings.columns.iter_mut().enumerate() {
+                column.hidden |= self.is_column_sliced_out(i);
+            }
+        }
+        settings
+    }
+
     /// Get the number of rows
     pub fn len(&self) -> usize {
         self.rows.len()
@@ -100,89 +450,759 @@ impl<'a> TableSlice<'a> {
         self.rows.get(row)
     }
 
-    /// Get the width of the column at position `col_idx`.
-    /// Return 0 if the column does not exists;
-    fn get_column_width(&self, col_idx: usize) -> usize {
-        let mut width = match *self.titles {
-            Some(ref t) => t.get_column_width(col_idx, self.format),
-            None => 0,
+    /// Get the width of all columns, and return a slice
+    /// with the result for each column
+    fn get_all_column_width(&self) -> Vec<usize> {
+        // Rows are rendered once, their width being measured for all the columns
+        let mut col_width = Vec::new();
+        for r in self.all_rows() {
+            let count = r.column_count();
+            if count > col_width.len() {
+                col_width.resize(count, 0);
+            }
+            for (i, width) in col_width.iter_mut().enumerate().take(count) {
+                *width = (*width).max(r.get_column_width(i, self.format));
+            }
+        }
+        for (width, c) in col_width.iter_mut().zip(&self.settings.columns) {
+            *width = (*width).min(c.max_width.unwrap_or(usize::MAX));
+            *width = (*width).max(c.min_width);
+        }
+        self.fit_max_width(&mut col_width);
+        // Enlarge the last column if a full width row doesn't fit in the table width
+        let total = self.full_width(&col_width);
+        let needed = self
+            .settings
+            .heading
+            .iter()
+            .chain(self.visible_rows())
+            .filter(|r| r.is_full_width())
+            .filter_map(|r| r.get_cell(0))
+            .map(|c| c.get_width_with(self.format.is_ambiguous_wide()))
+            .max()
+            .unwrap_or(0);
+        if let Some(last) = col_width.last_mut() {
+            *last += needed.saturating_sub(total);
+        }
+        col_width
+    }
+
+    /// Shrink the columns in `col_width` so that the table fits in its maximum width, if any.
+    /// The available width is shared equally between the columns, narrower columns leaving
+    /// what they don't need to the others. Columns with a fixed width are not shrunk
+    fn fit_max_width(&self, col_width: &mut [usize]) {
+        let max = match self.settings.max_width {
+            Some(max) => max,
+            None => return,
         };
-        for r in self.rows {
-            let l = r.get_column_width(col_idx, self.format);
-            if l > width {
-                width = l;
+        let sep = |pos| {
+            self.format
+                .get_column_separator(pos)
+                .map(|_| 1)
+                .unwrap_or_default()
+        };
+        let (lp, rp) = self.format.get_padding_width();
+        let n = col_width.len();
+        let overhead = self.format.get_indent()
+            + sep(ColumnPosition::Left)
+            + sep(ColumnPosition::Right)
+            + n * (lp + rp)
+            + n.saturating_sub(1) * sep(ColumnPosition::Intern);
+        let mut budget = max.saturating_sub(overhead);
+        let mut flexible = Vec::with_capacity(n);
+        for (i, w) in col_width.iter().enumerate() {
+            match self.settings.columns.get(i) {
+                Some(c) if c.fixed_width => budget = budget.saturating_sub(*w),
+                _ => flexible.push(i),
             }
         }
-        width
+        if flexible.iter().map(|&i| col_width[i]).sum::<usize>() <= budget {
+            return;
+        }
+        flexible.sort_by_key(|&i| col_width[i]);
+        let mut remaining = flexible.len();
+        for i in flexible {
+            let share = (budget / remaining).max(1);
+            col_width[i] = col_width[i].min(share);
+            budget = budget.saturating_sub(col_width[i]);
+            remaining -= 1;
+        }
     }
 
-    /// Get the width of all columns, and return a slice
-    /// with the result for each column
-    fn get_all_column_width(&self) -> Vec<usize> {
-        let colnum = self.get_column_num();
-        let mut col_width = vec![0usize; colnum];
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..colnum {
-            // TODO: calling "get_column_width()" in a loop is inefficient
-            col_width[i] = self.get_column_width(i);
+    /// Returns an iterator over the rows, with their index, styled with `attr`,
+    /// either as a row default style or on one of their cells
+    pub fn rows_with_style(&self, attr: Attr) -> impl Iterator<Item = (usize, &'a Row)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(move |(_, r)| r.has_style(attr))
+    }
+
+    /// Get the width of a cell spanning all the columns, whose width is given by `col_width`
+    fn full_width(&self, col_width: &[usize]) -> usize {
+        let (lp, rp) = self.format.get_padding_width();
+        let sep = self
+            .format
+            .get_column_separator(ColumnPosition::Intern)
+            .map(|_| 1)
+            .unwrap_or_default();
+        col_width.iter().sum::<usize>() + col_width.len().saturating_sub(1) * (lp + rp + sep)
+    }
+
+    /// Get the width of the table printed with columns of the given widths,
+    /// including borders and indentation
+    fn table_width(&self, col_width: &[usize]) -> usize {
+        let (lp, rp) = self.format.get_padding_width();
+        let sep = |pos| {
+            self.format
+                .get_column_separator(pos)
+                .map(|_| 1)
+                .unwrap_or_default()
+        };
+        self.format.get_indent()
+            + sep(ColumnPosition::Left)
+            + lp
+            + self.full_width(col_width)
+            + rp
+            + sep(ColumnPosition::Right)
+    }
+
+    /// Print the caption to `out` if it is set at `position`, centered relative to the table.
+    /// Returns the number of printed lines
+    fn print_caption<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        col_width: &[usize],
+        position: CaptionPosition,
+    ) -> Result<usize, io::Error> {
+        let caption = match self.settings.caption {
+            Some((ref caption, pos)) if pos == position => caption,
+            _ => return Ok(0),
+        };
+        let indent = self.format.get_indent();
+        let width = self.table_width(col_width) - indent;
+        let mut height = 0;
+        let wide = self.format.is_ambiguous_wide();
+        for line in caption.lines() {
+            out.write_all(&vec![b' '; indent])?;
+            utils::print_align(out, Alignment::CENTER, line, ' ', width, true, wide)?;
+            out.write_all(self.format.newline_bytes())?;
+            height += 1;
         }
-        col_width
+        Ok(height)
+    }
+
+    /// Print the number of rows below the table, like `(2 rows)`, if enabled.
+    /// Full width rows are not counted
+    fn print_summary<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        if !self.settings.summary {
+            return Ok(0);
+        }
+        let count = self.visible_rows().filter(|r| !r.is_full_width()).count();
+        let plural = if count == 1 { "" } else { "s" };
+        out.write_all(&vec![b' '; self.format.get_indent()])?;
+        write!(out, "({} row{})", count, plural)?;
+        out.write_all(self.format.newline_bytes())?;
+        Ok(1)
+    }
+
+    /// Merge the widths of the columns covered by each cell of `row`,
+    /// so that a line separator printed with them only has junctions between its cells
+    fn merged_widths(&self, row: &Row, col_width: &[usize]) -> Vec<usize> {
+        if row.is_full_width() && !col_width.is_empty() {
+            return vec![self.full_width(col_width)];
+        }
+        let mut widths = Vec::new();
+        let mut column = 0;
+        for cell in row.iter() {
+            if column >= col_width.len() {
+                break;
+            }
+            let end = (column + cell.get_hspan()).min(col_width.len());
+            widths.push(self.full_width(&col_width[column..end]));
+            column = end;
+        }
+        widths.extend_from_slice(&col_width[column.min(col_width.len())..]);
+        widths
     }
 
-    /// Returns an iterator over the immutable cells of the column specified by `column`
-    pub fn column_iter(&self, column: usize) -> ColumnIter {
-        ColumnIter(self.rows.iter(), column)
+    /// Get the width of each column, as used when printing the table.
+    /// It can be given to [`Row::render`](struct.Row.html#method.render) to print rows
+    /// lining up with this table
+    pub fn column_widths(&self) -> Vec<usize> {
+        self.get_all_column_width()
+    }
+
+    /// Returns an iterator over the immutable cells of the column specified by `column`,
+    /// either its position or its title. It's empty if there's no column with this title
+    pub fn column_iter<'c, C: Into<ColumnRef<'c>>>(&self, column: C) -> ColumnIter<'a> {
+        match column.into().position(self.titles.as_ref()) {
+            Some(column) => ColumnIter(self.rows.iter(), column),
+            None => ColumnIter([].iter(), 0),
+        }
     }
 
     /// Returns an iterator over immutable rows
-    pub fn row_iter(&self) -> Iter<Row> {
+    pub fn row_iter(&self) -> Iter<'_, Row> {
         self.rows.iter()
     }
 
-    /// Internal only
-    fn __print<T: Write + ?Sized, F>(&self, out: &mut T, f: F) -> Result<usize, Error>
+    /// Check if the column at position `col_idx` must be hidden when printing or exporting
+    fn is_column_hidden(&self, col_idx: usize) -> bool {
+        let (hidden, hide_if_empty) = self
+            .settings
+            .columns
+            .get(col_idx)
+            .map(|c| (c.hidden, c.hide_if_empty))
+            .unwrap_or_default();
+        // A table without rows keeps all its columns, unless asked for each of them
+        let hide_if_empty = hide_if_empty
+            || self.settings.hide_empty_columns && self.visible_rows().next().is_some();
+        hidden
+            || self.is_column_sliced_out(col_idx)
+            || hide_if_empty
+                && self
+                    .visible_rows()
+                    .filter_map(|r| r.get_cell(col_idx))
+                    .all(|c| {
+                        let content = c.get_content();
+                        content.is_empty()
+                            || Some(&content) == self.settings.empty_placeholder.as_ref()
+                    })
+    }
+
+    /// Build a copy of the footer of this slice with the aggregates computed in it,
+    /// or return `None` if there's no aggregate
+    fn aggregated_footer(&self) -> Option<Row> {
+        if self.settings.aggregates.is_empty() {
+            return None;
+        }
+        let mut footer = self.footer.clone().unwrap_or_else(Row::empty);
+        for aggregate in &self.settings.aggregates {
+            let column = aggregate.column();
+            let mut precision = 0;
+            let values = self
+                .visible_rows()
+                .filter_map(|r| r.get_cell(column))
+                .filter_map(|c| {
+                    let content = c.get_content();
+                    let value = content.trim().parse::<f64>().ok()?;
+                    let decimals = content
+                        .trim()
+                        .find('.')
+                        .map(|i| content.trim().len() - i - 1);
+                    precision = precision.max(decimals.unwrap_or(0));
+                    Some(value)
+                })
+                .collect::<Vec<_>>();
+            while footer.len() <= column {
+                footer.add_cell(Cell::default());
+            }
+            let mut cell = Cell::new(&aggregate.compute(&values, precision));
+            cell.align(Alignment::RIGHT);
+            footer.set_cell(cell, column).ok()?;
+        }
+        Some(footer)
+    }
+
+    /// Get the positions of the columns which are not hidden,
+    /// or `None` if all the columns are visible
+    fn visible_columns(&self) -> Option<Vec<usize>> {
+        let column_num = self.get_column_num();
+        let visible = (0..column_num)
+            .filter(|&i| !self.is_column_hidden(i))
+            .collect::<Vec<_>>();
+        if visible.len() == column_num {
+            None
+        } else {
+            Some(visible)
+        }
+    }
+
+    /// Return a printable view over the rows of this slice matching `predicate`,
+    /// without cloning them into a new table
+    pub fn filter_view<F: FnMut(&Row) -> bool>(&self, mut predicate: F) -> FilteredView<'a> {
+        let selection = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| predicate(r))
+            .map(|(i, _)| i)
+            .collect();
+        FilteredView {
+            slice: *self,
+            selection,
+        }
+    }
+
+    /// Build a table made of `n` rows of this slice selected with `strategy`, for a preview
+    /// of a huge dataset. A full width row like `… 42 more rows` is added where rows were elided.
+    /// Titles, footer, format and settings are kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::{SampleStrategy, Table};
+    /// let table: Table = (0..1000).map(|i| vec![i, i * i]).collect();
+    /// table.sample(6, SampleStrategy::HeadTail).printstd();
+    /// ```
+    pub fn sample(&self, n: usize, strategy: SampleStrategy) -> Table {
+        // Rows are picked while iterating over them, to not collect a huge dataset
+        let total = self.visible_rows().count();
+        let elided = total.saturating_sub(n);
+        let marker = || {
+            let plural = if elided > 1 { "s" } else { "" };
+            Row::full_width(&format!("… {} more row{}", elided, plural))
+        };
+        let mut rows = self.visible_rows().cloned();
+        let mut sample = Vec::with_capacity(n + 1);
+        if elided == 0 {
+            sample.extend(rows);
+        } else {
+            match strategy {
+                SampleStrategy::Head => {
+                    sample.extend(rows.take(n));
+                    sample.push(marker());
+                }
+                SampleStrategy::HeadTail => {
+                    let head = n - n / 2;
+                    sample.extend(rows.by_ref().take(head));
+                    sample.push(marker());
+                    sample.extend(rows.skip(elided));
+                }
+                SampleStrategy::Random(seed) => {
+                    // Selection sampling: each row is kept with the probability
+                    // that it's one of the rows still needed among the remaining ones
+                    let mut rng = utils::Rng::new(seed);
+                    let mut needed = n;
+                    for (i, row) in self.visible_rows().enumerate() {
+                        if needed == 0 {
+                            break;
+                        }
+                        if rng.below(total - i) < needed {
+                            sample.push(row.clone());
+                            needed -= 1;
+                        }
+                    }
+                    sample.push(marker());
+                }
+            }
+        }
+        Table {
+            format: Box::new(*self.format),
+            titles: Box::new(self.titles.clone()),
+            rows: sample,
+            footer: Box::new(self.footer.clone()),
+            settings: Box::new(self.owned_settings()),
+        }
+    }
+
+    /// Build a table made of the columns of this slice given by position or title in `columns`,
+    /// in this order, for a report over some columns of a wide table. A column can be listed
+    /// several times, while titles matching no column are ignored.
+    /// Column settings are kept, as well as the format and the other settings.
+    /// Aggregates are computed in the footer before projecting the columns.
+    /// Spanning cells whose columns are no longer adjacent are split, the first part keeping
+    /// the content. Full width rows are left untouched
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["1", "alice", "admin"], ["2", "bob", "guest"]);
+    /// table.set_titles(row!["id", "name", "role"]);
+    /// let roles = table.project(&["role", "name"]);
+    /// assert_eq!(roles[1][0].get_content(), "guest");
+    /// assert_eq!(table.project(&[1])[0].len(), 1);
+    /// # }
+    /// ```
+    pub fn project<'c, C>(&self, columns: &[C]) -> Table
     where
-        F: Fn(&Row, &mut T, &TableFormat, &[usize]) -> Result<usize, Error>,
+        C: Into<ColumnRef<'c>> + Copy,
     {
-        let mut height = 0;
+        let titles = self.titles.as_ref();
+        let columns = columns
+            .iter()
+            .filter_map(|&c| c.into().position(titles))
+            .collect::<Vec<_>>();
+        let mut settings = self.settings.clone();
+        let aggregated = self.aggregated_footer();
+        if aggregated.is_some() {
+            settings.aggregates.clear();
+        }
+        let footer = aggregated.as_ref().or(self.footer.as_ref());
+        settings.columns = columns
+            .iter()
+            .map(|&i| self.settings.columns.get(i).cloned().unwrap_or_default())
+            .collect();
+        for group in &mut settings.title_groups {
+            *group = group.project(&columns);
+        }
+        Table {
+            format: Box::new(*self.format),
+            titles: Box::new(self.titles.as_ref().map(|r| r.project(&columns))),
+            rows: self.visible_rows().map(|r| r.project(&columns)).collect(),
+            footer: Box::new(footer.map(|r| r.project(&columns))),
+            settings: Box::new(settings),
+        }
+    }
+
+    /// Internal only
+    fn __print<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+    ) -> Result<usize, io::Error> {
+        match self.print_view() {
+            Some(rendering) => rendering.slice(self).print_lines(out, printer),
+            None => self.print_lines(out, printer),
+        }
+    }
+
+    /// Print the lines of the table, once rendered through its view
+    fn print_lines<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+    ) -> Result<usize, io::Error> {
         // Compute columns width
         let col_width = self.get_all_column_width();
-        height += self
-            .format
-            .print_line_separator(out, &col_width, LinePosition::Top)?;
-        if let Some(ref t) = *self.titles {
-            height += f(t, out, self.format, &col_width)?;
-            height += self
-                .format
-                .print_line_separator(out, &col_width, LinePosition::Title)?;
-        }
+        let mut height = self.print_header(out, printer, &col_width)?;
         // Print rows
-        let mut iter = self.rows.iter().peekable();
-        while let Some(r) = iter.next() {
-            height += f(r, out, self.format, &col_width)?;
-            if iter.peek().is_some() {
-                height +=
-                    self.format
-                        .print_line_separator(out, &col_width, LinePosition::Intern)?;
+        let mut iter = self.rendered_rows().enumerate().peekable();
+        // Full width rows and group headers are neither striped nor counted
+        let mut data_rows = 0;
+        while let Some((i, r)) = iter.next() {
+            let stripe = if r.is_full_width() || r.is_group_header() {
+                false
+            } else {
+                data_rows += 1;
+                data_rows % 2 == 0
+            };
+            let striped;
+            let row = match self.settings.stripe_style {
+                Some(style) if stripe => {
+                    striped = Row::clone(&r).with_style(style);
+                    &striped
+                }
+                _ => &*r,
+            };
+            height += printer.print_row(row, out, self.format, &col_width, self.settings)?;
+            if let Some((_, next)) = iter.peek() {
+                height += match self.repeated_titles(i + 1) {
+                    Some(titles) => {
+                        self.print_repeated_titles(out, printer, &r, i + 1, titles, &col_width)?
+                    }
+                    None => self.print_row_separator(out, printer, &r, i + 1, next, &col_width)?,
+                };
             }
         }
+        height += self.print_footer(out, printer, &col_width)?;
+        height += self.print_summary(out)?;
+        out.flush()?;
+        Ok(height)
+    }
+
+    /// Print the caption above the table, the top border, the heading, the title groups
+    /// and the titles to `out`, with `printer`.
+    /// Returns the number of printed lines
+    fn print_header<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+        col_width: &[usize],
+    ) -> Result<usize, io::Error> {
+        let mut height = self.print_caption(out, col_width, CaptionPosition::Above)?;
+        let groups = &self.settings.title_groups;
+        // The top border has no junction above the heading, nor inside the first title groups
+        let top_width = match (&self.settings.heading, groups.first()) {
+            (Some(_), _) if !col_width.is_empty() => vec![self.full_width(col_width)],
+            (None, Some(group)) => self.merged_widths(group, col_width),
+            _ => col_width.to_vec(),
+        };
         height += self
             .format
-            .print_line_separator(out, &col_width, LinePosition::Bottom)?;
-        out.flush()?;
+            .print_line_separator(out, &top_width, LinePosition::Top, printer)?;
+        if let Some(ref h) = self.settings.heading {
+            height += printer.print_row(h, out, self.format, col_width, self.settings)?;
+            let widths = match groups.first() {
+                Some(group) => self.merged_widths(group, col_width),
+                None => col_width.to_vec(),
+            };
+            height +=
+                self.format
+                    .print_line_separator(out, &widths, LinePosition::Heading, printer)?;
+        }
+        // Markdown like formats need a title row, even an empty one
+        let empty;
+        let titles = match *self.titles {
+            None if self.format.get_alignment_markers() && groups.is_empty() => {
+                empty = Row::new(vec![Cell::default(); col_width.len()]);
+                Some(&empty)
+            }
+            ref titles => titles.as_ref(),
+        };
+        for (i, group) in groups.iter().enumerate() {
+            height += printer.print_row(group, out, self.format, col_width, self.settings)?;
+            // Without titles, the last title groups are separated from the rows like titles
+            height += match groups.get(i + 1).or(titles) {
+                Some(next) => self.format.print_line_separator(
+                    out,
+                    &self.merged_widths(next, col_width),
+                    LinePosition::Intern,
+                    printer,
+                )?,
+                None => self.print_title_separator(out, printer, col_width)?,
+            };
+        }
+        if let Some(t) = titles {
+            height += printer.print_row(t, out, self.format, col_width, self.settings)?;
+            height += self.print_title_separator(out, printer, col_width)?;
+        }
+        Ok(height)
+    }
+
+    /// Print the title line separator to `out`, with the underline character of each column,
+    /// and with their alignment taken from the first data row if the format marks it.
+    /// Returns the number of printed lines
+    fn print_title_separator<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+        col_width: &[usize],
+    ) -> Result<usize, io::Error> {
+        let markers = self.format.get_alignment_markers();
+        let columns = self
+            .column_alignments(col_width.len())
+            .into_iter()
+            .enumerate()
+            .map(|(i, align)| ColumnLine {
+                align: Some(align).filter(|_| markers),
+                line: self.settings.columns.get(i).and_then(|c| c.title_line),
+            })
+            .collect::<Vec<_>>();
+        self.format
+            .print_title_separator(out, col_width, &columns, printer)
+    }
+
+    /// Get the alignment of each of the `column_num` columns, as marked under the titles:
+    /// the alignment of the first data row, falling back to the column alignment
+    fn column_alignments(&self, column_num: usize) -> Vec<Alignment> {
+        let first = self.rendered_rows().find(|r| !r.is_full_width());
+        (0..column_num)
+            .map(|i| {
+                first
+                    .as_ref()
+                    .and_then(|r| {
+                        r.get_cell_at_column(i)
+                            .and_then(Cell::get_explicit_alignment)
+                            .or(r.inherited(self.settings, i).align)
+                    })
+                    .or_else(|| self.settings.columns.get(i).and_then(|c| c.align))
+                    .unwrap_or(Alignment::LEFT)
+            })
+            .collect()
+    }
+
+    /// Get the titles to print again after `count` data rows, if any
+    fn repeated_titles(&self, count: usize) -> Option<&Row> {
+        match self.settings.repeat_titles {
+            Some(n) if n > 0 && count % n == 0 => self.titles.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Print `titles` again after the row `row`, which is the data row number `count`,
+    /// preceded by the line separator and followed by the title separator.
+    /// Returns the number of printed lines
+    fn print_repeated_titles<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+        row: &Row,
+        count: usize,
+        titles: &Row,
+        col_width: &[usize],
+    ) -> Result<usize, io::Error> {
+        let mut height = self.print_row_separator(out, printer, row, count, titles, col_width)?;
+        height += printer.print_row(titles, out, self.format, col_width, self.settings)?;
+        height += self.print_title_separator(out, printer, col_width)?;
+        Ok(height)
+    }
+
+    /// Print the line separator between the rows `row` and `next` to `out`,
+    /// `row` being the data row number `count`.
+    /// Returns the number of printed lines
+    fn print_row_separator<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+        row: &Row,
+        count: usize,
+        next: &Row,
+        col_width: &[usize],
+    ) -> Result<usize, io::Error> {
+        let (pos, forced) = self.row_separator(row, count, next);
+        let format = next.get_format().unwrap_or(self.format);
+        match forced {
+            None => format.print_line_separator(out, col_width, pos, printer),
+            Some(true) => format.print_forced_intern_separator(out, col_width, printer),
+            Some(false) => Ok(0),
+        }
+    }
+
+    /// Get the position of the line separator between `row`, which is the data row number `count`,
+    /// and `next`, and whether it's forced (`Some(true)`) or suppressed (`Some(false)`),
+    /// or `None` if it depends on the format of `next`
+    fn row_separator(&self, row: &Row, count: usize, next: &Row) -> (LinePosition, Option<bool>) {
+        // Separator rows are rules on their own
+        if row.is_separator() || next.is_separator() {
+            return (LinePosition::Intern, Some(false));
+        }
+        let pos = if next.is_group_header() {
+            LinePosition::Group
+        } else {
+            LinePosition::Intern
+        };
+        // Only every n-th internal separator is printed, if enabled
+        let separator = match self.settings.separator_every {
+            Some(n) if n > 0 && pos == LinePosition::Intern => Some(count % n == 0),
+            _ => None,
+        };
+        (pos, row.get_separator_after().or(separator))
+    }
+
+    /// Print the footer, the bottom border and the caption below the table to `out`,
+    /// with `printer`.
+    /// Returns the number of printed lines
+    fn print_footer<T: Write + ?Sized, P: Printer<T>>(
+        &self,
+        out: &mut T,
+        printer: &P,
+        col_width: &[usize],
+    ) -> Result<usize, io::Error> {
+        let mut height = 0;
+        if let Some(ref t) = *self.footer {
+            height += t.get_format().unwrap_or(self.format).print_line_separator(
+                out,
+                col_width,
+                LinePosition::Footer,
+                printer,
+            )?;
+            height += printer.print_row(t, out, self.format, col_width, self.settings)?;
+        }
+        height +=
+            self.format
+                .print_line_separator(out, col_width, LinePosition::Bottom, printer)?;
+        height += self.print_caption(out, col_width, CaptionPosition::Below)?;
         Ok(height)
     }
 
     /// Print the table to `out` and returns the number of
     /// line printed, or an error
-    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
-        self.__print(out, Row::print)
+    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.__print(out, &Plain)
+    }
+
+    /// Print the first `head` and the last `tail` rows of the table to `out`, with a single
+    /// full width row like `… 1234 rows omitted …` between them, for a quick look at a huge table.
+    /// The whole table is printed if it doesn't have more rows than that.
+    /// Aggregates in the footer and row numbers are computed over all the rows.
+    /// Returns the number of printed lines
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::Table;
+    /// let table: Table = (0..1000).map(|i| vec![i, i * i]).collect();
+    /// table.print_snipped(&mut std::io::stdout(), 3, 2).unwrap();
+    /// ```
+    pub fn print_snipped<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        head: usize,
+        tail: usize,
+    ) -> Result<usize, io::Error> {
+        // Aggregates and row numbers are computed over all the rows,
+        // while only the printed ones are numbered
+        let mut rendering = Rendering::new(self);
+        let aggregates = rendering.add_aggregates(self);
+        let numbers = rendering.add_row_numbers();
+        let slice = if aggregates || numbers {
+            rendering.slice(self)
+        } else {
+            *self
+        };
+        let rows = slice.numbered_rows().collect::<Vec<_>>();
+        if head.saturating_add(tail) >= rows.len() {
+            return self.print(out);
+        }
+        let omitted = rows.len() - head - tail;
+        let plural = if omitted > 1 { "s" } else { "" };
+        let render = |&(count, row): &(usize, &Row)| slice.render_row(count, row).into_owned();
+        let mut snipped = Vec::with_capacity(head + tail + 1);
+        snipped.extend(rows[..head].iter().map(render));
+        snipped.push(Row::full_width(&format!(
+            "… {} row{} omitted …",
+            omitted, plural
+        )));
+        snipped.extend(rows[rows.len() - tail..].iter().map(render));
+        let table = Table {
+            format: Box::new(*slice.format),
+            titles: Box::new(slice.titles.clone()),
+            rows: snipped,
+            footer: Box::new(slice.footer.clone()),
+            settings: Box::new(slice.owned_settings()),
+        };
+        table.print(out)
+    }
+
+    /// Return an estimation of the size in bytes of the rendered table, without rendering it.
+    /// Cells are assumed to only contain 1 byte wide characters, so the actual size may be bigger
+    pub fn render_size_hint(&self) -> usize {
+        let col_width = self.get_all_column_width();
+        let format = self.format;
+        let (lp, rp) = format.get_padding();
+        let pad_len = format.get_padding_char().len_utf8();
+        let sep_len = |pos| {
+            format
+                .get_column_separator(pos)
+                .map(char::len_utf8)
+                .unwrap_or_default()
+        };
+        let line_len = format.get_indent()
+            + sep_len(ColumnPosition::Left)
+            + col_width
+                .iter()
+                .map(|w| w + (lp + rp) * pad_len)
+                .sum::<usize>()
+            + col_width.len().saturating_sub(1) * sep_len(ColumnPosition::Intern)
+            + sep_len(ColumnPosition::Right)
+            + format.newline_bytes().len();
+        let lines: usize = self.all_rows().map(|r| r.get_height()).sum();
+        let caption = match self.settings.caption {
+            Some((ref caption, _)) => line_len * caption.lines().count() + caption.len(),
+            None => 0,
+        };
+        let summary = if self.settings.summary { line_len } else { 0 };
+        // Line separators may be printed before, between, and after rows
+        line_len * (2 * lines + 1) + caption + summary
+    }
+
+    /// Render the table into a `String`, failing with `Error::TooLarge` instead of
+    /// allocating more than `max_size` bytes.
+    /// It's a safer alternative to `to_string()` when the table may be huge.
+    pub fn try_to_string(&self, max_size: usize) -> Result<String, Error> {
+        let mut writer = StringWriter::with_limit(max_size, self.render_size_hint());
+        match self.print(&mut writer) {
+            Ok(_) => Ok(writer.into_string()),
+            Err(_) if writer.limit_exceeded() => Err(Error::TooLarge { max_size }),
+            Err(e) => Err(e.into()),
+        }
     }
 
     /// Print the table to terminal `out`, applying styles when needed and returns the number of
     /// line printed, or an error
-    pub fn print_term<T: Terminal + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
-        self.__print(out, Row::print_term)
+    pub fn print_term<T: Terminal + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.__print(out, &Styled)
     }
 
     /// Print the table to standard output. Colors won't be displayed unless
@@ -191,11 +1211,29 @@ impl<'a> TableSlice<'a> {
     /// output is redirected to a file, or piped to another program, the output is considered
     /// as not beeing tty, and ANSI escape characters won't be displayed unless `force colorize`
     /// is set to `true`.
+    /// When stdout is a terminal narrower than the table, the table is fitted to it,
+    /// unless disabled with `Table::set_auto_fit()`.
+    /// When stdout is a terminal and the locale doesn't use UTF-8, box drawing characters
+    /// of the borders are replaced with ASCII ones, like with `Table::set_ascii_borders()`.
     /// # Returns
-    /// A `Result` holding the number of lines printed, or an `io::Error` if any failure happens
-    pub fn print_tty(&self, force_colorize: bool) -> Result<usize, Error> {
+    /// A `Result` holding the number of lines printed, or an `io::Error` if any failure happens,
+    /// including standard output being closed. See `try_print_tty()` to ignore the latter
+    pub fn print_tty(&self, force_colorize: bool) -> Result<usize, io::Error> {
         use is_terminal::IsTerminal;
-        match (stdout(), io::stdout().is_terminal() || force_colorize) {
+        let is_terminal = io::stdout().is_terminal();
+        if let Some(ref settings) = self.fit_terminal(is_terminal) {
+            return TableSlice { settings, ..*self }.print_tty(force_colorize);
+        }
+        if is_terminal && !self.settings.ascii_borders && !utils::locale_supports_utf8() {
+            let mut settings = self.settings.clone();
+            settings.ascii_borders = true;
+            return TableSlice {
+                settings: &settings,
+                ..*self
+            }
+            .print_tty(force_colorize);
+        }
+        match (stdout(), is_terminal || force_colorize) {
             (Some(mut o), true) => self.print_term(&mut *o),
             _ => self.print(&mut io::stdout()),
         }
@@ -211,29 +1249,224 @@ impl<'a> TableSlice<'a> {
         let _ = self.print_tty(false); // Ignore result
     }
 
-    /// Print table in HTML format to `out`.
-    pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), Error> {
+    /// Print the table to standard output like `print_tty()`, without failing when standard output
+    /// is closed before the table is printed, like when piping into `head`.
+    /// Other errors are returned. It's the right choice for command line tools
+    pub fn try_print_tty(&self, force_colorize: bool) -> Result<(), io::Error> {
+        utils::ignore_broken_pipe(self.print_tty(force_colorize))
+    }
+
+    /// Print the table to standard output like `printstd()`, returning errors other than
+    /// standard output being closed. See `try_print_tty()` for details
+    pub fn try_printstd(&self) -> Result<(), io::Error> {
+        self.try_print_tty(false)
+    }
+
+    /// Print the table to standard output like `print_tty()`, but through a pager when stdout is
+    /// a terminal and the table is taller than it. The pager is given by the `PAGER` environment
+    /// variable, and defaults to `less -R`. Colors are kept, the pager being expected to
+    /// interpret ANSI escape characters. If the pager can't be started, the table is printed
+    /// to standard output
+    /// # Returns
+    /// A `Result` holding the number of lines of the table, or an `io::Error` if any failure happens
+    pub fn print_paged(&self, force_colorize: bool) -> Result<usize, io::Error> {
+        use is_terminal::IsTerminal;
+        let height = match utils::terminal_height() {
+            Some(height) if io::stdout().is_terminal() => height,
+            _ => return self.print_tty(force_colorize),
+        };
+        let fitted = self.fit_terminal(true);
+        let slice = match fitted {
+            Some(ref settings) => TableSlice { settings, ..*self },
+            None => *self,
+        };
+        let (lines, output) = match term::TerminfoTerminal::new(Vec::new()) {
+            Some(mut t) => (slice.print_term(&mut t)?, t.into_inner()),
+            None => {
+                let mut output = Vec::new();
+                (slice.print(&mut output)?, output)
+            }
+        };
+        if lines < height {
+            let mut out = io::stdout();
+            out.write_all(&output)?;
+            out.flush()?;
+        } else {
+            utils::page(&output)?;
+        }
+        Ok(lines)
+    }
+
+    /// Print the table to standard output through a pager when it's taller than the terminal.
+    /// Any failure to print is ignored. See `print_paged()` for details
+    pub fn printstd_paged(&self) {
+        let _ = self.print_paged(false); // Ignore result
+    }
+
+    /// Get the settings of this slice constrained to the width of the terminal, when printing to
+    /// a terminal narrower than the table, and unless disabled or constrained otherwise
+    fn fit_terminal(&self, is_terminal: bool) -> Option<Settings> {
+        if !is_terminal || self.settings.no_auto_fit || self.settings.max_width.is_some() {
+            return None;
+        }
+        let width = utils::terminal_width()?;
+        if self.table_width(&self.get_all_column_width()) <= width {
+            return None;
+        }
+        let mut settings = self.settings.clone();
+        settings.max_width = Some(width);
+        Some(settings)
+    }
+
+    /// Print the table in HTML format to `out`, as a `<table>` element.
+    ///
+    /// Cells content is HTML escaped, the lines of multi-line cells are separated with `<br />`,
+    /// and the alignment and style of each cell are written in its `style` attribute.
+    /// Spanning cells get a `colspan` attribute, and the caption, heading, titles and footer
+    /// are printed too, with the aggregates if any. Hidden columns and rows are left out.
+    /// It's the output used to display tables in Jupyter notebooks with the `evcxr` feature
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let table = table!([r->"a<b", "c\nd"]);
+    /// let mut out = Vec::new();
+    /// table.print_html(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "<table><tr><td style=\"text-align: right;\">a&lt;b</td>\
+    ///      <td style=\"text-align: left;\">c<br />d</td></tr></table>"
+    /// );
+    /// # }
+    /// ```
+    pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), io::Error> {
+        self.print_html_with_escaper(out, &HtmlEscaper)
+    }
+
+    /// Render the table in HTML format into a `String`.
+    /// See [`print_html()`](#method.print_html) for details
+    pub fn to_html(&self) -> String {
+        let mut writer = StringWriter::new();
+        // Writing to a string without size limit doesn't fail
+        let _ = self.print_html(&mut writer);
+        writer.into_string()
+    }
+
+    /// Print table in HTML format to `out`, escaping cells content with `escaper`.
+    pub fn print_html_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+    ) -> Result<(), io::Error> {
+        self.__print_html(out, escaper, &HtmlOptions::new())
+    }
+
+    /// Print table in HTML format to `out`, with the CSS classes and inline styles
+    /// configured in `options`, so that it fits in existing web pages
+    pub fn print_html_with_options<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        options: &HtmlOptions,
+    ) -> Result<(), io::Error> {
+        self.__print_html(out, &HtmlEscaper, options)
+    }
+
+    /// Internal method to print the table in HTML format
+    fn __print_html<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+        options: &HtmlOptions,
+    ) -> Result<(), io::Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).__print_html(out, escaper, options);
+        }
         // Compute column width
         let column_num = self.get_column_num();
-        out.write_all(b"<table>")?;
-        // Print titles / table header
+        let row_attr = options.row_attr();
+        write!(out, "<table{}>", options.table_attr())?;
+        if let Some((ref caption, position)) = self.settings.caption {
+            match position {
+                CaptionPosition::Above => out.write_all(b"<caption>")?,
+                CaptionPosition::Below => {
+                    out.write_all(b"<caption style=\"caption-side: bottom\">")?
+                }
+            }
+            out.write_all(escaper.escape(caption).as_bytes())?;
+            out.write_all(b"</caption>")?;
+        }
+        // Print heading
+        if let Some(ref h) = self.settings.heading {
+            write!(out, "<tr{}>", row_attr)?;
+            h.print_html_with_settings(out, column_num, escaper, self.settings, options)?;
+            out.write_all(b"</tr>")?;
+        }
+        // Print title groups, titles / table header
+        for g in &self.settings.title_groups {
+            out.write_all(b"<th>")?;
+            g.print_html_with_settings(out, column_num, escaper, self.settings, options)?;
+            out.write_all(b"</th>")?;
+        }
         if let Some(ref t) = *self.titles {
             out.write_all(b"<th>")?;
-            t.print_html(out, column_num)?;
+            t.print_html_with_settings(out, column_num, escaper, self.settings, options)?;
             out.write_all(b"</th>")?;
         }
         // Print rows
-        for r in self.rows {
-            out.write_all(b"<tr>")?;
-            r.print_html(out, column_num)?;
+        for r in self.exported_rows() {
+            write!(out, "<tr{}>", row_attr)?;
+            r.print_html_with_settings(out, column_num, escaper, self.settings, options)?;
             out.write_all(b"</tr>")?;
         }
+        // Print footer
+        if let Some(ref t) = *self.footer {
+            write!(out, "<tfoot><tr{}>", row_attr)?;
+            t.print_html_with_settings(out, column_num, escaper, self.settings, options)?;
+            out.write_all(b"</tr></tfoot>")?;
+        }
         out.write_all(b"</table>")?;
         out.flush()?;
         Ok(())
     }
 }
 
+/// Copy `row` with its cells too wide for their column in `col_width` wrapped or truncated,
+/// depending on the `columns` settings.
+/// Ambiguous width characters are 2 columns wide if `wide_ambiguous` is `true`.
+/// Spanning cells and full width rows are left untouched
+fn constrain_row(
+    row: &Row,
+    col_width: &[usize],
+    columns: &[ColumnSettings],
+    wide_ambiguous: bool,
+) -> Row {
+    let mut row = row.clone();
+    if row.is_full_width() {
+        return row;
+    }
+    let mut column = 0;
+    for cell in row.iter_mut() {
+        let max = col_width.get(column).copied().unwrap_or(usize::MAX);
+        let wrap = columns.get(column).map(|c| c.wrap).unwrap_or_default();
+        column += cell.get_hspan();
+        if cell.get_hspan() > 1 || cell.get_width_with(wide_ambiguous) <= max {
+            continue;
+        }
+        if wrap {
+            let lines = utils::wrap(&cell.get_content(), max, wide_ambiguous);
+            cell.set_content(&lines.join("\n"));
+        } else {
+            *cell = cell
+                .clone()
+                .shorten_lines(max, wide_ambiguous, |line, width| {
+                    utils::truncate(line, width, wide_ambiguous)
+                });
+        }
+    }
+    row
+}
+
 impl<'a> IntoIterator for &'a TableSlice<'a> {
     type Item = &'a Row;
     type IntoIter = Iter<'a, Row>;
@@ -242,21 +1475,279 @@ impl<'a> IntoIterator for &'a TableSlice<'a> {
     }
 }
 
-impl Table {
-    /// Create an empty table
-    pub fn new() -> Table {
-        Self::init(Vec::new())
-    }
-
-    /// Create a table initialized with `rows`
-    pub fn init(rows: Vec<Row>) -> Table {
+/// A printable view over the rows of a table matching a predicate.
+/// It is obtained with the `Table::filter_view` method, and borrows the rows from the table.
+///
+/// # Examples
+/// ```rust
+/// # #[macro_use] extern crate prettytable;
+/// # fn main() {
+/// let table = table!(["apple", 3], ["pear", 0], ["plum", 8]);
+/// let in_stock = table.filter_view(|row| row[1].get_content() != "0");
+/// assert_eq!(in_stock.len(), 2);
+/// in_stock.printstd();
+/// # }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct FilteredView<'a> {
+    slice: TableSlice<'a>,
+    selection: Vec<usize>,
+}
+
+impl<'a> FilteredView<'a> {
+    /// Get a slice restricted to the selected rows. Only used for printing
+    fn selected(&self) -> TableSlice<'_> {
+        TableSlice {
+            selection: Some(&self.selection),
+            ..self.slice
+        }
+    }
+
+    /// Get the number of selected rows
+    pub fn len(&self) -> usize {
+        self.selection.len()
+    }
+
+    /// Check if no row is selected
+    pub fn is_empty(&self) -> bool {
+        self.selection.is_empty()
+    }
+
+    /// Returns an iterator over the selected rows
+    pub fn row_iter(&self) -> impl Iterator<Item = &'a Row> + '_ {
+        let rows = self.slice.rows;
+        self.selection.iter().map(move |&i| &rows[i])
+    }
+
+    /// Print the selected rows to `out` and returns the number
+    /// of lines printed, or an error
+    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.selected().print(out)
+    }
+
+    /// Print the selected rows to terminal `out`, applying styles when needed and returns the number
+    /// of lines printed, or an error
+    pub fn print_term<T: Terminal + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.selected().print_term(out)
+    }
+
+    /// Print the selected rows to standard output. Colors won't be displayed unless
+    /// stdout is a tty terminal, or `force_colorize` is set to `true`.
+    /// See `Table::print_tty()` for details
+    pub fn print_tty(&self, force_colorize: bool) -> Result<usize, io::Error> {
+        self.selected().print_tty(force_colorize)
+    }
+
+    /// Print the selected rows to standard output. Any failure to print is ignored.
+    /// See `Table::printstd()` for details
+    pub fn printstd(&self) {
+        self.selected().printstd()
+    }
+
+    /// Print the selected rows to standard output, ignoring a closed standard output.
+    /// See `Table::try_print_tty()` for details
+    pub fn try_print_tty(&self, force_colorize: bool) -> Result<(), io::Error> {
+        self.selected().try_print_tty(force_colorize)
+    }
+
+    /// Print the selected rows to standard output, ignoring a closed standard output.
+    /// See `Table::try_printstd()` for details
+    pub fn try_printstd(&self) -> Result<(), io::Error> {
+        self.selected().try_printstd()
+    }
+
+    /// Print the selected rows to standard output, through a pager when they don't fit
+    /// in the terminal. See `Table::print_paged()` for details
+    pub fn print_paged(&self, force_colorize: bool) -> Result<usize, io::Error> {
+        self.selected().print_paged(force_colorize)
+    }
+
+    /// Print the selected rows to standard output, through a pager when they don't fit
+    /// in the terminal. Any failure to print is ignored
+    pub fn printstd_paged(&self) {
+        self.selected().printstd_paged()
+    }
+
+    /// Print the selected rows in HTML format to `out`.
+    pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), io::Error> {
+        self.selected().print_html(out)
+    }
+}
+
+impl<'a> fmt::Display for FilteredView<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.selected().fmt(fmt)
+    }
+}
+
+impl Table {
+    /// Create an empty table
+    pub fn new() -> Table {
+        Self::init(Vec::new())
+    }
+
+    /// Create a table initialized with `rows`
+    pub fn init(rows: Vec<Row>) -> Table {
         Table {
             rows,
             titles: Box::new(None),
+            footer: Box::new(None),
+            settings: Box::default(),
             format: Box::new(*consts::FORMAT_DEFAULT),
         }
     }
 
+    /// Create a table from `(key, value)` pairs, with a column per distinct key.
+    /// Keys are used as titles, in order of first appearance,
+    /// and each value is added below the previous ones with the same key.
+    /// Columns with fewer values are completed with empty cells
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::Table;
+    /// let events = vec![("GET", "/"), ("POST", "/login"), ("GET", "/home")];
+    /// let table = Table::from_grouped(events);
+    /// // +-------+--------+
+    /// // | GET   | POST   |
+    /// // +=======+========+
+    /// // | /     | /login |
+    /// // +-------+--------+
+    /// // | /home |        |
+    /// // +-------+--------+
+    /// assert_eq!(table.len(), 2);
+    /// assert_eq!(table[1][0].get_content(), "/home");
+    /// ```
+    pub fn from_grouped<I, K, V>(pairs: I) -> Table
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: PartialEq + ToString,
+        V: ToString,
+    {
+        let mut columns: Vec<(K, Vec<String>)> = Vec::new();
+        for (key, value) in pairs {
+            match columns.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, values)) => values.push(value.to_string()),
+                None => columns.push((key, vec![value.to_string()])),
+            }
+        }
+        let (titles, columns) = columns
+            .into_iter()
+            .map(|(k, values)| (k.to_string(), values))
+            .unzip();
+        Self::from_titled_columns(titles, columns)
+    }
+
+    /// Create a table from `(title, values)` columns, from left to right.
+    /// Each value is added below the previous one of its column, and columns
+    /// with fewer values are completed with empty cells
+    ///
+    /// # Example
+    /// ```
+    /// # use prettytable::Table;
+    /// let names = vec!["foo.txt", "bar.rs"];
+    /// let sizes = vec!["120", "4096"];
+    /// let table = Table::from_columns(vec![("name", names), ("size", sizes)]);
+    /// // +---------+------+
+    /// // | name    | size |
+    /// // +=========+======+
+    /// // | foo.txt | 120  |
+    /// // +---------+------+
+    /// // | bar.rs  | 4096 |
+    /// // +---------+------+
+    /// assert_eq!(table[1][1].get_content(), "4096");
+    /// ```
+    pub fn from_columns<I, T, C>(columns: I) -> Table
+    where
+        I: IntoIterator<Item = (T, C)>,
+        T: ToString,
+        C: IntoIterator,
+        C::Item: ToString,
+    {
+        let (titles, columns) = columns
+            .into_iter()
+            .map(|(t, c)| {
+                (
+                    t.to_string(),
+                    c.into_iter().map(|v| v.to_string()).collect(),
+                )
+            })
+            .unzip();
+        Self::from_titled_columns(titles, columns)
+    }
+
+    /// Create a table with `titles`, whose rows are made of the values of `columns`
+    /// at the same position. Columns with fewer values are completed with empty cells
+    fn from_titled_columns(titles: Vec<String>, columns: Vec<Vec<String>>) -> Table {
+        let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+        let rows = (0..height)
+            .map(|i| {
+                Row::new(
+                    columns
+                        .iter()
+                        .map(|v| v.get(i).map(|s| Cell::new(s)).unwrap_or_default())
+                        .collect(),
+                )
+            })
+            .collect();
+        let mut table = Table::init(rows);
+        if !titles.is_empty() {
+            table.set_titles(titles.into_iter().collect());
+        }
+        table
+    }
+
+    /// Build a new table whose rows are the columns of this one, and whose columns are its rows.
+    /// If `titles_as_column` is `true`, the titles become the first column, which is handy to print
+    /// a single record vertically. Otherwise, titles are left out.
+    /// The footer, if any, becomes the last column. Full width rows are left out.
+    ///
+    /// Spanning cells are put in the first column they cover, without span.
+    /// The format and the table default style are kept, while column settings are not
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["1042", "alice", "admin"]);
+    /// table.set_titles(row!["id", "name", "role"]);
+    /// // +------+-------+
+    /// // | id   | 1042  |
+    /// // +------+-------+
+    /// // | name | alice |
+    /// // +------+-------+
+    /// // | role | admin |
+    /// // +------+-------+
+    /// let vertical = table.transpose(true);
+    /// assert_eq!(vertical[1][1].get_content(), "alice");
+    /// # }
+    /// ```
+    pub fn transpose(&self, titles_as_column: bool) -> Table {
+        let titles = self.titles.as_ref().as_ref().filter(|_| titles_as_column);
+        let sources: Vec<&Row> = titles
+            .into_iter()
+            .chain(self.rows.iter())
+            .chain(self.footer.as_ref())
+            .filter(|r| !r.is_full_width())
+            .collect();
+        let rows = (0..self.get_column_num())
+            .map(|column| {
+                Row::new(
+                    sources
+                        .iter()
+                        .map(|r| match r.get_cell_at_column(column) {
+                            Some(cell) => cell.clone().with_hspan(1),
+                            None => Cell::default(),
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+        let mut table = Table::init(rows);
+        table.set_format(*self.format);
+        table.settings.style = self.settings.style.clone();
+        table
+    }
+
     /// Change the table format. Eg : Separators
     pub fn set_format(&mut self, format: TableFormat) {
         *self.format = format;
@@ -269,7 +1760,6 @@ impl Table {
 
     /// Compute and return the number of column
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
-    #[cfg(test)] // Only used for testing for now
     pub(crate) fn get_column_num(&self) -> usize {
         self.as_slice().get_column_num()
     }
@@ -285,7 +1775,8 @@ impl Table {
     }
 
     /// Set the optional title lines
-    pub fn set_titles(&mut self, titles: Row) {
+    pub fn set_titles(&mut self, mut titles: Row) {
+        titles.set_header(true);
         *self.titles = Some(titles);
     }
 
@@ -294,288 +1785,1786 @@ impl Table {
         *self.titles = None;
     }
 
-    /// Get a mutable reference to a row
-    pub fn get_mut_row(&mut self, row: usize) -> Option<&mut Row> {
-        self.rows.get_mut(row)
+    /// Set the default alignment of the titles and title groups, taking precedence
+    /// over the columns alignment, since titles are often centered above left or right
+    /// aligned data. Title rows and cells with their own alignment are not affected
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// use prettytable::format::Alignment;
+    /// # fn main() {
+    /// let mut table = table!(["apple", 3]);
+    /// table.set_titles(row!["fruit", "stock"]);
+    /// table.set_column_alignment(1, Alignment::RIGHT);
+    /// table.set_titles_alignment(Alignment::CENTER);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+-------+
+    /// | fruit | stock |
+    /// +=======+=======+
+    /// | apple |     3 |
+    /// +-------+-------+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_titles_alignment(&mut self, align: Alignment) {
+        self.settings.titles_align = Some(align);
     }
 
-    /// Get an immutable reference to a row
-    pub fn get_row(&self, row: usize) -> Option<&Row> {
-        self.rows.get(row)
+    /// Remove the default alignment of the titles, which are then aligned like their column
+    pub fn unset_titles_alignment(&mut self) {
+        self.settings.titles_align = None;
     }
 
-    /// Append a row in the table, transferring ownership of this row to the table
-    /// and returning a mutable reference to the row
-    pub fn add_row(&mut self, row: Row) -> &mut Row {
-        self.rows.push(row);
-        let l = self.rows.len() - 1;
-        &mut self.rows[l]
+    /// Add a row of title groups, printed above the titles line. Its cells usually span over
+    /// several columns, to group the titles below them under a common header.
+    /// Several rows can be added, they are printed from top to bottom in the order they were added
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["web-1", "2", "4"]);
+    /// table.set_titles(row!["host", "cpu", "mem"]);
+    /// table.add_title_group(row!["", H2c->"usage"]);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+-----------+
+    /// |       |   usage   |
+    /// +-------+-----+-----+
+    /// | host  | cpu | mem |
+    /// +=======+=====+=====+
+    /// | web-1 | 2   | 4   |
+    /// +-------+-----+-----+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn add_title_group(&mut self, mut group: Row) {
+        group.set_header(true);
+        self.settings.title_groups.push(group);
     }
 
-    /// Append an empty row in the table. Return a mutable reference to this new row.
-    pub fn add_empty_row(&mut self) -> &mut Row {
-        self.add_row(Row::default())
+    /// Remove all the rows of title groups
+    pub fn clear_title_groups(&mut self) {
+        self.settings.title_groups.clear();
     }
 
-    /// Insert `row` at the position `index`, and return a mutable reference to this row.
-    /// If index is higher than current numbers of rows, `row` is appended at the end of the table
-    pub fn insert_row(&mut self, index: usize, row: Row) -> &mut Row {
-        if index < self.rows.len() {
-            self.rows.insert(index, row);
-            &mut self.rows[index]
-        } else {
-            self.add_row(row)
-        }
+    /// Set the maximum size in bytes of the table rendered with `Display`.
+    /// A bigger table is truncated after its last line fitting in this size instead of
+    /// exhausting memory, and followed by a `… output truncated at N bytes` line.
+    /// Use `try_to_string()` to get an error instead.
+    /// `None`, the default, means no limit
+    pub fn set_max_render_size(&mut self, max_size: Option<usize>) {
+        self.settings.max_render_size = max_size;
     }
 
-    /// Modify a single element in the table
-    pub fn set_element(&mut self, element: &str, column: usize, row: usize) -> Result<(), &str> {
-        let rowline = self.get_mut_row(row).ok_or("Cannot find row")?;
-        // TODO: If a cell already exist, copy it's alignment parameter
-        rowline.set_cell(Cell::new(element), column)
+    /// Render the table into a `String`, failing with `Error::TooLarge` instead of
+    /// allocating more than `max_size` bytes.
+    /// It's a safer alternative to `to_string()` when the table may be huge.
+    pub fn try_to_string(&self, max_size: usize) -> Result<String, Error> {
+        self.as_slice().try_to_string(max_size)
     }
 
-    /// Remove the row at position `index`. Silently skip if the row does not exist
-    pub fn remove_row(&mut self, index: usize) {
-        if index < self.rows.len() {
-            self.rows.remove(index);
-        }
+    /// Get the index of the column whose title is `name`,
+    /// or `None` if there's no title with this content
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        ColumnRef::Name(name).position(self.titles.as_ref().as_ref())
     }
 
-    /// Return an iterator over the immutable cells of the column specified by `column`
-    pub fn column_iter(&self, column: usize) -> ColumnIter {
-        ColumnIter(self.rows.iter(), column)
+    /// Get the row at index `row` bound to the titles of the table, so that its cells
+    /// can be looked up by column title, like `table.named_row(0).unwrap()["hostname"]`
+    pub fn named_row(&self, row: usize) -> Option<NamedRow<'_>> {
+        let titles = self.titles.as_ref().as_ref();
+        self.rows.get(row).map(|r| NamedRow::new(r, titles))
     }
 
-    /// Return an iterator over the mutable cells of the column specified by `column`
-    pub fn column_iter_mut(&mut self, column: usize) -> ColumnIterMut {
-        ColumnIterMut(self.rows.iter_mut(), column)
+    /// Returns an iterator over the rows bound to the titles of the table, so that their cells
+    /// can be looked up by column title, like `row["hostname"]`.
+    /// See [`NamedRow`](struct.NamedRow.html)
+    pub fn named_rows(&self) -> impl Iterator<Item = NamedRow<'_>> {
+        let titles = self.titles.as_ref().as_ref();
+        self.rows.iter().map(move |r| NamedRow::new(r, titles))
     }
 
-    /// Returns an iterator over immutable rows
-    pub fn row_iter(&self) -> Iter<Row> {
-        self.rows.iter()
+    /// Set a heading, like a report title, printed above the titles row.
+    /// It is centered in a single cell spanning the whole table width,
+    /// and separated from the rest of the table by the `LinePosition::Heading` line separator
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["apple", 3], ["pear", 2]);
+    /// table.set_titles(row!["fruit", "stock"]);
+    /// table.set_heading("Inventory");
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn set_heading(&mut self, heading: &str) {
+        let mut row = Row::full_width(heading);
+        row.align(Alignment::CENTER);
+        self.settings.heading = Some(row);
     }
 
-    /// Returns an iterator over mutable rows
-    pub fn row_iter_mut(&mut self) -> IterMut<Row> {
-        self.rows.iter_mut()
+    /// Unset the heading
+    pub fn unset_heading(&mut self) {
+        self.settings.heading = None;
     }
 
-    /// Print the table to `out` and returns the number
-    /// of lines printed, or an error
-    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
-        self.as_slice().print(out)
+    /// Set a caption, printed as free text above or below the table depending on `position`,
+    /// and centered relative to the table width.
+    /// It is also part of the HTML and JSON exports
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::CaptionPosition;
+    /// # fn main() {
+    /// let mut table = table!(["apple", "3"], ["pear", "5"]);
+    /// table.set_caption("Table 1: fruits", CaptionPosition::Below);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+---+
+    /// | apple | 3 |
+    /// +-------+---+
+    /// | pear  | 5 |
+    /// +-------+---+
+    /// Table 1: fruits
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_caption(&mut self, caption: &str, position: CaptionPosition) {
+        self.settings.caption = Some((caption.to_string(), position));
     }
 
-    /// Print the table to terminal `out`, applying styles when needed and returns the number
-    /// of lines printed, or an error
-    pub fn print_term<T: Terminal + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
-        self.as_slice().print_term(out)
+    /// Unset the caption
+    pub fn unset_caption(&mut self) {
+        self.settings.caption = None;
     }
 
-    /// Print the table to standard output. Colors won't be displayed unless
-    /// stdout is a tty terminal, or `force_colorize` is set to `true`.
-    /// In ANSI terminals, colors are displayed using ANSI escape characters. When for example the
-    /// output is redirected to a file, or piped to another program, the output is considered
-    /// as not beeing tty, and ANSI escape characters won't be displayed unless `force colorize`
-    /// is set to `true`.
-    /// # Returns
-    /// A `Result` holding the number of lines printed, or an `io::Error` if any failure happens
-    pub fn print_tty(&self, force_colorize: bool) -> Result<usize, Error> {
-        self.as_slice().print_tty(force_colorize)
+    /// Compute the `aggregates` of the rows, like sums or averages, when printing the table,
+    /// and print them in the footer, in the columns they aggregate.
+    /// If the footer is set, its cells in these columns are replaced with the aggregates,
+    /// so that other columns can hold a label. The aggregates are also part of the exports
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Aggregate;
+    /// # fn main() {
+    /// let mut table = table!(["apple", "3", "1.25"], ["pear", "5", "0.5"]);
+    /// table.set_footer(row!["total"]);
+    /// table.set_aggregate_footer(&[Aggregate::Sum(1), Aggregate::Avg(2)]);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+---+------+
+    /// | apple | 3 | 1.25 |
+    /// +-------+---+------+
+    /// | pear  | 5 | 0.5  |
+    /// +=======+===+======+
+    /// | total | 8 | 0.88 |
+    /// +-------+---+------+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_aggregate_footer(&mut self, aggregates: &[Aggregate]) {
+        self.settings.aggregates = aggregates.to_vec();
     }
 
-    /// Print the table to standard output. Colors won't be displayed unless
-    /// stdout is a tty terminal. This means that if stdout is redirected to a file, or piped
-    /// to another program, no color will be displayed.
-    /// To force colors rendering, use `print_tty()` method.
-    /// Any failure to print is ignored. For better control, use `print_tty()`.
-    /// Calling `printstd()` is equivalent to calling `print_tty(false)` and ignoring the result.
-    pub fn printstd(&self) {
-        self.as_slice().printstd()
+    /// Remove the aggregates from the footer
+    pub fn unset_aggregate_footer(&mut self) {
+        self.settings.aggregates.clear();
     }
 
-    /// Print table in HTML format to `out`.
-    pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), Error> {
-        self.as_slice().print_html(out)
+    /// Set the optional footer line, printed below the rows.
+    /// It is useful for totals, for example
+    pub fn set_footer(&mut self, footer: Row) {
+        *self.footer = Some(footer);
     }
-}
 
-/// Trait implemented by types which can be sliced
-pub trait AsTableSlice {
-    /// Get a slice from self
-    fn as_slice(&self) -> TableSlice<'_>;
-}
+    /// Unset the footer line
+    pub fn unset_footer(&mut self) {
+        *self.footer = None;
+    }
 
-impl AsTableSlice for Table {
-    fn as_slice(&self) -> TableSlice<'_> {
-        TableSlice {
-            format: &self.format,
-            titles: &self.titles,
-            rows: &self.rows,
+    /// Hide or show the column `column`, given by position or title, when printing or exporting
+    /// the table. Hidden columns keep their data, and can be shown again later
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["a", "verbose details", "b"]);
+    /// table.set_column_hidden(1, true);
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), "+---+---+\n| a | b |\n+---+---+\n");
+    /// assert_eq!(table[0][1].get_content(), "verbose details");
+    /// # }
+    /// ```
+    pub fn set_column_hidden<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, hidden: bool) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.hidden = hidden;
         }
     }
-}
 
-impl<T> AsTableSlice for T
-where
-    T: AsRef<Table>,
-{
-    fn as_slice(&self) -> TableSlice<'_> {
-        self.as_ref().as_slice()
+    /// Hide the column `column`, given by position or title, when printing or exporting the table
+    /// if all its cells are empty,
+    /// or contain the placeholder set with `set_empty_placeholder()`.
+    /// It's handy when printing many optional fields which are usually not set.
+    /// Titles and footer are not taken into account to decide whether the column is empty
+    pub fn set_column_hide_if_empty<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, hide: bool) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.hide_if_empty = hide;
+        }
     }
-}
 
-impl Index<usize> for Table {
-    type Output = Row;
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.rows[idx]
+    /// Lay the table out from right to left when printing it, for Arabic or Hebrew content:
+    /// the first column is printed on the right, the left and right padding are swapped,
+    /// and cells aligned left, including by default, are aligned right and conversely.
+    /// The order of the data is not changed
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["a", "bc"], ["def", r->"g"]);
+    /// table.set_right_to_left(true);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +----+-----+
+    /// | bc |   a |
+    /// +----+-----+
+    /// | g  | def |
+    /// +----+-----+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_right_to_left(&mut self, rtl: bool) {
+        self.settings.right_to_left = rtl;
     }
-}
 
-impl<'a> Index<usize> for TableSlice<'a> {
-    type Output = Row;
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.rows[idx]
+    /// Set whether the box drawing characters of the borders and separators, including the ones
+    /// of rows with their own format, are replaced with their ASCII equivalents when printing,
+    /// so that a single format can be used for outputs not supporting UTF-8.
+    /// It's done automatically by `print_tty()` and `printstd()` when the locale doesn't use UTF-8
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_ROUNDED;
+    /// # fn main() {
+    /// let mut table = table!(["a", "b"]);
+    /// table.set_format(*FORMAT_ROUNDED);
+    /// table.set_ascii_borders(true);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +---+---+
+    /// | a | b |
+    /// +---+---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_ascii_borders(&mut self, ascii: bool) {
+        self.settings.ascii_borders = ascii;
     }
-}
 
-impl IndexMut<usize> for Table {
-    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        &mut self.rows[idx]
+    /// Hide all the columns whose cells are empty when printing or exporting the table,
+    /// like with `set_column_hide_if_empty()` for each column.
+    /// It's handy for tables generically built from sparse data.
+    /// A table without any row keeps all its columns
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["a", "", "b"], ["c", "", "d"]);
+    /// table.set_hide_empty_columns(true);
+    /// assert_eq!(table.to_string().lines().next(), Some("+---+---+"));
+    /// # }
+    /// ```
+    pub fn set_hide_empty_columns(&mut self, hide: bool) {
+        self.settings.hide_empty_columns = hide;
     }
-}
 
-impl fmt::Display for Table {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        self.as_slice().fmt(fmt)
+    /// Add a default style attribute to all the cells in this table.
+    /// Column, row and cell style attributes take precedence, in this order
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::{color, Attr};
+    /// # fn main() {
+    /// let mut table = table!(["ok", "2"], [Fr->"failed", "1"]);
+    /// table.style(Attr::ForegroundColor(color::GREEN));
+    /// table.column_style(1, Attr::Bold);
+    /// // Prints "ok" in green, "failed" in red, and the 2nd column in bold
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn style(&mut self, attr: Attr) {
+        self.settings.style.push(attr);
     }
-}
 
-impl<'a> fmt::Display for TableSlice<'a> {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let mut writer = StringWriter::new();
-        if self.print(&mut writer).is_err() {
-            return Err(fmt::Error);
-        }
-        fmt.write_str(writer.as_string())
+    /// Remove all the table's default style attributes.
+    /// Column, row and cell style are not modified
+    pub fn reset_style(&mut self) {
+        self.settings.style.clear();
     }
-}
 
-impl<B: ToString, A: IntoIterator<Item = B>> FromIterator<A> for Table {
-    fn from_iter<T>(iterator: T) -> Table
-    where
-        T: IntoIterator<Item = A>,
-    {
-        Self::init(iterator.into_iter().map(Row::from).collect())
+    /// Set a style attribute applied to every other data row, starting with the second one,
+    /// like a background color or a dim style making long rows easier to follow.
+    /// It's applied like a row style attribute, cell style attributes taking precedence.
+    /// Full width rows and group headers are neither striped nor counted
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::{color, Attr};
+    /// # fn main() {
+    /// let mut table = table!(["a", 1], ["b", 2], ["c", 3]);
+    /// table.set_stripe_style(Attr::BackgroundColor(color::BRIGHT_BLACK));
+    /// // Prints the row of "b" with a gray background
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn set_stripe_style(&mut self, style: Attr) {
+        self.settings.stripe_style = Some(style);
     }
-}
 
-impl FromIterator<Row> for Table {
-    fn from_iter<T>(iterator: T) -> Table
-    where
-        T: IntoIterator<Item = Row>,
-    {
-        Self::init(iterator.into_iter().collect())
+    /// Remove the style applied to every other data row
+    pub fn unset_stripe_style(&mut self) {
+        self.settings.stripe_style = None;
     }
-}
 
-impl<T, A, B> From<T> for Table
-where
-    B: ToString,
-    A: IntoIterator<Item = B>,
-    T: IntoIterator<Item = A>,
-{
-    fn from(it: T) -> Table {
-        Self::from_iter(it)
+    /// Add a default style attribute to all the cells in the column `column`, given by position or title.
+    /// It applies on top of the table's default style,
+    /// while row and cell style attributes take precedence
+    pub fn column_style<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, attr: Attr) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.style.push(attr);
+        }
     }
-}
 
-impl<'a> IntoIterator for &'a Table {
-    type Item = &'a Row;
-    type IntoIter = Iter<'a, Row>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.row_iter()
+    /// Set the default style of the column `column`, given by position or title,
+    /// by applying the given specifier string. It replaces the column's default
+    /// style attributes and alignment, while row and cell ones still take precedence.
+    ///
+    /// See [`Cell::style_spec`](struct.Cell.html#method.style_spec) for the specifier syntax.
+    /// Horizontal spanning specifiers are ignored
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["disk", "98%"], ["memory", Fg->"12%"]);
+    /// // Usage in bold red and right aligned, unless cells say otherwise
+    /// table.set_column_style(1, "bFrr");
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn set_column_style<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, spec: &str) {
+        let spec = Cell::default().style_spec(spec);
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.style = spec.get_style().to_vec();
+            settings.align = spec.get_explicit_alignment();
+        }
     }
-}
 
-impl<'a> IntoIterator for &'a mut Table {
-    type Item = &'a mut Row;
-    type IntoIter = IterMut<'a, Row>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.row_iter_mut()
+    /// Remove all the default style attributes of the column `column`, given by position or title
+    pub fn reset_column_style<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.style.clear();
+        }
     }
-}
 
-// impl IntoIterator for Table {
-//     type Item = Row;
-//     type IntoIter = std::vec::IntoIter<Self::Item>;
-//     fn into_iter(self) -> Self::IntoIter {
-//         self.rows.into_iter()
-//     }
-// }
+    /// Constrain the width of the column `column`, given by position or title.
+    /// Content too wide for a maximum width is truncated with an ellipsis,
+    /// or wrapped onto several lines if enabled with [`set_column_wrap`](#method.set_column_wrap).
+    /// Setting a minimum or a maximum width keeps the other bound, so that both can be set,
+    /// moving it if needed for the minimum not to exceed the maximum.
+    /// Unlike fixed widths, they can be shrunk to fit [`set_max_width`](#method.set_max_width).
+    /// Spanning cells are not constrained
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::ColumnWidth;
+    /// # fn main() {
+    /// let mut table = table!(["1", "Some quite long description"]);
+    /// table.set_column_width(0, ColumnWidth::Fixed(4));
+    /// table.set_column_width(1, ColumnWidth::Max(10));
+    /// // +------+------------+
+    /// // | 1    | Some quit… |
+    /// // +------+------------+
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn set_column_width<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, width: ColumnWidth) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            let (min, max) = match width {
+                ColumnWidth::Fixed(n) => (n, Some(n)),
+                ColumnWidth::Min(n) => (n, settings.max_width.map(|max| max.max(n))),
+                ColumnWidth::Max(n) => (settings.min_width.min(n), Some(n)),
+            };
+            settings.min_width = min;
+            settings.max_width = max;
+            settings.fixed_width = matches!(width, ColumnWidth::Fixed(_));
+        }
+    }
 
-impl<A: Into<Row>> Extend<A> for Table {
-    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
-        self.rows.extend(iter.into_iter().map(|r| r.into()));
+    /// Remove the width constraints of the column `column`, given by position or title
+    pub fn unset_column_width<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.min_width = 0;
+            settings.max_width = None;
+            settings.fixed_width = false;
+        }
     }
-}
 
-/// Iterator over immutable cells in a column
-pub struct ColumnIter<'a>(Iter<'a, Row>, usize);
+    /// Set the maximum width of the table, borders included, when printed as text.
+    /// Columns are shrunk to fit, by sharing the available width equally between them,
+    /// narrower columns leaving what they don't need to the others.
+    /// Columns with a fixed width are left as is, while minimum widths may not be honored.
+    ///
+    /// Content too wide for the resulting columns is truncated, or wrapped for columns
+    /// set with [`set_column_wrap`](#method.set_column_wrap). Spanning cells and
+    /// full width rows are not constrained, and may still make the table wider.
+    /// `None`, the default, means no limit
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["id", "A very long description of the item"]);
+    /// table.set_column_wrap(1, true);
+    /// table.set_max_width(Some(30));
+    /// // +----+--------------------+
+    /// // | id | A very long        |
+    /// // |    | description of the |
+    /// // |    | item               |
+    /// // +----+--------------------+
+    /// let rendered = table.to_string();
+    /// assert!(rendered.lines().all(|l| l.chars().count() <= 30));
+    /// # }
+    /// ```
+    pub fn set_max_width(&mut self, max_width: Option<usize>) {
+        self.settings.max_width = max_width;
+    }
 
-impl<'a> Iterator for ColumnIter<'a> {
-    type Item = &'a Cell;
-    fn next(&mut self) -> Option<&'a Cell> {
-        self.0.next().and_then(|row| row.get_cell(self.1))
+    /// Set whether the table is fitted to the width of the terminal by `print_tty()` and `printstd()`,
+    /// which is the default. When printing to a terminal narrower than the table,
+    /// columns are then shrunk like with [`set_max_width`](#method.set_max_width),
+    /// so that lines don't wrap. The width is queried from the terminal, or read from the
+    /// `COLUMNS` environment variable. It has no effect when a maximum width is set,
+    /// or when the output is not a terminal
+    pub fn set_auto_fit(&mut self, auto_fit: bool) {
+        self.settings.no_auto_fit = !auto_fit;
     }
-}
 
-/// Iterator over mutable cells in a column
-pub struct ColumnIterMut<'a>(IterMut<'a, Row>, usize);
+    /// Set whether a leading `#` column numbering the rows from 1 is printed.
+    /// Numbers are computed when printing, so they follow sorting, filtering and slicing.
+    /// Full width rows are not numbered.
+    /// The column is only added to the text output, and not to the exported data
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["alice"], ["bob"]);
+    /// table.set_titles(row!["name"]);
+    /// table.set_row_numbers(true);
+    /// // +---+-------+
+    /// // | # | name  |
+    /// // +===+=======+
+    /// // | 1 | alice |
+    /// // +---+-------+
+    /// // | 2 | bob   |
+    /// // +---+-------+
+    /// assert!(table.to_string().contains("| 2 | bob   |"));
+    /// # }
+    /// ```
+    pub fn set_row_numbers(&mut self, enabled: bool) {
+        self.settings.row_numbers = enabled;
+    }
 
-impl<'a> Iterator for ColumnIterMut<'a> {
-    type Item = &'a mut Cell;
-    fn next(&mut self) -> Option<&'a mut Cell> {
-        self.0.next().and_then(|row| row.get_mut_cell(self.1))
+    /// Set whether the number of rows is printed on a line below the table, like `(42 rows)`.
+    /// Hidden and full width rows are not counted
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["alice"], ["bob"]);
+    /// table.set_summary(true);
+    /// // +-------+
+    /// // | alice |
+    /// // +-------+
+    /// // | bob   |
+    /// // +-------+
+    /// // (2 rows)
+    /// assert_eq!(table.to_string().lines().last(), Some("(2 rows)"));
+    /// # }
+    /// ```
+    pub fn set_summary(&mut self, enabled: bool) {
+        self.settings.summary = enabled;
     }
-}
 
-impl<'a> AsTableSlice for TableSlice<'a> {
-    fn as_slice(&self) -> TableSlice<'_> {
-        *self
+    /// Print the titles again every `rows` data rows, followed by the title separator,
+    /// so that long listings stay readable while scrolling. `None` prints them only once,
+    /// at the top of the table
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE;
+    /// # fn main() {
+    /// let mut table = table!(["a"], ["b"], ["c"]);
+    /// table.set_titles(row!["t"]);
+    /// table.set_format(*FORMAT_NO_LINESEP_WITH_TITLE);
+    /// table.set_repeat_titles(Some(2));
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +---+
+    /// | t |
+    /// +---+
+    /// | a |
+    /// | b |
+    /// | t |
+    /// +---+
+    /// | c |
+    /// +---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_repeat_titles(&mut self, rows: Option<usize>) {
+        self.settings.repeat_titles = rows;
     }
-}
 
-impl<'a> AsRef<TableSlice<'a>> for TableSlice<'a> {
-    fn as_ref(&self) -> &TableSlice<'a> {
-        self
+    /// Print the internal line separator only after every `rows` data rows, like a rule
+    /// every 5 rows in dense numeric tables, even if the format doesn't separate rows.
+    /// `None` lets the format decide. Rows forcing or suppressing the separator after them
+    /// with `Row::set_separator_after()` are not affected
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["1"], ["2"], ["3"]);
+    /// table.set_separator_every(Some(2));
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +---+
+    /// | 1 |
+    /// | 2 |
+    /// +---+
+    /// | 3 |
+    /// +---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_separator_every(&mut self, rows: Option<usize>) {
+        self.settings.separator_every = rows;
     }
-}
 
-/// Trait implemented by types which can be sliced
-pub trait Slice<'a, E> {
-    /// Type output after slicing
-    type Output: 'a;
-    /// Get a slice from self
-    fn slice(&'a self, arg: E) -> Self::Output;
-}
+    /// Set whether the content too wide for the maximum width of the column `column`,
+    /// given by position or title, is wrapped onto several lines instead of being truncated.
+    /// Lines are broken between words when possible
+    pub fn set_column_wrap<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, wrap: bool) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.wrap = wrap;
+        }
+    }
 
-impl<'a, T, E> Slice<'a, E> for T
-where
-    T: AsTableSlice,
-    [Row]: Index<E, Output = [Row]>,
+    /// Set the default alignment of the cells in the column `column`, given by position or title.
+    /// Cells and rows with their own alignment are not affected
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::Alignment;
+    /// # fn main() {
+    /// let mut table = table!(["disk", "42"]);
+    /// table.set_titles(row!["name", "size"]);
+    /// table.set_column_alignment("size", Alignment::RIGHT);
+    /// // Rows added afterwards are aligned too
+    /// table.add_row(row!["memory", "1024"]);
+    /// # }
+    /// ```
+    pub fn set_column_alignment<'c, C: Into<ColumnRef<'c>>>(
+        &mut self,
+        column: C,
+        align: Alignment,
+    ) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.align = Some(align);
+        }
+    }
+
+    /// Remove the default alignment of the column `column`, given by position or title
+    pub fn unset_column_alignment<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.align = None;
+        }
+    }
+
+    /// Set the character of the title line separator under the column `column`,
+    /// given by position or title, instead of the format's one.
+    /// It's handy to mark the key columns of a table
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["1", "apple"]);
+    /// table.set_titles(row!["id", "name"]);
+    /// table.set_column_title_underline("name", '-');
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +----+-------+
+    /// | id | name  |
+    /// +====+-------+
+    /// | 1  | apple |
+    /// +----+-------+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_column_title_underline<'c, C: Into<ColumnRef<'c>>>(
+        &mut self,
+        column: C,
+        line: char,
+    ) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.title_line = Some(line);
+        }
+    }
+
+    /// Remove the character of the title line separator under the column `column`,
+    /// given by position or title, which is then drawn with the format's one
+    pub fn unset_column_title_underline<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        if let Some(settings) = self.column_settings_by_ref(column) {
+            settings.title_line = None;
+        }
+    }
+
+    /// Get a mutable reference to the settings of the column at position `column`,
+    /// creating default settings if needed
+    fn column_settings_mut(&mut self, column: usize) -> &mut ColumnSettings {
+        let columns = &mut self.settings.columns;
+        if column >= columns.len() {
+            columns.resize(column + 1, ColumnSettings::default());
+        }
+        &mut columns[column]
+    }
+
+    /// Get the position of `column`, looking its title up if needed
+    fn column_position<'c, C: Into<ColumnRef<'c>>>(&self, column: C) -> Option<usize> {
+        column.into().position(self.titles.as_ref().as_ref())
+    }
+
+    /// Same as `column_settings_mut()`, with the column given by position or title.
+    /// Return `None` if there's no column with this title
+    fn column_settings_by_ref<'c, C: Into<ColumnRef<'c>>>(
+        &mut self,
+        column: C,
+    ) -> Option<&mut ColumnSettings> {
+        let column = self.column_position(column)?;
+        Some(self.column_settings_mut(column))
+    }
+
+    /// Give the same width to the columns at the same position in all the `tables`,
+    /// so that they line up column for column when printed one after another.
+    /// Each column gets the width of the widest one in the group, which is set as its minimum width.
+    /// Tables should share the same format, and must be aligned again if their content changes.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Table;
+    /// # fn main() {
+    /// let mut t1 = table!(["a", "long content"]);
+    /// let mut t2 = table!(["longer content", "b"]);
+    /// Table::align_widths(&mut [&mut t1, &mut t2]);
+    /// t1.printstd();
+    /// t2.printstd();
+    /// # }
+    /// ```
+    pub fn align_widths(tables: &mut [&mut Table]) {
+        let mut widths: Vec<usize> = Vec::new();
+        for table in tables.iter() {
+            let col_width = table.as_slice().get_all_column_width();
+            if col_width.len() > widths.len() {
+                widths.resize(col_width.len(), 0);
+            }
+            for (w, cw) in widths.iter_mut().zip(col_width) {
+                *w = (*w).max(cw);
+            }
+        }
+        for table in tables.iter_mut() {
+            for (i, w) in widths.iter().enumerate() {
+                table.column_settings_mut(i).min_width = *w;
+            }
+        }
+    }
+
+    /// Set the content standing for a missing value, eg. `"-"` or `"None"`.
+    /// Cells with this content are considered empty when hiding empty columns
+    pub fn set_empty_placeholder(&mut self, placeholder: &str) {
+        self.settings.empty_placeholder = Some(placeholder.to_owned());
+    }
+
+    /// Unset the empty cells placeholder
+    pub fn unset_empty_placeholder(&mut self) {
+        self.settings.empty_placeholder = None;
+    }
+
+    /// Get a mutable reference to a row
+    pub fn get_mut_row(&mut self, row: usize) -> Option<&mut Row> {
+        self.rows.get_mut(row)
+    }
+
+    /// Get an immutable reference to a row
+    pub fn get_row(&self, row: usize) -> Option<&Row> {
+        self.rows.get(row)
+    }
+
+    /// Append a row in the table, transferring ownership of this row to the table
+    /// and returning a mutable reference to the row
+    pub fn add_row(&mut self, row: Row) -> &mut Row {
+        self.rows.push(row);
+        let l = self.rows.len() - 1;
+        &mut self.rows[l]
+    }
+
+    /// Append an empty row in the table. Return a mutable reference to this new row.
+    pub fn add_empty_row(&mut self) -> &mut Row {
+        self.add_row(Row::default())
+    }
+
+    /// Append a horizontal rule after the last row, printed with the internal line separator
+    /// even if the format doesn't separate rows, to visually group the rows into sections.
+    /// It's a shortcut for adding a [`Row::separator`](struct.Row.html#method.separator).
+    /// Return a mutable reference to the separator row, whose format may be set
+    /// to draw this rule differently
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::consts::FORMAT_NO_LINESEP;
+    /// # fn main() {
+    /// let mut table = table!(["apple", 3], ["pear", 2]);
+    /// table.add_separator();
+    /// table.add_row(row!["total", 5]);
+    /// table.set_format(*FORMAT_NO_LINESEP);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+---+
+    /// | apple | 3 |
+    /// | pear  | 2 |
+    /// +-------+---+
+    /// | total | 5 |
+    /// +-------+---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn add_separator(&mut self) -> &mut Row {
+        self.add_row(Row::separator())
+    }
+
+    /// Insert a horizontal rule before the row at position `index`, like `add_separator()`.
+    /// With `index` 0, the rule is printed before the first row.
+    /// If index is higher than current numbers of rows, the rule is appended at the end of the table.
+    /// Return a mutable reference to the separator row
+    pub fn insert_separator(&mut self, index: usize) -> &mut Row {
+        self.insert_row(index, Row::separator())
+    }
+
+    /// Append a group of `rows` in the table, under a group header row labelled with `label`.
+    /// The group header spans the whole table width, and is preceded by the group line separator.
+    /// Returns a mutable reference to the group header row
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Table;
+    /// # fn main() {
+    /// let mut table = Table::new();
+    /// table.add_group("Fruits", vec![row!["apple", 3], row!["pear", 2]]);
+    /// table.add_group("Vegetables", vec![row!["carrot", 12]]);
+    /// table.printstd();
+    /// # }
+    /// ```
+    pub fn add_group<I: IntoIterator<Item = Row>>(&mut self, label: &str, rows: I) -> &mut Row {
+        let idx = self.rows.len();
+        self.rows.push(Row::group_header(label));
+        self.rows.extend(rows);
+        &mut self.rows[idx]
+    }
+
+    /// Insert `row` at the position `index`, and return a mutable reference to this row.
+    /// If index is higher than current numbers of rows, `row` is appended at the end of the table
+    pub fn insert_row(&mut self, index: usize, row: Row) -> &mut Row {
+        if index < self.rows.len() {
+            self.rows.insert(index, row);
+            &mut self.rows[index]
+        } else {
+            self.add_row(row)
+        }
+    }
+
+    /// Modify a single element in the table.
+    /// Fails with `Error::OutOfBounds` if there's no such row, or no such cell in the row
+    pub fn set_element(&mut self, element: &str, column: usize, row: usize) -> Result<(), Error> {
+        let len = self.rows.len();
+        let rowline = self
+            .get_mut_row(row)
+            .ok_or(Error::OutOfBounds { index: row, len })?;
+        // TODO: If a cell already exist, copy it's alignment parameter
+        rowline.set_cell(Cell::new(element), column)
+    }
+
+    /// Remove the row at position `index`. Silently skip if the row does not exist
+    pub fn remove_row(&mut self, index: usize) {
+        if index < self.rows.len() {
+            self.rows.remove(index);
+        }
+    }
+
+    /// Remove all the rows, keeping the titles, footer, format and settings,
+    /// so that the table can be filled again, like on each refresh of a display
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Keep the first `len` rows and remove the others.
+    /// Nothing happens if the table has `len` rows or less
+    pub fn truncate(&mut self, len: usize) {
+        self.rows.truncate(len);
+    }
+
+    /// Keep only the rows for which `predicate` returns `true`, in their original order
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["web-1", "up"], ["web-2", "down"], ["db-1", "up"]);
+    /// table.retain(|row| row[1].get_content() == "up");
+    /// assert_eq!(table.len(), 2);
+    /// # }
+    /// ```
+    pub fn retain<F: FnMut(&Row) -> bool>(&mut self, predicate: F) {
+        self.rows.retain(predicate);
+    }
+
+    /// Remove the consecutive rows having the same content in the column `column`,
+    /// given by position or title, keeping the first one of each run.
+    /// Like `Vec::dedup`, the table should be sorted on this column to remove all the duplicates.
+    /// Rows missing this column are considered equal
+    pub fn dedup_by_column<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        let column = match self.column_position(column) {
+            Some(column) => column,
+            None => return,
+        };
+        self.rows.dedup_by(|a, b| {
+            a.get_cell(column).map(Cell::get_content) == b.get_cell(column).map(Cell::get_content)
+        });
+    }
+
+    /// Append clones of the rows of `other` at the end of this table, like when merging results
+    /// from several sources. Titles and footer of `other` are ignored.
+    /// Appended rows covering fewer columns than this table are completed with empty cells,
+    /// so that the merged table stays consistent. Tables can also be merged with `+` and `+=`
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["eu-west", "12", "ok"]);
+    /// table.extend_from_table(&table!(["us-east", "7"]));
+    /// assert_eq!(table[1].len(), 3);
+    /// assert_eq!(table.validate(), Ok(()));
+    /// # }
+    /// ```
+    pub fn extend_from_table(&mut self, other: &Table) {
+        self.append_rows(other.rows.iter().cloned());
+    }
+
+    /// Append `rows`, completing them with empty cells up to the number of columns of the table
+    fn append_rows<I: IntoIterator<Item = Row>>(&mut self, rows: I) {
+        let columns = self.get_column_num();
+        for mut row in rows {
+            if !row.is_full_width() {
+                while row.column_count() < columns {
+                    row.add_cell(Cell::default());
+                }
+            }
+            self.rows.push(row);
+        }
+    }
+
+    /// Insert a column at the position `index`, with `title` in the titles row and
+    /// the `cells` in the following rows, one per row.
+    /// Rows shorter than `index` are padded with empty cells, and rows are given an empty cell
+    /// when there are fewer `cells` than rows. Extra cells are added as new rows.
+    /// If the table has no titles, they are created when `title` is provided.
+    /// The footer, if any, gets an empty cell. Full width rows are left untouched
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Cell;
+    /// # fn main() {
+    /// let mut table = table!(["Alice", "42"], ["Bob", "37"]);
+    /// table.insert_column(1, Some(Cell::new("team")), vec![Cell::new("red"), Cell::new("blue")]);
+    /// assert_eq!(table[1][1].get_content(), "blue");
+    /// # }
+    /// ```
+    pub fn insert_column<I>(&mut self, index: usize, title: Option<Cell>, cells: I)
+    where
+        I: IntoIterator<Item = Cell>,
+    {
+        match (self.titles.as_mut(), title) {
+            (Some(titles), title) => titles.insert_column(index, title.unwrap_or_default()),
+            (None, Some(title)) => {
+                let mut titles = Row::empty();
+                titles.insert_column(index, title);
+                self.set_titles(titles);
+            }
+            (None, None) => (),
+        }
+        for group in &mut self.settings.title_groups {
+            group.insert_column(index, Cell::default());
+        }
+        let mut cells = cells.into_iter();
+        for row in self.rows.iter_mut().filter(|r| !r.is_full_width()) {
+            row.insert_column(index, cells.next().unwrap_or_default());
+        }
+        for cell in cells {
+            let mut row = Row::empty();
+            row.insert_column(index, cell);
+            self.rows.push(row);
+        }
+        if let Some(ref mut footer) = *self.footer {
+            footer.insert_column(index, Cell::default());
+        }
+        if index < self.settings.columns.len() {
+            self.settings
+                .columns
+                .insert(index, ColumnSettings::default());
+        }
+    }
+
+    /// Append a column at the right of the table, with `title` in the titles row and
+    /// the `cells` in the following rows, one per row.
+    /// `align` sets the default alignment of the cells in the new column, cells and rows
+    /// with their own alignment taking precedence.
+    /// See [`insert_column`](#method.insert_column) for how missing cells, extra cells and titles are handled
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Cell;
+    /// # use prettytable::format::Alignment;
+    /// # fn main() {
+    /// let mut table = table!(["Alice"], ["Bob"]);
+    /// table.set_titles(row!["name"]);
+    /// let scores = vec![Cell::new("42"), Cell::new("37")];
+    /// table.add_column(Some(Cell::new("score")), scores, Some(Alignment::RIGHT));
+    /// # }
+    /// ```
+    pub fn add_column<I>(&mut self, title: Option<Cell>, cells: I, align: Option<Alignment>)
+    where
+        I: IntoIterator<Item = Cell>,
+    {
+        let index = self.get_column_num();
+        self.insert_column(index, title, cells);
+        if align.is_some() {
+            self.column_settings_mut(index).align = align;
+        }
+    }
+
+    /// Remove the column `column`, given by position or title, from the titles, the rows and the footer.
+    /// Cells spanning over this column lose one column of span instead of being removed.
+    /// Full width rows are left untouched
+    pub fn remove_column<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) {
+        let index = match self.column_position(column) {
+            Some(index) => index,
+            None => return,
+        };
+        if let Some(ref mut titles) = *self.titles {
+            titles.remove_column(index);
+        }
+        for row in &mut self.rows {
+            row.remove_column(index);
+        }
+        if let Some(ref mut footer) = *self.footer {
+            footer.remove_column(index);
+        }
+        for group in &mut self.settings.title_groups {
+            group.remove_column(index);
+        }
+        if index < self.settings.columns.len() {
+            self.settings.columns.remove(index);
+        }
+    }
+
+    /// Change the order of the columns, in the titles, the rows, the footer and the column settings.
+    /// `order` gives the current position of the columns in their new order.
+    /// Columns missing from `order` are moved after the listed ones, keeping their relative order,
+    /// while out of range or duplicated positions are ignored.
+    /// Spanning cells whose columns are no longer adjacent are split, the first part keeping
+    /// the content. Full width rows are left untouched
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["a", "b", "c"]);
+    /// table.reorder_columns(&[2, 0]);
+    /// assert_eq!(table.to_string().replace("\r\n", "\n"), "+---+---+---+\n| c | a | b |\n+---+---+---+\n");
+    /// # }
+    /// ```
+    pub fn reorder_columns(&mut self, order: &[usize]) {
+        let column_num = self.get_column_num();
+        let mut columns = Vec::with_capacity(column_num);
+        for &col in order.iter().chain(&(0..column_num).collect::<Vec<_>>()) {
+            if col < column_num && !columns.contains(&col) {
+                columns.push(col);
+            }
+        }
+        if let Some(ref mut titles) = *self.titles {
+            *titles = titles.project(&columns);
+        }
+        for row in &mut self.rows {
+            *row = row.project(&columns);
+        }
+        if let Some(ref mut footer) = *self.footer {
+            *footer = footer.project(&columns);
+        }
+        for group in &mut self.settings.title_groups {
+            *group = group.project(&columns);
+        }
+        let settings = &self.settings.columns;
+        let reordered = columns
+            .iter()
+            .map(|&i| settings.get(i).cloned().unwrap_or_default())
+            .collect::<Vec<_>>();
+        self.settings.columns = reordered;
+    }
+
+    /// Return a printable view over the rows of this table matching `predicate`,
+    /// without cloning them into a new table
+    pub fn filter_view<F: FnMut(&Row) -> bool>(&self, predicate: F) -> FilteredView<'_> {
+        self.as_slice().filter_view(predicate)
+    }
+
+    /// Get a mutable slice of the rows in `range`, like `table.slice_mut(1..4)`,
+    /// whose rows and cells can be edited in bulk
+    pub fn slice_mut<E>(&mut self, range: E) -> TableSliceMut<'_>
+    where
+        [Row]: IndexMut<E, Output = [Row]>,
+    {
+        TableSliceMut {
+            titles: &self.titles,
+            rows: self.rows.as_mut_slice().index_mut(range),
+        }
+    }
+
+    /// Get a slice restricted to the columns in `range`.
+    /// See [`TableSlice::slice_columns`](struct.TableSlice.html#method.slice_columns) for details
+    pub fn slice_columns<R: RangeBounds<usize>>(&self, range: R) -> TableSlice<'_> {
+        self.as_slice().slice_columns(range)
+    }
+
+    /// Get a slice of the whole table printed with `format`, leaving the table's own format
+    /// unchanged. See [`TableSlice::with_format`](struct.TableSlice.html#method.with_format)
+    pub fn with_format<'a>(&'a self, format: &'a TableFormat) -> TableSlice<'a> {
+        self.as_slice().with_format(format)
+    }
+
+    /// Sort the rows in place, comparing their cells in the column `column`,
+    /// given by position or title, with `compare`.
+    /// The sort is stable, and rows without a cell in this column are moved at the end.
+    /// Rows are left untouched if there's no column with this title
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["b", 10], ["a", 2], ["c", 1]);
+    /// // Sort by numeric value of the 2nd column
+    /// table.sort_by_column(1, |a, b| {
+    ///     let a: i32 = a.get_content().parse().unwrap();
+    ///     let b: i32 = b.get_content().parse().unwrap();
+    ///     a.cmp(&b)
+    /// });
+    /// assert_eq!(table[0][0].get_content(), "c");
+    /// # }
+    /// ```
+    pub fn sort_by_column<'c, C, F>(&mut self, column: C, mut compare: F)
+    where
+        C: Into<ColumnRef<'c>>,
+        F: FnMut(&Cell, &Cell) -> Ordering,
+    {
+        let column = match self.column_position(column) {
+            Some(column) => column,
+            None => return,
+        };
+        self.rows
+            .sort_by(|a, b| match (a.get_cell(column), b.get_cell(column)) {
+                (Some(a), Some(b)) => compare(a, b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+    }
+
+    /// Sort the rows in place by the content of their cells in the column `column`,
+    /// given by position or title, compared as strings, in ascending or descending order
+    pub fn sort_by_column_str<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C, ascending: bool) {
+        self.sort_by_column(column, |a, b| {
+            let ord = a.get_content().cmp(&b.get_content());
+            if ascending {
+                ord
+            } else {
+                ord.reverse()
+            }
+        });
+    }
+
+    /// Format the numbers in the column `column`, given by position or title,
+    /// with the same number of decimal places,
+    /// and align them to the right, so that they line up.
+    /// The number of decimal places is the smallest one at which different values are
+    /// still printed differently, limited to `max_precision`.
+    /// Cells not containing a number are left untouched.
+    ///
+    /// Only the current rows are formatted, rows added afterwards are not
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["t1", 21.5], ["t2", 21.25], ["t3", 21.0], ["t4", 20.0 + 0.1 + 0.2]);
+    /// table.format_float_column(1, 3);
+    /// assert_eq!(table[0][1].get_content(), "21.5");
+    /// assert_eq!(table[3][1].get_content(), "20.3");
+    /// # }
+    /// ```
+    pub fn format_float_column<'c, C: Into<ColumnRef<'c>>>(
+        &mut self,
+        column: C,
+        max_precision: usize,
+    ) {
+        let column = match self.column_position(column) {
+            Some(column) => column,
+            None => return,
+        };
+        let mut values = self
+            .column_iter(column)
+            .filter_map(|c| c.get_content().trim().parse::<f64>().ok())
+            .filter(|v| !v.is_nan())
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+        // Rounding keeps the order, so only neighbours may become equal
+        let rounded = |v: f64, precision: usize| format!("{:.*}", precision, v).parse::<f64>();
+        let precision = (0..max_precision)
+            .find(|&p| {
+                values
+                    .windows(2)
+                    .all(|w| rounded(w[0], p) != rounded(w[1], p))
+            })
+            .unwrap_or(max_precision);
+        for cell in self.column_iter_mut(column) {
+            if let Ok(v) = cell.get_content().trim().parse::<f64>() {
+                cell.set_content(&format!("{:.*}", precision, v));
+                cell.align(Alignment::RIGHT);
+            }
+        }
+    }
+
+    /// Get the width of each column, as used when printing the table.
+    /// It can be given to [`Row::render`](struct.Row.html#method.render) to print rows
+    /// lining up with this table
+    pub fn column_widths(&self) -> Vec<usize> {
+        self.as_slice().column_widths()
+    }
+
+    /// Return an iterator over the immutable cells of the column specified by `column`,
+    /// either its position or its title. It's empty if there's no column with this title
+    pub fn column_iter<'c, C: Into<ColumnRef<'c>>>(&self, column: C) -> ColumnIter<'_> {
+        self.as_slice().column_iter(column)
+    }
+
+    /// Return an iterator over the mutable cells of the column specified by `column`,
+    /// either its position or its title. It's empty if there's no column with this title
+    pub fn column_iter_mut<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) -> ColumnIterMut<'_> {
+        match self.column_position(column) {
+            Some(column) => ColumnIterMut(self.rows.iter_mut(), column),
+            None => ColumnIterMut([].iter_mut(), 0),
+        }
+    }
+
+    /// Returns an iterator over immutable rows
+    pub fn row_iter(&self) -> Iter<'_, Row> {
+        self.rows.iter()
+    }
+
+    /// Returns an iterator over the rows, with their index, styled with `attr`,
+    /// either as a row default style or on one of their cells.
+    /// It's useful to retrieve rows whose style marks a state, like failures printed in red
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::{color, Attr};
+    /// # fn main() {
+    /// let table = table!(["build", "ok"], ["test", Fr->"failed"], ["lint", "ok"]);
+    /// let failed = table.rows_with_style(Attr::ForegroundColor(color::RED)).count();
+    /// assert_eq!(failed, 1);
+    /// # }
+    /// ```
+    pub fn rows_with_style(&self, attr: Attr) -> impl Iterator<Item = (usize, &Row)> {
+        self.as_slice().rows_with_style(attr)
+    }
+
+    /// Returns an iterator over mutable rows
+    pub fn row_iter_mut(&mut self) -> IterMut<'_, Row> {
+        self.rows.iter_mut()
+    }
+
+    /// Build a table made of `n` rows of this table selected with `strategy`, for a preview
+    /// of a huge dataset. A full width row like `… 42 more rows` is added where rows were elided.
+    /// Titles, footer, format and settings are kept
+    pub fn sample(&self, n: usize, strategy: SampleStrategy) -> Table {
+        self.as_slice().sample(n, strategy)
+    }
+
+    /// Build a table made of the columns given by position or title in `columns`, in this order.
+    /// See [`TableSlice::project`](struct.TableSlice.html#method.project) for details
+    pub fn project<'c, C>(&self, columns: &[C]) -> Table
+    where
+        C: Into<ColumnRef<'c>> + Copy,
+    {
+        self.as_slice().project(columns)
+    }
+
+    /// Print the first `head` and the last `tail` rows of the table to `out`, with the number
+    /// of omitted rows between them.
+    /// See [`TableSlice::print_snipped`](struct.TableSlice.html#method.print_snipped) for details
+    pub fn print_snipped<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        head: usize,
+        tail: usize,
+    ) -> Result<usize, io::Error> {
+        self.as_slice().print_snipped(out, head, tail)
+    }
+
+    /// Print the table to `out` and returns the number
+    /// of lines printed, or an error
+    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.as_slice().print(out)
+    }
+
+    /// Print the table to terminal `out`, applying styles when needed and returns the number
+    /// of lines printed, or an error
+    pub fn print_term<T: Terminal + ?Sized>(&self, out: &mut T) -> Result<usize, io::Error> {
+        self.as_slice().print_term(out)
+    }
+
+    /// Print the table to standard output. Colors won't be displayed unless
+    /// stdout is a tty terminal, or `force_colorize` is set to `true`.
+    /// In ANSI terminals, colors are displayed using ANSI escape characters. When for example the
+    /// output is redirected to a file, or piped to another program, the output is considered
+    /// as not beeing tty, and ANSI escape characters won't be displayed unless `force colorize`
+    /// is set to `true`.
+    /// # Returns
+    /// A `Result` holding the number of lines printed, or an `io::Error` if any failure happens,
+    /// including standard output being closed. See `try_print_tty()` to ignore the latter
+    pub fn print_tty(&self, force_colorize: bool) -> Result<usize, io::Error> {
+        self.as_slice().print_tty(force_colorize)
+    }
+
+    /// Print the table to standard output. Colors won't be displayed unless
+    /// stdout is a tty terminal. This means that if stdout is redirected to a file, or piped
+    /// to another program, no color will be displayed.
+    /// To force colors rendering, use `print_tty()` method.
+    /// Any failure to print is ignored. For better control, use `print_tty()`.
+    /// Calling `printstd()` is equivalent to calling `print_tty(false)` and ignoring the result.
+    pub fn printstd(&self) {
+        self.as_slice().printstd()
+    }
+
+    /// Print the table to standard output like `print_tty()`, without failing when standard output
+    /// is closed before the table is printed, like when piping into `head`.
+    /// Other errors are returned. It's the right choice for command line tools
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use prettytable::Table;
+    /// let table: Table = (0..1000).map(|i| vec![i, i * i]).collect();
+    /// if let Err(e) = table.try_print_tty(false) {
+    ///     eprintln!("error: {}", e);
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    pub fn try_print_tty(&self, force_colorize: bool) -> Result<(), io::Error> {
+        self.as_slice().try_print_tty(force_colorize)
+    }
+
+    /// Print the table to standard output like `printstd()`, returning errors other than
+    /// standard output being closed. See `try_print_tty()` for details
+    pub fn try_printstd(&self) -> Result<(), io::Error> {
+        self.as_slice().try_printstd()
+    }
+
+    /// Print the table to standard output like `print_tty()`, but through a pager when stdout is
+    /// a terminal and the table is taller than it. The pager is given by the `PAGER` environment
+    /// variable, and defaults to `less -R`. Colors are kept, the pager being expected to
+    /// interpret ANSI escape characters. If the pager can't be started, the table is printed
+    /// to standard output
+    /// # Returns
+    /// A `Result` holding the number of lines of the table, or an `io::Error` if any failure happens
+    pub fn print_paged(&self, force_colorize: bool) -> Result<usize, io::Error> {
+        self.as_slice().print_paged(force_colorize)
+    }
+
+    /// Print the table to standard output through a pager when it's taller than the terminal.
+    /// Any failure to print is ignored. See `print_paged()` for details
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use prettytable::Table;
+    /// let table: Table = (0..1000).map(|i| vec![i, i * i]).collect();
+    /// table.printstd_paged();
+    /// ```
+    pub fn printstd_paged(&self) {
+        self.as_slice().printstd_paged()
+    }
+
+    /// Print the table in HTML format to `out`, as a `<table>` element.
+    /// See [`TableSlice::print_html`](struct.TableSlice.html#method.print_html) for details
+    pub fn print_html<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), io::Error> {
+        self.as_slice().print_html(out)
+    }
+
+    /// Render the table in HTML format into a `String`.
+    /// See [`TableSlice::print_html`](struct.TableSlice.html#method.print_html) for details
+    pub fn to_html(&self) -> String {
+        self.as_slice().to_html()
+    }
+
+    /// Print table in HTML format to `out`, escaping cells content with `escaper`.
+    pub fn print_html_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+    ) -> Result<(), io::Error> {
+        self.as_slice().print_html_with_escaper(out, escaper)
+    }
+
+    /// Print table in HTML format to `out`, with the CSS classes and inline styles
+    /// configured in `options`.
+    /// See [`HtmlOptions`](struct.HtmlOptions.html) for details
+    pub fn print_html_with_options<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        options: &HtmlOptions,
+    ) -> Result<(), io::Error> {
+        self.as_slice().print_html_with_options(out, options)
+    }
+}
+
+/// Trait implemented by types which can be sliced
+pub trait AsTableSlice {
+    /// Get a slice from self
+    fn as_slice(&self) -> TableSlice<'_>;
+}
+
+impl AsTableSlice for Table {
+    fn as_slice(&self) -> TableSlice<'_> {
+        TableSlice {
+            format: &self.format,
+            titles: &self.titles,
+            rows: &self.rows,
+            footer: &self.footer,
+            settings: &self.settings,
+            selection: None,
+            columns: None,
+            view: None,
+        }
+    }
+}
+
+impl<T> AsTableSlice for T
+where
+    T: AsRef<Table>,
 {
-    type Output = TableSlice<'a>;
-    fn slice(&'a self, arg: E) -> Self::Output {
-        let mut sl = self.as_slice();
-        sl.rows = sl.rows.index(arg);
-        sl
+    fn as_slice(&self) -> TableSlice<'_> {
+        self.as_ref().as_slice()
     }
 }
 
-/// Create a table filled with some values
+impl Index<usize> for Table {
+    type Output = Row;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.rows[idx]
+    }
+}
+
+impl<'a> Index<usize> for TableSlice<'a> {
+    type Output = Row;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.rows[idx]
+    }
+}
+
+impl IndexMut<usize> for Table {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.rows[idx]
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.as_slice().fmt(fmt)
+    }
+}
+
+impl<'a> fmt::Display for TableSlice<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut writer = match self.settings.max_render_size {
+            Some(max) => StringWriter::with_limit(max, self.render_size_hint()),
+            None => StringWriter::new(),
+        };
+        match (self.print(&mut writer), self.settings.max_render_size) {
+            (Ok(_), _) => fmt.write_str(writer.as_string()),
+            // Output too big is cut after its last complete line, followed by a marker
+            (Err(_), Some(max)) if writer.limit_exceeded() => {
+                let out = writer.as_string();
+                let end = out.rfind('\n').map_or(0, |i| i + 1);
+                fmt.write_str(&out[..end])?;
+                write!(fmt, "… output truncated at {} bytes", max)?;
+                fmt.write_str(&String::from_utf8_lossy(self.format.newline_bytes()))
+            }
+            (Err(_), _) => Err(fmt::Error),
+        }
+    }
+}
+
+impl<B: ToString, A: IntoIterator<Item = B>> FromIterator<A> for Table {
+    fn from_iter<T>(iterator: T) -> Table
+    where
+        T: IntoIterator<Item = A>,
+    {
+        Self::init(iterator.into_iter().map(Row::from).collect())
+    }
+}
+
+impl FromIterator<Row> for Table {
+    fn from_iter<T>(iterator: T) -> Table
+    where
+        T: IntoIterator<Item = Row>,
+    {
+        Self::init(iterator.into_iter().collect())
+    }
+}
+
+impl<T, A, B> From<T> for Table
+where
+    B: ToString,
+    A: IntoIterator<Item = B>,
+    T: IntoIterator<Item = A>,
+{
+    fn from(it: T) -> Table {
+        Self::from_iter(it)
+    }
+}
+
+impl<'a> IntoIterator for &'a Table {
+    type Item = &'a Row;
+    type IntoIter = Iter<'a, Row>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.row_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Table {
+    type Item = &'a mut Row;
+    type IntoIter = IterMut<'a, Row>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.row_iter_mut()
+    }
+}
+
+impl IntoIterator for Table {
+    type Item = Row;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter()
+    }
+}
+
+impl<A: Into<Row>> Extend<A> for Table {
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        self.rows.extend(iter.into_iter().map(|r| r.into()));
+    }
+}
+
+/// Reference to a column, either by its position or by its title.
+/// Methods taking a column accept both a position (`usize`) and a title (`&str`),
+/// so that code accessing columns by title keeps working when their positions change.
+/// A title matching no column makes setters a no-op and iterators empty
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # use prettytable::format::Alignment;
+/// # fn main() {
+/// let mut table = table!(["Alice", "42"], ["Bob", "37"]);
+/// table.set_titles(row!["name", "score"]);
+/// assert_eq!(table.column_iter("score").count(), 2);
+/// table.set_column_alignment("score", Alignment::RIGHT);
+/// table.sort_by_column_str(0, false);
+/// table.sort_by_column_str("name", true);
+/// assert_eq!(table.column_index("score"), Some(1));
+/// assert_eq!(table.column_iter("missing").count(), 0);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ColumnRef<'a> {
+    /// The column at this position
+    Index(usize),
+    /// The column whose title has this content
+    Name(&'a str),
+}
+
+impl<'a> ColumnRef<'a> {
+    /// Get the position of the column, looking names up in `titles`
+    fn position(self, titles: Option<&Row>) -> Option<usize> {
+        match self {
+            ColumnRef::Index(index) => Some(index),
+            ColumnRef::Name(name) => titles?.iter().position(|c| c.get_content() == name),
+        }
+    }
+}
+
+impl<'a> From<usize> for ColumnRef<'a> {
+    fn from(index: usize) -> ColumnRef<'a> {
+        ColumnRef::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for ColumnRef<'a> {
+    fn from(name: &'a str) -> ColumnRef<'a> {
+        ColumnRef::Name(name)
+    }
+}
+
+impl<'a> From<&'a String> for ColumnRef<'a> {
+    fn from(name: &'a String) -> ColumnRef<'a> {
+        ColumnRef::Name(name)
+    }
+}
+
+impl Add for Table {
+    type Output = Table;
+    fn add(mut self, other: Table) -> Table {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<&'a Table> for Table {
+    type Output = Table;
+    fn add(mut self, other: &'a Table) -> Table {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for Table {
+    fn add_assign(&mut self, other: Table) {
+        self.append_rows(other.rows);
+    }
+}
+
+impl<'a> AddAssign<&'a Table> for Table {
+    fn add_assign(&mut self, other: &'a Table) {
+        self.extend_from_table(other);
+    }
+}
+
+/// Iterator over immutable cells in a column
+pub struct ColumnIter<'a>(Iter<'a, Row>, usize);
+
+impl<'a> Iterator for ColumnIter<'a> {
+    type Item = &'a Cell;
+    fn next(&mut self) -> Option<&'a Cell> {
+        self.0.next().and_then(|row| row.get_cell(self.1))
+    }
+}
+
+/// Iterator over mutable cells in a column
+pub struct ColumnIterMut<'a>(IterMut<'a, Row>, usize);
+
+impl<'a> Iterator for ColumnIterMut<'a> {
+    type Item = &'a mut Cell;
+    fn next(&mut self) -> Option<&'a mut Cell> {
+        self.0.next().and_then(|row| row.get_mut_cell(self.1))
+    }
+}
+
+/// A mutable `Table` slice, obtained with the `Table::slice_mut` method.
+/// It gives access to a range of rows, to edit them in bulk
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # use prettytable::{Attr, Cell};
+/// # use prettytable::format::Alignment;
+/// # fn main() {
+/// let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+/// let mut slice = table.slice_mut(1..);
+/// slice.style(Attr::Bold);
+/// slice.align(Alignment::RIGHT);
+/// for cell in slice.column_iter_mut(1) {
+///     *cell = Cell::new("0");
+/// }
+/// assert_eq!(table[2][1].get_content(), "0");
+/// assert!(table[1].has_style(Attr::Bold));
+/// # }
+/// ```
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct TableSliceMut<'a> {
+    titles: &'a Option<Row>,
+    rows: &'a mut [Row],
+}
+
+impl<'a> TableSliceMut<'a> {
+    /// Get the number of rows
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if the slice is empty
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Get a mutable reference to a row
+    pub fn get_mut_row(&mut self, row: usize) -> Option<&mut Row> {
+        self.rows.get_mut(row)
+    }
+
+    /// Returns an iterator over the mutable rows
+    pub fn row_iter_mut(&mut self) -> IterMut<'_, Row> {
+        self.rows.iter_mut()
+    }
+
+    /// Returns an iterator over the mutable cells of the column specified by `column`,
+    /// either its position or its title. It's empty if there's no column with this title
+    pub fn column_iter_mut<'c, C: Into<ColumnRef<'c>>>(&mut self, column: C) -> ColumnIterMut<'_> {
+        match column.into().position(self.titles.as_ref()) {
+            Some(column) => ColumnIterMut(self.rows.iter_mut(), column),
+            None => ColumnIterMut([].iter_mut(), 0),
+        }
+    }
+
+    /// Set the default text alignment of the cells in all the rows.
+    /// Cells with their own alignment are not affected
+    pub fn align(&mut self, align: Alignment) {
+        for row in self.rows.iter_mut() {
+            row.align(align);
+        }
+    }
+
+    /// Add a default style attribute to all the cells in all the rows.
+    /// Cells' own style attributes take precedence
+    pub fn style(&mut self, attr: Attr) {
+        for row in self.rows.iter_mut() {
+            row.style(attr);
+        }
+    }
+
+    /// Remove the default style attributes and alignment of all the rows.
+    /// Cells' own style is not modified
+    pub fn reset_style(&mut self) {
+        for row in self.rows.iter_mut() {
+            row.reset_style();
+        }
+    }
+}
+
+impl<'a> Index<usize> for TableSliceMut<'a> {
+    type Output = Row;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.rows[idx]
+    }
+}
+
+impl<'a> IndexMut<usize> for TableSliceMut<'a> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.rows[idx]
+    }
+}
+
+impl<'a> AsTableSlice for TableSlice<'a> {
+    fn as_slice(&self) -> TableSlice<'_> {
+        *self
+    }
+}
+
+impl<'a> AsRef<TableSlice<'a>> for TableSlice<'a> {
+    fn as_ref(&self) -> &TableSlice<'a> {
+        self
+    }
+}
+
+/// Trait implemented by types which can be sliced
+pub trait Slice<'a, E> {
+    /// Type output after slicing
+    type Output: 'a;
+    /// Get a slice from self
+    fn slice(&'a self, arg: E) -> Self::Output;
+}
+
+impl<'a, T, E> Slice<'a, E> for T
+where
+    T: AsTableSlice,
+    [Row]: Index<E, Output = [Row]>,
+{
+    type Output = TableSlice<'a>;
+    fn slice(&'a self, arg: E) -> Self::Output {
+        let mut sl = self.as_slice();
+        sl.rows = sl.rows.index(arg);
+        sl
+    }
+}
+
+/// Create a table filled with some values
 ///
 /// All the arguments used for elements must implement the `std::string::ToString` trait
 /// # Syntax
@@ -610,716 +3599,2640 @@ where
 /// ```
 ///
 /// For details about style specifier syntax, check doc for [`Cell::style_spec`](cell/struct.Cell.html#method.style_spec) method
+///
+/// An existing table can also be sliced by giving a range of rows after it
+///
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # fn main() {
+/// let tab = table!([1, 2], [3, 4], [5, 6]);
+/// let slice = table!(tab, 1..);
+/// assert_eq!(slice.len(), 2);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! table {
+    ($table:ident, $range:expr) => (
+        $crate::Slice::slice(&$table, $range)
+    );
     ($([$($content:tt)*]), *) => (
         $crate::Table::init(vec![$($crate::row![$($content)*]), *])
     );
 }
 
-/// Create a table with `table!` macro, print it to standard output, then return this table for future usage.
-///
-/// The syntax is the same that the one for the `table!` macro
-#[macro_export]
-macro_rules! ptable {
-    ($($content:tt)*) => (
-        {
-            let tab = $crate::table!($($content)*);
-            tab.printstd();
-            tab
+/// Create a table with `table!` macro, print it to standard output, then return this table for future usage.
+///
+/// The syntax is the same that the one for the `table!` macro.
+/// When slicing an existing table, like in `ptable!(table, 10..20)`, the slice is printed and returned
+#[macro_export]
+macro_rules! ptable {
+    ($($content:tt)*) => (
+        {
+            let tab = $crate::table!($($content)*);
+            tab.printstd();
+            tab
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::StringWriter;
+    use crate::{
+        color, format, row, Aggregate, AsTableSlice, Attr, CaptionPosition, Cell, ColumnRef,
+        ColumnWidth, Error, HtmlOptions, Row, SampleStrategy, Slice, Table, TableSlice,
+    };
+    use format::consts::{
+        FORMAT_BOX_CHARS, FORMAT_CLEAN, FORMAT_DEFAULT, FORMAT_DOUBLE_BORDER, FORMAT_MARKDOWN,
+        FORMAT_NO_COLSEP, FORMAT_NO_LINESEP,
+    };
+
+    #[test]
+    fn table() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        let out = "\
++-----+----+-----+
+| t1  | t2 | t3  |
++=====+====+=====+
+| a   | bc | def |
++-----+----+-----+
+| def | bc | a   |
++-----+----+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        table.unset_titles();
+        let out = "\
++-----+----+-----+
+| a   | bc | def |
++-----+----+-----+
+| def | bc | a   |
++-----+----+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn index() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        assert_eq!(table[1][1].get_content(), "bc");
+
+        table[1][1] = Cell::new("newval");
+        assert_eq!(table[1][1].get_content(), "newval");
+
+        let out = "\
++-----+--------+-----+
+| t1  | t2     | t3  |
++=====+========+=====+
+| a   | bc     | def |
++-----+--------+-----+
+| def | newval | a   |
++-----+--------+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn table_size() {
+        let mut table = Table::new();
+        assert!(table.is_empty());
+        assert!(table.as_slice().is_empty());
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.as_slice().len(), 0);
+        assert_eq!(table.get_column_num(), 0);
+        assert_eq!(table.as_slice().get_column_num(), 0);
+        table.add_empty_row();
+        assert!(!table.is_empty());
+        assert!(!table.as_slice().is_empty());
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.as_slice().len(), 1);
+        assert_eq!(table.get_column_num(), 0);
+        assert_eq!(table.as_slice().get_column_num(), 0);
+        table[0].add_cell(Cell::default());
+        assert_eq!(table.get_column_num(), 1);
+        assert_eq!(table.as_slice().get_column_num(), 1);
+    }
+
+    #[test]
+    fn get_row() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        assert!(table.get_row(12).is_none());
+        assert!(table.get_row(1).is_some());
+        assert_eq!(table.get_row(1).unwrap()[0].get_content(), "def");
+        assert!(table.get_mut_row(12).is_none());
+        assert!(table.get_mut_row(1).is_some());
+        table.get_mut_row(1).unwrap().add_cell(Cell::new("z"));
+        assert_eq!(table.get_row(1).unwrap()[3].get_content(), "z");
+    }
+
+    #[test]
+    fn add_empty_row() {
+        let mut table = Table::new();
+        assert_eq!(table.len(), 0);
+        table.add_empty_row();
+        assert_eq!(table.len(), 1);
+        assert_eq!(table[0].len(), 0);
+    }
+
+    #[test]
+    fn remove_row() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.remove_row(12);
+        assert_eq!(table.len(), 2);
+        table.remove_row(0);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table[0][0].get_content(), "def");
+    }
+
+    #[test]
+    fn insert_row() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.insert_row(
+            12,
+            Row::new(vec![Cell::new("1"), Cell::new("2"), Cell::new("3")]),
+        );
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[2][1].get_content(), "2");
+        table.insert_row(
+            1,
+            Row::new(vec![Cell::new("3"), Cell::new("4"), Cell::new("5")]),
+        );
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[1][1].get_content(), "4");
+        assert_eq!(table[2][1].get_content(), "bc");
+    }
+
+    #[test]
+    fn set_element() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        assert!(table.set_element("foo", 12, 12).is_err());
+        assert!(matches!(
+            table.set_element("foo", 1, 12),
+            Err(Error::OutOfBounds { index: 12, len: 2 })
+        ));
+        assert!(table.set_element("foo", 1, 1).is_ok());
+        assert_eq!(table[1][1].get_content(), "foo");
+    }
+
+    #[test]
+    fn no_linesep() {
+        let mut table = Table::new();
+        table.set_format(*FORMAT_NO_LINESEP);
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        assert_eq!(table[1][1].get_content(), "bc");
+
+        table[1][1] = Cell::new("newval");
+        assert_eq!(table[1][1].get_content(), "newval");
+
+        let out = "\
++-----+--------+-----+
+| t1  | t2     | t3  |
+| a   | bc     | def |
+| def | newval | a   |
++-----+--------+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn no_colsep() {
+        let mut table = Table::new();
+        table.set_format(*FORMAT_NO_COLSEP);
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        assert_eq!(table[1][1].get_content(), "bc");
+
+        table[1][1] = Cell::new("newval");
+        assert_eq!(table[1][1].get_content(), "newval");
+
+        let out = "\
+------------------
+ t1   t2      t3 \n\
+==================
+ a    bc      def \n\
+------------------
+ def  newval  a \n\
+------------------
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn clean() {
+        let mut table = Table::new();
+        table.set_format(*FORMAT_CLEAN);
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        assert_eq!(table[1][1].get_content(), "bc");
+
+        table[1][1] = Cell::new("newval");
+        assert_eq!(table[1][1].get_content(), "newval");
+
+        let out = "\
+\u{0020}t1   t2      t3 \n\
+\u{0020}a    bc      def \n\
+\u{0020}def  newval  a \n\
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(3, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn padding() {
+        let mut table = Table::new();
+        let mut format = *FORMAT_DEFAULT;
+        format.padding(2, 2);
+        table.set_format(format);
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        assert_eq!(table[1][1].get_content(), "bc");
+
+        table[1][1] = Cell::new("newval");
+        assert_eq!(table[1][1].get_content(), "newval");
+
+        let out = "\
++-------+----------+-------+
+|  t1   |  t2      |  t3   |
++=======+==========+=======+
+|  a    |  bc      |  def  |
++-------+----------+-------+
+|  def  |  newval  |  a    |
++-------+----------+-------+
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn indent() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        table.get_format().indent(8);
+        let out = "        +-----+----+-----+
+        | t1  | t2 | t3  |
+        +=====+====+=====+
+        | a   | bc | def |
+        +-----+----+-----+
+        | def | bc | a   |
+        +-----+----+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn slices() {
+        let mut table = Table::new();
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("0"),
+            Cell::new("0"),
+            Cell::new("0"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("1"),
+            Cell::new("1"),
+            Cell::new("1"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("2"),
+            Cell::new("2"),
+            Cell::new("2"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("3"),
+            Cell::new("3"),
+            Cell::new("3"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("4"),
+            Cell::new("4"),
+            Cell::new("4"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("5"),
+            Cell::new("5"),
+            Cell::new("5"),
+        ]));
+        let out = "\
++----+----+----+
+| t1 | t2 | t3 |
++====+====+====+
+| 1  | 1  | 1  |
++----+----+----+
+| 2  | 2  | 2  |
++----+----+----+
+| 3  | 3  | 3  |
++----+----+----+
+";
+        let slice = table.slice(..);
+        let slice = slice.slice(1..);
+        let slice = slice.slice(..3);
+        assert_eq!(out, slice.to_string().replace("\r\n", "\n"));
+        assert_eq!(9, slice.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(out, table.slice(1..4).to_string().replace("\r\n", "\n"));
+        assert_eq!(
+            9,
+            table.slice(1..4).print(&mut StringWriter::new()).unwrap()
+        );
+    }
+
+    #[test]
+    fn slice_macros() {
+        let table = table!([0], [1], [2], [3], [4]);
+        let slice = table!(table, 1..3);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice[0][0].get_content(), "1");
+        let slice = ptable!(table, ..);
+        assert_eq!(slice.len(), 5);
+        let slice = ptable!(table, 3..);
+        assert_eq!(slice[0][0].get_content(), "3");
+    }
+
+    #[test]
+    fn test_unicode_separators() {
+        let mut table = Table::new();
+        table.set_format(*FORMAT_BOX_CHARS);
+        table.add_row(Row::new(vec![
+            Cell::new("1"),
+            Cell::new("1"),
+            Cell::new("1"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("2"),
+            Cell::new("2"),
+            Cell::new("2"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        let out = "\
+┌────┬────┬────┐
+│ t1 │ t2 │ t3 │
+├────┼────┼────┤
+│ 1  │ 1  │ 1  │
+├────┼────┼────┤
+│ 2  │ 2  │ 2  │
+└────┴────┴────┘
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn test_readme_format() {
+        // The below is lifted from the README
+
+        let mut table = Table::new();
+        let format = format::FormatBuilder::new()
+            .column_separator('|')
+            .borders('|')
+            .separators(
+                &[format::LinePosition::Top, format::LinePosition::Bottom],
+                format::LineSeparator::new('-', '+', '+', '+'),
+            )
+            .padding(1, 1)
+            .build();
+        table.set_format(format);
+
+        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
+        table.add_row(Row::new(vec![Cell::new("Value 1"), Cell::new("Value 2")]));
+        table.add_row(Row::new(vec![
+            Cell::new("Value three"),
+            Cell::new("Value four"),
+        ]));
+
+        let out = "\
++-------------+------------+
+| Title 1     | Title 2    |
+| Value 1     | Value 2    |
+| Value three | Value four |
++-------------+------------+
+";
+
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn test_readme_format_with_title() {
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+
+        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
+        table.add_row(Row::new(vec![Cell::new("Value 1"), Cell::new("Value 2")]));
+        table.add_row(Row::new(vec![
+            Cell::new("Value three"),
+            Cell::new("Value four"),
+        ]));
+
+        let out = "\
++-------------+------------+
+| Title 1     | Title 2    |
++-------------+------------+
+| Value 1     | Value 2    |
+| Value three | Value four |
++-------------+------------+
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(6, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn test_empty_table_with_title() {
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+
+        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
+
+        let out = "\
++---------+---------+
+| Title 1 | Title 2 |
++---------+---------+
++---------+---------+
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(4, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn test_horizontal_span() {
+        let mut table = Table::new();
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2").with_hspan(2),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def").style_spec("H02c"),
+            Cell::new("a"),
+        ]));
+        let out = "\
++----+----+-----+
+| t1 | t2       |
++====+====+=====+
+| a  | bc | def |
++----+----+-----+
+|   def   | a   |
++----+----+-----+
+";
+        println!("{}", out);
+        println!("____");
+        println!("{}", table.to_string().replace("\r\n", "\n"));
+        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn separator_after() {
+        let mut table = table!(["a", "b"], ["c", "d"], ["e", "f"], ["g", "h"]);
+        table[0].set_separator_after(false);
+        let out = "\
++---+---+
+| a | b |
+| c | d |
++---+---+
+| e | f |
++---+---+
+| g | h |
++---+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(8, table.print(&mut StringWriter::new()).unwrap());
+
+        table.set_format(*FORMAT_NO_LINESEP);
+        table[0].unset_separator_after();
+        table[1].set_separator_after(true);
+        // No separator after the last row
+        table[3].set_separator_after(true);
+        let out = "\
++---+---+
+| a | b |
+| c | d |
++---+---+
+| e | f |
+| g | h |
++---+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn add_separator() {
+        let mut table = table!(["a", "1"], ["b", "2"]);
+        table.set_format(*FORMAT_NO_LINESEP);
+        table.insert_separator(0);
+        table.insert_separator(2).set_format(*FORMAT_BOX_CHARS);
+        table.add_separator();
+        table.add_row(row!["c", "3"]);
+        assert_eq!(table.len(), 6);
+        assert!(table[0].is_separator());
+        let out = "\
++---+---+
++---+---+
+| a | 1 |
+├───┼───┤
+| b | 2 |
++---+---+
+| c | 3 |
++---+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(8, table.print(&mut StringWriter::new()).unwrap());
+
+        // No other line separator is printed around separator rows, which are not numbered
+        table.set_format(*FORMAT_DEFAULT);
+        table.set_row_numbers(true);
+        let out = "\
++---+---+---+
++---+---+---+
+| 1 | a | 1 |
+├───┼───┼───┤
+| 2 | b | 2 |
++---+---+---+
+| 3 | c | 3 |
++---+---+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+
+        // Separator rows are not exported
+        let mut markdown = StringWriter::new();
+        table.to_markdown(&mut markdown).unwrap();
+        assert_eq!(markdown.as_string().lines().count(), 5);
+    }
+
+    #[test]
+    fn footer() {
+        let mut table = table!(["a", "bc"], ["def", "1"]);
+        table.set_titles(row!["t1", "t2"]);
+        table.set_footer(row!["total", "3"]);
+        let out = "\
++-------+----+
+| t1    | t2 |
++=======+====+
+| a     | bc |
++-------+----+
+| def   | 1  |
++=======+====+
+| total | 3  |
++-------+----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(9, table.print(&mut StringWriter::new()).unwrap());
+
+        table.get_format().separator(
+            format::LinePosition::Footer,
+            format::LineSeparator::new('~', '+', '+', '+'),
+        );
+        table.unset_titles();
+        let out = "\
++-------+----+
+| a     | bc |
++-------+----+
+| def   | 1  |
++~~~~~~~+~~~~+
+| total | 3  |
++-------+----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.unset_footer();
+        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn max_render_size() {
+        let mut table = table!(["a", "bc"], ["def", "1"]);
+        let out = table.to_string();
+        assert!(table.as_slice().render_size_hint() >= out.len());
+        assert_eq!(table.try_to_string(out.len()).unwrap(), out);
+        assert!(matches!(
+            table.try_to_string(out.len() - 1),
+            Err(Error::TooLarge { max_size }) if max_size == out.len() - 1
+        ));
+
+        table.set_max_render_size(Some(30));
+        let truncated = "\
++-----+----+
+| a   | bc |
+… output truncated at 30 bytes
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), truncated);
+        assert!(table
+            .slice(..1)
+            .to_string()
+            .replace("\r\n", "\n")
+            .ends_with("\n… output truncated at 30 bytes\n"));
+        table.set_max_render_size(Some(5));
+        assert_eq!(
+            table.to_string().replace("\r\n", "\n"),
+            "… output truncated at 5 bytes\n"
+        );
+        table.set_max_render_size(None);
+        assert_eq!(table.to_string(), out);
+    }
+
+    /// An output failing with an error of the given kind, like a closed pipe
+    struct FailingWriter(std::io::ErrorKind);
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(self.0, "write failed"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn ignore_broken_pipe() {
+        // The error handling of `try_print_tty()`, with outputs other than stdout
+        let table = table!(["a", "b"]);
+        let res = table.print(&mut FailingWriter(std::io::ErrorKind::BrokenPipe));
+        assert!(crate::utils::ignore_broken_pipe(res).is_ok());
+        let res = table.print(&mut FailingWriter(std::io::ErrorKind::PermissionDenied));
+        assert_eq!(
+            crate::utils::ignore_broken_pipe(res).unwrap_err().kind(),
+            std::io::ErrorKind::PermissionDenied
+        );
+        assert!(crate::utils::ignore_broken_pipe(table.print(&mut StringWriter::new())).is_ok());
+    }
+
+    #[test]
+    fn groups() {
+        let mut table = Table::new();
+        table.set_titles(row!["t1", "t2"]);
+        table.add_group("Section A", vec![row!["a", "b"], row!["c", "d"]]);
+        table.add_group("Section Bravo", vec![row!["e", "f"]]);
+        assert!(table[0].is_group_header());
+        assert!(!table[1].is_group_header());
+        let out = "\
++----+----------+
+| t1 | t2       |
++====+==========+
+| Section A     |
++----+----------+
+| a  | b        |
++----+----------+
+| c  | d        |
++====+==========+
+| Section Bravo |
++----+----------+
+| e  | f        |
++----+----------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(13, table.print(&mut StringWriter::new()).unwrap());
+
+        let mut writer = StringWriter::new();
+        table.slice(3..).print_html(&mut writer).unwrap();
+        assert_eq!(
+            writer.as_string(),
+            "<table><th><td style=\"text-align: left;\">t1</td><td style=\"text-align: left;\">t2</td></th>\
+             <tr><td colspan=\"2\" style=\"text-align: left;\">Section Bravo</td></tr>\
+             <tr><td style=\"text-align: left;\">e</td><td style=\"text-align: left;\">f</td></tr></table>"
+        );
+    }
+
+    #[test]
+    fn table_html() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc"),
+            Cell::new("def"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("def"),
+            Cell::new("bc"),
+            Cell::new("a"),
+        ]));
+        table.set_titles(Row::new(vec![
+            Cell::new("t1"),
+            Cell::new("t2"),
+            Cell::new("t3"),
+        ]));
+        let out = "\
+<table>\
+<th><td style=\"text-align: left;\">t1</td><td style=\"text-align: left;\">t2</td><td style=\"text-align: left;\">t3</td></th>\
+<tr><td style=\"text-align: left;\">a</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">def</td></tr>\
+<tr><td style=\"text-align: left;\">def</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">a</td></tr>\
+</table>";
+        let mut writer = StringWriter::new();
+        assert!(table.print_html(&mut writer).is_ok());
+        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
+        table.unset_titles();
+        let out = "\
+<table>\
+<tr><td style=\"text-align: left;\">a</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">def</td></tr>\
+<tr><td style=\"text-align: left;\">def</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">a</td></tr>\
+</table>";
+        let mut writer = StringWriter::new();
+        assert!(table.print_html(&mut writer).is_ok());
+        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
+        assert_eq!(table.to_html(), out);
+    }
+
+    #[test]
+    fn table_html_colors() {
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("bold").style_spec("b"),
+            Cell::new("italic").style_spec("i"),
+            Cell::new("underline").style_spec("u"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("left").style_spec("l"),
+            Cell::new("center").style_spec("c"),
+            Cell::new("right").style_spec("r"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("red").style_spec("Fr"),
+            Cell::new("black").style_spec("Fd"),
+            Cell::new("yellow").style_spec("Fy"),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("bright magenta on cyan").style_spec("FMBc"),
+            Cell::new("white on bright green").style_spec("FwBG"),
+            Cell::new("default on blue").style_spec("Bb"),
+        ]));
+        table.set_titles(Row::new(
+            vec![Cell::new("span horizontal").style_spec("H3")],
+        ));
+        let out = "\
+<table>\
+<th><td colspan=\"3\" style=\"text-align: left;\">span horizontal</td></th>\
+<tr><td style=\"font-weight: bold;text-align: left;\">bold</td><td style=\"font-style: italic;text-align: left;\">italic</td><td style=\"text-decoration: underline;text-align: left;\">underline</td></tr>\
+<tr><td style=\"text-align: left;\">left</td><td style=\"text-align: center;\">center</td><td style=\"text-align: right;\">right</td></tr>\
+<tr><td style=\"color: #aa0000;text-align: left;\">red</td><td style=\"color: #000000;text-align: left;\">black</td><td style=\"color: #aa5500;text-align: left;\">yellow</td></tr>\
+<tr><td style=\"color: #ff55ff;background-color: #00aaaa;text-align: left;\">bright magenta on cyan</td><td style=\"color: #aaaaaa;background-color: #55ff55;text-align: left;\">white on bright green</td><td style=\"background-color: #0000aa;text-align: left;\">default on blue</td></tr>\
+</table>";
+        let mut writer = StringWriter::new();
+        assert!(table.print_html(&mut writer).is_ok());
+        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn table_html_options() {
+        let mut table = table!([bFr->"a\"b", H2->"span"], ["c", "d", "e"]);
+        table.set_footer(row!["f", "g", "h"]);
+        let options = HtmlOptions::new()
+            .table_class("report")
+            .row_class("line")
+            .cell_class("\"cell\"");
+        let out = "\
+<table class=\"report\">\
+<tr class=\"line\"><td class=\"&quot;cell&quot;\" style=\"font-weight: bold;color: #aa0000;text-align: left;\">a&quot;b</td><td class=\"&quot;cell&quot;\" colspan=\"2\" style=\"text-align: left;\">span</td></tr>\
+<tr class=\"line\"><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">c</td><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">d</td><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">e</td></tr>\
+<tfoot><tr class=\"line\"><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">f</td><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">g</td><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">h</td></tr></tfoot>\
+</table>";
+        let mut writer = StringWriter::new();
+        table
+            .print_html_with_options(&mut writer, &options)
+            .unwrap();
+        assert_eq!(writer.as_string(), out);
+
+        let options = options.inline_styles(false);
+        let mut writer = StringWriter::new();
+        table
+            .print_html_with_options(&mut writer, &options)
+            .unwrap();
+        assert!(writer.as_string().starts_with(
+            "<table class=\"report\"><tr class=\"line\"><td class=\"&quot;cell&quot;\" style=\"text-align: left;\">a&quot;b</td>"
+        ));
+
+        // The default options don't change the output
+        let mut writer = StringWriter::new();
+        table
+            .print_html_with_options(&mut writer, &HtmlOptions::default())
+            .unwrap();
+        assert_eq!(writer.as_string(), table.to_html());
+    }
+
+    #[test]
+    fn test_panic() {
+        let mut table = Table::new();
+
+        table.add_row(Row::new(vec![Cell::new("\u{1b}[\u{1b}\u{0}\u{0}")]));
+
+        let out = "+--+
+| \u{1b}[\u{1b}\u{0}\u{0} |
++--+
+";
+
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(3, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn hide_empty_columns() {
+        let mut table = table!(["a", "", "-", ""], ["b", "", "", "x"]);
+        table.set_titles(row!["name", "opt1", "opt2", "opt3"]);
+        table.set_column_hide_if_empty(1, true);
+        table.set_column_hide_if_empty(2, true);
+        table.set_column_hide_if_empty(3, true);
+        let out = "\
++------+------+------+
+| name | opt2 | opt3 |
++======+======+======+
+| a    | -    |      |
++------+------+------+
+| b    |      | x    |
++------+------+------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.set_empty_placeholder("-");
+        let out = "\
++------+------+
+| name | opt3 |
++======+======+
+| a    |      |
++------+------+
+| b    | x    |
++------+------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        table.set_column_hide_if_empty(2, false);
+        assert_eq!(table.to_string().lines().next().unwrap().len(), 22);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(!html.as_string().contains("opt1"));
+    }
+
+    #[test]
+    fn hide_all_empty_columns() {
+        let mut table = table!(["a", "", "-", ""], ["b", "", "", "x"]);
+        table.set_titles(row!["name", "opt1", "opt2", "opt3"]);
+        table.set_hide_empty_columns(true);
+        table.set_empty_placeholder("-");
+        let out = "\
++------+------+
+| name | opt3 |
++======+======+
+| a    |      |
++------+------+
+| b    | x    |
++------+------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.clear();
+        assert!(table.to_string().contains("opt1"));
+        table.set_hide_empty_columns(false);
+        table.add_row(row!["c", "", "", ""]);
+        assert!(table.to_string().contains("opt1"));
+    }
+
+    #[test]
+    fn project() {
+        let mut table = table!(["a", H2->"bc", "d"], ["e", "f", "g", "h"]);
+        table.set_titles(row!["t1", "t2", "t3", "t4"]);
+        table.set_column_style("t3", "Fr");
+        table.set_aggregate_footer(&[Aggregate::Count(0)]);
+        let projected = table.project(&["t3", "t1", "missing", "t3"]);
+        let out = "\
++----+----+----+
+| t3 | t1 | t3 |
++====+====+====+
+| bc | a  | bc |
++----+----+----+
+| g  | e  | g  |
++====+====+====+
+|    |  0 |    |
++----+----+----+
+";
+        assert_eq!(projected.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(projected.settings.columns.len(), 3);
+        assert!(!projected.settings.columns[0].style.is_empty());
+        assert!(projected.settings.columns[1].style.is_empty());
+        assert!(projected.footer.is_some() && projected.settings.aggregates.is_empty());
+        let projected = table.slice(1..).project(&[3, 2]);
+        assert_eq!(projected.len(), 1);
+        assert_eq!(projected[0][0].get_content(), "h");
+        assert_eq!(table.project::<usize>(&[]).get_column_num(), 0);
+    }
+
+    #[test]
+    fn reorder_columns() {
+        let mut table = table!(["a", H2->"bc", "d"], ["e", "f", "g"]);
+        table.set_titles(row!["t1", "t2", "t3", "t4"]);
+        table.set_column_alignment(0, format::Alignment::RIGHT);
+        table.reorder_columns(&[3, 1, 2, 7, 3]);
+        let out = "\
++----+----+----+----+
+| t4 | t2 | t3 | t1 |
++====+====+====+====+
+| d  | bc      |  a |
++----+----+----+----+
+|    | f  | g  |  e |
++----+----+----+----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.reorder_columns(&[]);
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn title_groups() {
+        let mut table = table!(["web-1", "2", "4", "x"]);
+        table.set_format(*FORMAT_BOX_CHARS);
+        table.set_titles(row!["host", "cpu", "mem", "notes"]);
+        table.add_title_group(row!["", H2c->"usage", ""]);
+        table.set_column_hidden(3, true);
+        table.set_heading("Servers");
+        let out = "\
+┌───────────────────┐
+│      Servers      │
+├───────┼───────────┤
+│       │   usage   │
+├───────┼─────┼─────┤
+│ host  │ cpu │ mem │
+├───────┼─────┼─────┤
+│ web-1 │ 2   │ 4   │
+└───────┴─────┴─────┘
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.unset_heading();
+        table.unset_titles();
+        table.set_format(*FORMAT_DEFAULT);
+        let out = "\
++-------+-------+
+|       | usage |
++=======+===+===+
+| web-1 | 2 | 4 |
++-------+---+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.clear_title_groups();
+        assert_eq!(table.to_string().lines().count(), 3);
+    }
+
+    #[test]
+    fn caption() {
+        let mut table = table!(["apple", "3"], ["pear", "5"]);
+        table.set_caption("Fruits\n<stock>", CaptionPosition::Above);
+        table.get_format().indent(2);
+        let out = "\
+\u{0020}    Fruits
+\u{0020}    <stock>
+\u{0020} +-------+---+
+\u{0020} | apple | 3 |
+\u{0020} +-------+---+
+\u{0020} | pear  | 5 |
+\u{0020} +-------+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(html
+            .as_string()
+            .starts_with("<table><caption>Fruits\n&lt;stock&gt;</caption>"));
+        table.set_caption("Fruits", CaptionPosition::Below);
+        let mut json = StringWriter::new();
+        table.to_json(&mut json, false).unwrap();
+        assert_eq!(
+            json.as_string(),
+            r#"{"rows":[["apple","3"],["pear","5"]],"caption":"Fruits","caption_position":"below"}"#
+        );
+        assert!(table
+            .to_string()
+            .replace("\r\n", "\n")
+            .ends_with("+\n     Fruits\n"));
+        table.unset_caption();
+        assert_eq!(table.to_string().lines().count(), 5);
+    }
+
+    #[test]
+    fn clear_truncate() {
+        let mut table = table!(["a"], ["b"], ["c"]);
+        table.set_titles(row!["t"]);
+        table.truncate(5);
+        assert_eq!(table.len(), 3);
+        table.truncate(1);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table[0][0].get_content(), "a");
+        table.clear();
+        assert!(table.is_empty());
+        let out = "\
++---+
+| t |
++===+
++---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn retain_dedup() {
+        let mut table = table!(["a", "1"], ["a", "2"], ["b", "3"], ["a", "4"], ["c"], ["c"]);
+        table.set_titles(row!["key", "value"]);
+        table.dedup_by_column("key");
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[1][1].get_content(), "3");
+        table.dedup_by_column("value");
+        assert_eq!(table.len(), 4);
+        table.dedup_by_column(1);
+        assert_eq!(table.len(), 4);
+        table.retain(|r| r[0].get_content() != "a");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[1][0].get_content(), "c");
+    }
+
+    #[test]
+    fn aggregate_footer() {
+        let mut table = table!(["a", "10", "x"], ["b", "n/a", "-2.5"], ["c", "4"]);
+        table.set_aggregate_footer(&[
+            Aggregate::Count(0),
+            Aggregate::Min(1),
+            Aggregate::Max(2),
+            Aggregate::Sum(3),
+        ]);
+        let out = "\
++---+-----+------+---+
+| a | 10  | x    |   |
++---+-----+------+---+
+| b | n/a | -2.5 |   |
++---+-----+------+---+
+| c | 4   |      |   |
++===+=====+======+===+
+| 0 |   4 | -2.5 | 0 |
++---+-----+------+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        let mut json = StringWriter::new();
+        table.to_json(&mut json, false).unwrap();
+        assert!(json
+            .as_string()
+            .ends_with(r#""footer":["0","4","-2.5","0"]}"#));
+        table[1][1].set_content("");
+        table.set_aggregate_footer(&[Aggregate::Avg(1)]);
+        assert!(table.to_string().contains("| 7.00 |"));
+        table.unset_aggregate_footer();
+        assert_eq!(table.to_string().lines().count(), 7);
+    }
+
+    #[test]
+    fn slice_columns() {
+        let mut table = table!(["a", "1", "x", "10"], ["b", "2", "y", "20"]);
+        table.set_titles(row!["name", "n", "c", "m"]);
+        table.set_aggregate_footer(&[Aggregate::Sum(1)]);
+        let slice = table.slice_columns(..=1);
+        let out = "\
++------+---+
+| name | n |
++======+===+
+| a    | 1 |
++------+---+
+| b    | 2 |
++======+===+
+|      | 3 |
++------+---+
+";
+        assert_eq!(slice.to_string().replace("\r\n", "\n"), out);
+        assert!(!table.slice_columns(2..2).to_string().contains("name"));
+        assert_eq!(table.slice_columns(3..).column_iter(0).count(), 2);
+    }
+
+    #[test]
+    fn slice_with_format() {
+        let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+        table.set_titles(row!["name", "n"]);
+        let out = " name  n \n b     2 \n c     3 \n";
+        let slice = table.slice(1..).with_format(&FORMAT_CLEAN);
+        assert_eq!(slice.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(*table.get_format(), *FORMAT_DEFAULT);
+        let whole = table.with_format(&FORMAT_NO_LINESEP).to_string();
+        assert!(whole
+            .replace("\r\n", "\n")
+            .starts_with("+------+---+\n| name | n |\n"));
+    }
+
+    #[test]
+    fn table_eq() {
+        let table = table!(["a", "1"], ["b", "2"]);
+        let mut other = table.clone();
+        assert_eq!(table, other);
+        other[1][1] = Cell::new("2").with_style(Attr::Bold);
+        assert_ne!(table, other);
+        other[1][1] = Cell::new_align("2", format::Alignment::RIGHT);
+        assert_ne!(table, other);
+        other[1][1] = Cell::new("2");
+        assert_eq!(table, other);
+        other.set_format(*FORMAT_BOX_CHARS);
+        assert_ne!(table, other);
+    }
+
+    #[test]
+    fn slice_mut() {
+        let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+        table.set_titles(row!["name", "n"]);
+        let mut slice = table.slice_mut(1..);
+        assert_eq!(slice.len(), 2);
+        slice.style(Attr::Bold);
+        slice.align(format::Alignment::RIGHT);
+        for cell in slice.column_iter_mut("n") {
+            *cell = Cell::new("0");
         }
-    );
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::utils::StringWriter;
-    use crate::{format, AsTableSlice, Cell, Row, Slice, Table};
-    use format::consts::{
-        FORMAT_BOX_CHARS, FORMAT_CLEAN, FORMAT_DEFAULT, FORMAT_NO_COLSEP, FORMAT_NO_LINESEP,
-    };
+        assert_eq!(slice.column_iter_mut("missing").count(), 0);
+        slice[0].reset_style();
+        assert!(!table[0].has_style(Attr::Bold));
+        assert!(!table[1].has_style(Attr::Bold));
+        assert!(table[2].has_style(Attr::Bold));
+        assert_eq!(table[2][0].get_alignment(), format::Alignment::LEFT);
+        assert_eq!(table[0][1].get_content(), "1");
+        assert_eq!(table[1][1].get_content(), "0");
+        assert!(table.slice_mut(3..).is_empty());
+    }
 
     #[test]
-    fn table() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
+    fn hidden_columns() {
+        let mut table = table!(["a", "details", "1"], ["b", "more details", "2"]);
+        table.set_titles(row!["name", "info", "n"]);
+        table.set_column_hidden("info", true);
         let out = "\
-+-----+----+-----+
-| t1  | t2 | t3  |
-+=====+====+=====+
-| a   | bc | def |
-+-----+----+-----+
-| def | bc | a   |
-+-----+----+-----+
++------+---+
+| name | n |
++======+===+
+| a    | 1 |
++------+---+
+| b    | 2 |
++------+---+
 ";
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
-        table.unset_titles();
+        assert_eq!(table.get_column_num(), 3);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(!html.as_string().contains("details"));
+        let mut json = StringWriter::new();
+        table.to_json(&mut json, false).unwrap();
+        assert_eq!(
+            json.as_string(),
+            r#"{"titles":["name","n"],"rows":[["a","1"],["b","2"]]}"#
+        );
+        table.set_column_hidden(1, false);
+        assert!(table.to_string().contains("more details"));
+    }
+
+    #[test]
+    fn hidden_rows() {
+        let mut table = table!(["a", "b"], ["a very long cell", "c"], ["d", "e"]);
+        table[1].set_hidden(true);
         let out = "\
-+-----+----+-----+
-| a   | bc | def |
-+-----+----+-----+
-| def | bc | a   |
-+-----+----+-----+
++---+---+
+| a | b |
++---+---+
+| d | e |
++---+---+
 ";
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
         assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.len(), 3);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(!html.as_string().contains("long"));
+        table[1].set_hidden(false);
+        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
     }
 
     #[test]
-    fn index() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        assert_eq!(table[1][1].get_content(), "bc");
+    fn sample() {
+        let table: Table = (0..10).map(|i| vec![i]).collect();
+        let sample = table.sample(2, SampleStrategy::Head);
+        let out = "\
++---------------+
+| 0             |
++---------------+
+| 1             |
++---------------+
+| … 8 more rows |
++---------------+
+";
+        assert_eq!(sample.to_string().replace("\r\n", "\n"), out);
+        assert!(sample[2].is_full_width());
+        let sample = table.sample(3, SampleStrategy::HeadTail);
+        assert_eq!(sample[1][0].get_content(), "1");
+        assert_eq!(sample[2][0].get_content(), "… 7 more rows");
+        assert_eq!(sample[3][0].get_content(), "9");
+        let sample = table.sample(4, SampleStrategy::Random(42));
+        assert_eq!(sample, table.sample(4, SampleStrategy::Random(42)));
+        assert_eq!(sample.len(), 5);
+        let values = sample
+            .column_iter(0)
+            .take(4)
+            .map(|c| c.get_content().parse::<usize>().unwrap())
+            .collect::<Vec<_>>();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            table.sample(9, SampleStrategy::Head)[9][0].get_content(),
+            "… 1 more row"
+        );
+        assert_eq!(table.sample(10, SampleStrategy::HeadTail), table);
+    }
 
-        table[1][1] = Cell::new("newval");
-        assert_eq!(table[1][1].get_content(), "newval");
+    #[test]
+    fn align_widths() {
+        let mut t1 = table!(["a", "long content"]);
+        let mut t2 = table!(["longer content", "b"], ["c"]);
+        t2.set_titles(row!["t"]);
+        Table::align_widths(&mut [&mut t1, &mut t2]);
+        let out = "\
++----------------+--------------+
+| a              | long content |
++----------------+--------------+
+";
+        assert_eq!(t1.to_string().replace("\r\n", "\n"), out);
+        let out = "\
++----------------+--------------+
+| t              |              |
++================+==============+
+| longer content | b            |
++----------------+--------------+
+| c              |              |
++----------------+--------------+
+";
+        assert_eq!(t2.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn sort_by_column() {
+        let mut table = table!(["b", "1"], ["a"], ["c", "3"], ["a", "2"]);
+        table.sort_by_column_str(0, true);
+        let col = |t: &Table| {
+            t.column_iter(0)
+                .map(|c| c.get_content())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(col(&table), vec!["a", "a", "b", "c"]);
+        assert!(table[0].get_cell(1).is_none());
+        table.sort_by_column_str(1, false);
+        assert_eq!(col(&table), vec!["c", "a", "b", "a"]);
+        assert!(table[3].get_cell(1).is_none());
+    }
+
+    #[test]
+    fn filter_view() {
+        let mut table = table!(
+            ["apple", 3],
+            ["pear", 0],
+            ["a very long fruit name", 0],
+            ["plum", 8]
+        );
+        table.set_titles(row!["fruit", "stock"]);
+        let view = table.filter_view(|row| row[1].get_content() != "0");
+        assert_eq!(view.len(), 2);
+        assert_eq!(
+            view.row_iter()
+                .map(|r| r[0].get_content())
+                .collect::<Vec<_>>(),
+            vec!["apple", "plum"]
+        );
+        let out = "\
++-------+-------+
+| fruit | stock |
++=======+=======+
+| apple | 3     |
++-------+-------+
+| plum  | 8     |
++-------+-------+
+";
+        assert_eq!(view.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(7, view.print(&mut StringWriter::new()).unwrap());
+        let slice = table.slice(1..);
+        assert!(slice
+            .filter_view(|row| row[0].get_content() == "apple")
+            .is_empty());
+    }
 
+    #[test]
+    fn heading() {
+        let mut table = table!(["apple", "3"], ["pear", "2"]);
+        table.set_titles(row!["fruit", "stock"]);
+        table.set_heading("Inventory of the shop");
         let out = "\
-+-----+--------+-----+
-| t1  | t2     | t3  |
-+=====+========+=====+
-| a   | bc     | def |
-+-----+--------+-----+
-| def | newval | a   |
-+-----+--------+-----+
++-----------------------+
+| Inventory of the shop |
++-------+---------------+
+| fruit | stock         |
++=======+===============+
+| apple | 3             |
++-------+---------------+
+| pear  | 2             |
++-------+---------------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(9, table.print(&mut StringWriter::new()).unwrap());
+        table.set_heading("Fruits");
+        table.get_format().separator(
+            format::LinePosition::Heading,
+            format::LineSeparator::new('~', '~', '+', '+'),
+        );
+        let out = "\
++---------------+
+|    Fruits     |
++~~~~~~~~~~~~~~~+
+| fruit | stock |
++=======+=======+
+| apple | 3     |
++-------+-------+
+| pear  | 2     |
++-------+-------+
 ";
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(html.as_string().starts_with(
+            "<table><tr><td colspan=\"2\" style=\"text-align: center;\">Fruits</td></tr>"
+        ));
+        table.unset_heading();
         assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
     }
 
     #[test]
-    fn table_size() {
-        let mut table = Table::new();
-        assert!(table.is_empty());
-        assert!(table.as_slice().is_empty());
-        assert_eq!(table.len(), 0);
-        assert_eq!(table.as_slice().len(), 0);
-        assert_eq!(table.get_column_num(), 0);
-        assert_eq!(table.as_slice().get_column_num(), 0);
-        table.add_empty_row();
-        assert!(!table.is_empty());
-        assert!(!table.as_slice().is_empty());
-        assert_eq!(table.len(), 1);
-        assert_eq!(table.as_slice().len(), 1);
-        assert_eq!(table.get_column_num(), 0);
-        assert_eq!(table.as_slice().get_column_num(), 0);
-        table[0].add_cell(Cell::default());
-        assert_eq!(table.get_column_num(), 1);
-        assert_eq!(table.as_slice().get_column_num(), 1);
+    fn into_iter() {
+        let table = table!(["a", "b"], ["c", "d"]);
+        let rows: Vec<Row> = table.into_iter().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1][0].get_content(), "c");
     }
 
     #[test]
-    fn get_row() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        assert!(table.get_row(12).is_none());
-        assert!(table.get_row(1).is_some());
-        assert_eq!(table.get_row(1).unwrap()[0].get_content(), "def");
-        assert!(table.get_mut_row(12).is_none());
-        assert!(table.get_mut_row(1).is_some());
-        table.get_mut_row(1).unwrap().add_cell(Cell::new("z"));
-        assert_eq!(table.get_row(1).unwrap()[3].get_content(), "z");
+    fn rows_with_style() {
+        let mut table = table!(["a"], [Fr->"b"], ["c"]);
+        table[2].style(Attr::ForegroundColor(color::RED));
+        let red = table
+            .rows_with_style(Attr::ForegroundColor(color::RED))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(red, vec![1, 2]);
+        assert_eq!(table.rows_with_style(Attr::Bold).count(), 0);
     }
 
     #[test]
-    fn add_empty_row() {
-        let mut table = Table::new();
-        assert_eq!(table.len(), 0);
-        table.add_empty_row();
-        assert_eq!(table.len(), 1);
-        assert_eq!(table[0].len(), 0);
+    fn cell_by_name() {
+        let mut table = table!(["web-1", "10.0.0.1"], ["web-2", "10.0.0.2"]);
+        assert_eq!(table.column_index("ip"), None);
+        table.set_titles(row!["hostname", "ip"]);
+        assert_eq!(table.column_index("ip"), Some(1));
+        let row = &table[1];
+        assert_eq!(
+            row.get_cell_by_name(&table, "ip").unwrap().get_content(),
+            "10.0.0.2"
+        );
+        assert!(row.get_cell_by_name(&table, "cpu").is_none());
     }
 
     #[test]
-    fn remove_row() {
+    fn title_links() {
+        use crate::Terminal;
+        use std::collections::HashMap;
+        use term::terminfo::{TermInfo, TerminfoTerminal};
+
         let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.remove_row(12);
-        assert_eq!(table.len(), 2);
-        table.remove_row(0);
-        assert_eq!(table.len(), 1);
-        assert_eq!(table[0][0].get_content(), "def");
+        table.set_format(*FORMAT_CLEAN);
+        let cell = || Cell::new("a").with_link("https://example.com");
+        table.set_titles(Row::new(vec![cell()]));
+        table.add_row(Row::new(vec![cell()]));
+        let info = TermInfo {
+            names: Vec::new(),
+            bools: HashMap::new(),
+            numbers: HashMap::new(),
+            strings: HashMap::new(),
+        };
+        let mut term = TerminfoTerminal::new_with_terminfo(StringWriter::new(), info);
+        table.print_term(&mut term).unwrap();
+        let out = " \x1b]8;;https://example.com\x1b\\a\x1b]8;;\x1b\\ \n a \n";
+        assert_eq!(term.get_ref().as_string().replace("\r\n", "\n"), out);
+        // Links are not printed without a terminal
+        assert_eq!(table.to_string().replace("\r\n", "\n"), " a \n a \n");
     }
 
     #[test]
-    fn insert_row() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.insert_row(
-            12,
-            Row::new(vec![Cell::new("1"), Cell::new("2"), Cell::new("3")]),
-        );
-        assert_eq!(table.len(), 3);
-        assert_eq!(table[2][1].get_content(), "2");
-        table.insert_row(
-            1,
-            Row::new(vec![Cell::new("3"), Cell::new("4"), Cell::new("5")]),
-        );
-        assert_eq!(table.len(), 4);
-        assert_eq!(table[1][1].get_content(), "4");
-        assert_eq!(table[2][1].get_content(), "bc");
+    fn border_style() {
+        use crate::Terminal;
+        use std::collections::HashMap;
+        use term::terminfo::{TermInfo, TerminfoTerminal};
+
+        let mut table = table!(["a", "b"]);
+        let mut format = *FORMAT_BOX_CHARS;
+        format.border_style(Attr::ForegroundColor(color::BLUE));
+        table.set_format(format);
+        let mut strings = HashMap::new();
+        strings.insert("setaf", b"<%p1%d>".to_vec());
+        strings.insert("setab", b"<bg%p1%d>".to_vec());
+        strings.insert("sgr0", b"</>".to_vec());
+        let info = TermInfo {
+            names: Vec::new(),
+            bools: HashMap::new(),
+            numbers: vec![("colors", 8)].into_iter().collect(),
+            strings,
+        };
+        let mut term = TerminfoTerminal::new_with_terminfo(StringWriter::new(), info);
+        table.print_term(&mut term).unwrap();
+        let out = "\
+<4>┌───┬───┐</>
+<4>│</> a</> <4>│</> b</> <4>│</>
+<4>└───┴───┘</>
+";
+        assert_eq!(term.get_ref().as_string().replace("\r\n", "\n"), out);
+        // Borders are not styled without a terminal
+        assert!(table.to_string().starts_with("┌───┬───┐"));
     }
 
     #[test]
-    fn set_element() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        assert!(table.set_element("foo", 12, 12).is_err());
-        assert!(table.set_element("foo", 1, 1).is_ok());
-        assert_eq!(table[1][1].get_content(), "foo");
+    fn stripe_style() {
+        use crate::Terminal;
+        use std::collections::HashMap;
+        use term::terminfo::{TermInfo, TerminfoTerminal};
+
+        let mut table = table!(["a"], ["b"], [Fr->"c"], ["d"]);
+        table.set_format(*FORMAT_CLEAN);
+        table.set_stripe_style(Attr::BackgroundColor(color::BLUE));
+        let mut strings = HashMap::new();
+        strings.insert("setaf", b"<%p1%d>".to_vec());
+        strings.insert("setab", b"<bg%p1%d>".to_vec());
+        strings.insert("sgr0", b"</>".to_vec());
+        let info = TermInfo {
+            names: Vec::new(),
+            bools: HashMap::new(),
+            numbers: vec![("colors", 8)].into_iter().collect(),
+            strings,
+        };
+        let mut term = TerminfoTerminal::new_with_terminfo(StringWriter::new(), info.clone());
+        table.print_term(&mut term).unwrap();
+        let out = " a</> \n <bg4>b</> \n <1>c</> \n <bg4>d</> \n";
+        assert_eq!(term.get_ref().as_string().replace("\r\n", "\n"), out);
+
+        // Full width rows and group headers don't shift the stripes
+        table.insert_row(1, Row::group_header("g"));
+        table.insert_row(3, Row::full_width("w"));
+        let mut term = TerminfoTerminal::new_with_terminfo(StringWriter::new(), info);
+        table.print_term(&mut term).unwrap();
+        let out = " a</> \n g</> \n <bg4>b</> \n w</> \n <1>c</> \n <bg4>d</> \n";
+        assert_eq!(term.get_ref().as_string().replace("\r\n", "\n"), out);
     }
 
     #[test]
-    fn no_linesep() {
-        let mut table = Table::new();
-        table.set_format(*FORMAT_NO_LINESEP);
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        assert_eq!(table[1][1].get_content(), "bc");
+    fn format_float_column() {
+        let mut table = table!(["a", "1.2"], ["b", "1.25"], ["c", "n/a"], ["d", "-12"]);
+        table.format_float_column(1, 5);
+        let out = "\
++---+--------+
+| a |   1.20 |
++---+--------+
+| b |   1.25 |
++---+--------+
+| c | n/a    |
++---+--------+
+| d | -12.00 |
++---+--------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.format_float_column(1, 1);
+        assert_eq!(table[0][1].get_content(), "1.2");
+        assert_eq!(table[1][1].get_content(), "1.2");
 
-        table[1][1] = Cell::new("newval");
-        assert_eq!(table[1][1].get_content(), "newval");
+        // Rounding errors don't need more decimal places to tell values apart
+        let mut table = table!([0.1 + 0.2], [0.5], [1.25], [1.2]);
+        table.format_float_column(0, 10);
+        let contents = table
+            .column_iter(0)
+            .map(|c| c.get_content())
+            .collect::<Vec<_>>();
+        assert_eq!(contents, vec!["0.30", "0.50", "1.25", "1.20"]);
+        // but different values are kept apart, up to the maximum precision
+        let mut table = table!([0.1 + 0.2], [0.3], [0.5]);
+        table.format_float_column(0, 3);
+        let contents = table
+            .column_iter(0)
+            .map(|c| c.get_content())
+            .collect::<Vec<_>>();
+        assert_eq!(contents, vec!["0.300", "0.300", "0.500"]);
+        let mut table = table!([0.1 + 0.2], [0.3]);
+        table.format_float_column(0, 20);
+        assert_eq!(table[0][0].get_content(), "0.30000000000000004");
+        assert_eq!(table[1][0].get_content(), "0.29999999999999999");
+    }
+
+    #[test]
+    fn style_inheritance() {
+        let mut table = table!(["a", "b"], [Fr->"c", "d"]);
+        table[1].style(Attr::ForegroundColor(color::BLUE));
+        table.style(Attr::ForegroundColor(color::GREEN));
+        table.column_style(1, Attr::Bold);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert_eq!(
+            html.as_string(),
+            "<table><tr>\
+             <td style=\"color: #00aa00;text-align: left;\">a</td>\
+             <td style=\"color: #00aa00;font-weight: bold;text-align: left;\">b</td>\
+             </tr><tr>\
+             <td style=\"color: #00aa00;color: #0000aa;color: #aa0000;text-align: left;\">c</td>\
+             <td style=\"color: #00aa00;font-weight: bold;color: #0000aa;text-align: left;\">d</td>\
+             </tr></table>"
+        );
+        table.reset_style();
+        table.reset_column_style(1);
+        let mut html = StringWriter::new();
+        table.print_html(&mut html).unwrap();
+        assert!(html.as_string().starts_with(
+            "<table><tr><td style=\"text-align: left;\">a</td><td style=\"text-align: left;\">b</td>"
+        ));
+    }
 
+    #[test]
+    fn insert_remove_column() {
+        let mut table = table!(["a", "b"], [H2->"cd"], ["e"]);
+        table.add_row(Row::full_width("group"));
+        table.set_footer(row!["f1", "f2"]);
+        table.insert_column(
+            1,
+            Some(Cell::new("t")),
+            vec![Cell::new("x"), Cell::new("y")],
+        );
         let out = "\
-+-----+--------+-----+
-| t1  | t2     | t3  |
-| a   | bc     | def |
-| def | newval | a   |
-+-----+--------+-----+
++----+---+----+
+|    | t |    |
++====+===+====+
+| a  | x | b  |
++----+---+----+
+| y  | cd     |
++----+---+----+
+| e  |   |    |
++----+---+----+
+| group       |
++====+===+====+
+| f1 |   | f2 |
++----+---+----+
 ";
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
-        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+        table.insert_column(4, None, vec![Cell::new("z")]);
+        assert_eq!(table[0].len(), 5);
+        assert_eq!(table[0][4].get_content(), "z");
+        assert_eq!(table[2].len(), 5);
+
+        table.remove_column(4);
+        table.remove_column(0);
+        assert_eq!(table[1][0].get_content(), "cd");
+        assert_eq!(table[1][0].get_hspan(), 2);
+        table.remove_column(1);
+        assert_eq!(table[0].len(), 2);
+        assert_eq!(table[0][0].get_content(), "x");
+        assert_eq!(table[1][0].get_hspan(), 1);
+        assert_eq!(table[3][0].get_content(), "group");
     }
 
     #[test]
-    fn no_colsep() {
-        let mut table = Table::new();
-        table.set_format(*FORMAT_NO_COLSEP);
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        assert_eq!(table[1][1].get_content(), "bc");
-
-        table[1][1] = Cell::new("newval");
-        assert_eq!(table[1][1].get_content(), "newval");
-
+    fn add_column() {
+        let mut table = table!(["a"], ["bcd", r->"e"]);
+        table.add_column(
+            Some(Cell::new("total")),
+            vec![
+                Cell::new("x"),
+                Cell::new("y").style_spec("l"),
+                Cell::new("z"),
+            ],
+            Some(format::Alignment::RIGHT),
+        );
         let out = "\
-------------------
- t1   t2      t3 \n\
-==================
- a    bc      def \n\
-------------------
- def  newval  a \n\
-------------------
++-----+---+-------+
+|     |   | total |
++=====+===+=======+
+| a   |   |     x |
++-----+---+-------+
+| bcd | e | y     |
++-----+---+-------+
+|     |   |     z |
++-----+---+-------+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        table.add_column(None, vec![Cell::new("1234")], None);
+        assert_eq!(table[0][3].get_content(), "1234");
+        assert_eq!(table[2].len(), 4);
     }
 
     #[test]
-    fn clean() {
-        let mut table = Table::new();
-        table.set_format(*FORMAT_CLEAN);
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        assert_eq!(table[1][1].get_content(), "bc");
+    fn column_ref() {
+        let mut table = table!(["b", "1.5"], ["a", "2"]);
+        table.set_titles(row!["name", "value"]);
+        table.sort_by_column_str("name", true);
+        assert_eq!(table[0][0].get_content(), "a");
+        table.format_float_column("value", 2);
+        assert_eq!(table[1][1].get_content(), "1.5");
+        table.column_style("value", Attr::Bold);
+        assert_eq!(table.settings.columns[1].style, vec![Attr::Bold]);
+        let name = String::from("name");
+        assert_eq!(table.column_iter(&name).count(), 2);
+        assert_eq!(table.column_iter_mut("missing").count(), 0);
+        assert_eq!(table.as_slice().column_iter("missing").count(), 0);
+        table.column_style("missing", Attr::Bold);
+        table.set_column_hide_if_empty("missing", true);
+        table.sort_by_column_str("missing", false);
+        table.remove_column("missing");
+        assert_eq!(table.settings.columns.len(), 2);
+        assert_eq!(table[0][0].get_content(), "a");
+        table.remove_column("name");
+        assert_eq!(table[0][0].get_content(), "2.0");
+        assert_eq!(table.column_index("value"), Some(0));
 
-        table[1][1] = Cell::new("newval");
-        assert_eq!(table[1][1].get_content(), "newval");
+        let contents = table
+            .column_iter(ColumnRef::Name("value"))
+            .map(|c| c.get_content())
+            .collect::<Vec<_>>();
+        assert_eq!(contents, vec!["2.0", "1.5"]);
+        table.set_column_alignment(ColumnRef::Name("value"), format::Alignment::RIGHT);
+        assert_eq!(
+            table.settings.columns[0].align,
+            Some(format::Alignment::RIGHT)
+        );
+        assert_eq!(table.column_iter(ColumnRef::Name("missing")).count(), 0);
+        table.set_column_alignment(ColumnRef::Name("missing"), format::Alignment::CENTER);
+        assert_eq!(table.settings.columns.len(), 1);
+        assert_eq!(
+            table.settings.columns[0].align,
+            Some(format::Alignment::RIGHT)
+        );
+    }
 
+    #[test]
+    fn from_grouped() {
+        let table = Table::from_grouped(vec![(1, "a"), (2, "b"), (1, "c"), (3, "d"), (1, "e")]);
         let out = "\
-\u{0020}t1   t2      t3 \n\
-\u{0020}a    bc      def \n\
-\u{0020}def  newval  a \n\
++---+---+---+
+| 1 | 2 | 3 |
++===+===+===+
+| a | b | d |
++---+---+---+
+| c |   |   |
++---+---+---+
+| e |   |   |
++---+---+---+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(3, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        let table = Table::from_grouped(Vec::<(&str, &str)>::new());
+        assert!(table.is_empty());
+        assert!(table.titles.is_none());
     }
 
     #[test]
-    fn padding() {
-        let mut table = Table::new();
-        let mut format = *FORMAT_DEFAULT;
-        format.padding(2, 2);
-        table.set_format(format);
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        assert_eq!(table[1][1].get_content(), "bc");
+    fn row_numbers() {
+        let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+        table.set_titles(row!["name", "n"]);
+        table.insert_row(1, Row::full_width("group"));
+        table[2].set_hidden(true);
+        table.set_aggregate_footer(&[Aggregate::Sum(1)]);
+        table.set_row_numbers(true);
+        let out = "\
++---+------+---+
+| # | name | n |
++===+======+===+
+| 1 | a    | 1 |
++---+------+---+
+| group        |
++---+------+---+
+| 2 | c    | 3 |
++===+======+===+
+|   |      | 4 |
++---+------+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        let slice = table.slice(3..).slice_columns(..1).to_string();
+        assert!(slice.contains("| # | name |"));
+        assert!(slice.contains("| 1 | c    |"));
+    }
 
-        table[1][1] = Cell::new("newval");
-        assert_eq!(table[1][1].get_content(), "newval");
+    #[test]
+    fn summary() {
+        let mut table = table!(["a", "1"], ["b", "2"], ["c", "3"]);
+        table.set_format(*FORMAT_CLEAN);
+        table.get_format().indent(2);
+        table.add_row(Row::full_width("group"));
+        table[1].set_hidden(true);
+        table.set_summary(true);
+        table.set_caption("values", CaptionPosition::Below);
+        let out = "   a  1 \n   c  3 \n   group \n  values\n  (2 rows)\n";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(table.print(&mut Vec::new()).unwrap(), 5);
+        assert!(table.slice(..1).to_string().contains("(1 row)"));
+        table.set_summary(false);
+        assert!(!table.to_string().contains("rows"));
+    }
 
+    #[test]
+    fn print_snipped() {
+        let mut table: Table = (1..=6).map(|i| vec![i]).collect();
+        table.set_titles(row!["n"]);
+        table.set_aggregate_footer(&[Aggregate::Sum(0)]);
+        table.set_row_numbers(true);
+        table.set_format(*FORMAT_NO_LINESEP);
         let out = "\
-+-------+----------+-------+
-|  t1   |  t2      |  t3   |
-+=======+==========+=======+
-|  a    |  bc      |  def  |
-+-------+----------+-------+
-|  def  |  newval  |  a    |
-+-------+----------+-------+
++---+----------------+
+| # | n              |
+| 1 | 1              |
+| 2 | 2              |
+| … 3 rows omitted … |
+| 6 | 6              |
+|   |             21 |
++---+----------------+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        let mut snipped = Vec::new();
+        assert_eq!(table.print_snipped(&mut snipped, 2, 1).unwrap(), 8);
+        let snipped = String::from_utf8(snipped).unwrap();
+        assert_eq!(snipped.replace("\r\n", "\n"), out);
+        let mut whole = Vec::new();
+        table.print_snipped(&mut whole, 3, 3).unwrap();
+        assert_eq!(String::from_utf8(whole).unwrap(), table.to_string());
     }
 
     #[test]
-    fn indent() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        table.get_format().indent(8);
-        let out = "        +-----+----+-----+
-        | t1  | t2 | t3  |
-        +=====+====+=====+
-        | a   | bc | def |
-        +-----+----+-----+
-        | def | bc | a   |
-        +-----+----+-----+
+    fn from_columns() {
+        let table = Table::from_columns(vec![
+            ("name", vec!["a", "b", "c"]),
+            ("size", vec!["1"]),
+            ("kind", vec![]),
+        ]);
+        let out = "\
++------+------+------+
+| name | size | kind |
++======+======+======+
+| a    | 1    |      |
++------+------+------+
+| b    |      |      |
++------+------+------+
+| c    |      |      |
++------+------+------+
 ";
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        let table = Table::from_columns(vec![(1, 10..12)]);
+        assert_eq!(table[1][0].get_content(), "11");
+        let table = Table::from_columns(Vec::<(&str, Vec<&str>)>::new());
+        assert!(table.is_empty());
+        assert!(table.titles.is_none());
     }
 
     #[test]
-    fn slices() {
-        let mut table = Table::new();
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("0"),
-            Cell::new("0"),
-            Cell::new("0"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("1"),
-            Cell::new("1"),
-            Cell::new("1"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("2"),
-            Cell::new("2"),
-            Cell::new("2"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("3"),
-            Cell::new("3"),
-            Cell::new("3"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("4"),
-            Cell::new("4"),
-            Cell::new("4"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("5"),
-            Cell::new("5"),
-            Cell::new("5"),
-        ]));
+    fn transpose() {
+        let mut table = table!(["a", "b", "c"], [H2->"de", "f"]);
+        table.add_row(Row::full_width("group"));
+        table.set_titles(row!["t1", "t2", "t3"]);
+        table.set_footer(row!["f1"]);
         let out = "\
-+----+----+----+
-| t1 | t2 | t3 |
-+====+====+====+
-| 1  | 1  | 1  |
-+----+----+----+
-| 2  | 2  | 2  |
-+----+----+----+
-| 3  | 3  | 3  |
-+----+----+----+
++----+---+----+----+
+| t1 | a | de | f1 |
++----+---+----+----+
+| t2 | b |    |    |
++----+---+----+----+
+| t3 | c | f  |    |
++----+---+----+----+
 ";
-        let slice = table.slice(..);
-        let slice = slice.slice(1..);
-        let slice = slice.slice(..3);
-        assert_eq!(out, slice.to_string().replace("\r\n", "\n"));
-        assert_eq!(9, slice.print(&mut StringWriter::new()).unwrap());
-        assert_eq!(out, table.slice(1..4).to_string().replace("\r\n", "\n"));
+        assert_eq!(table.transpose(true).to_string().replace("\r\n", "\n"), out);
+        let transposed = table.transpose(false);
+        assert_eq!(transposed.len(), 3);
+        assert_eq!(transposed[0].len(), 3);
+        assert_eq!(transposed[0][0].get_content(), "a");
+        assert_eq!(transposed[0][1].get_hspan(), 1);
+        assert!(Table::new().transpose(true).is_empty());
+    }
+
+    #[test]
+    fn trim_trailing_whitespace() {
+        let mut table = table!(["a", "bc", ""], ["def\nx", r->"b", "c"], [H2->"e", ""]);
+        table.set_titles(row!["t1", "t2", "t3"]);
+        table.set_heading("heading");
+        for (name, mut format) in format::all_presets() {
+            format.trim_trailing_whitespace(true);
+            format.indent(2);
+            table.set_format(format);
+            for line in table.to_string().lines() {
+                assert!(!line.ends_with(' '), "{}: {:?}", name, line);
+            }
+        }
+        let mut format = *FORMAT_CLEAN;
+        format.trim_trailing_whitespace(true);
+        table.set_format(format);
+        table.unset_heading();
+        let out = "\
+\u{0020}t1   t2  t3\n\
+\u{0020}a    bc\n\
+\u{0020}def   b  c\n\
+\u{0020}x\n\
+\u{0020}e\n\
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn extend_from_table() {
+        let mut table = table!(["a", "b"]);
+        table.set_titles(row!["t1", "t2"]);
+        let mut other = table!(["c"], ["d", "e", "f"]);
+        other.set_titles(row!["ignored"]);
+        other.add_row(Row::full_width("group"));
+        table.extend_from_table(&other);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[1].len(), 2);
+        assert_eq!(table[2].len(), 3);
+        assert_eq!(table[3].len(), 1);
+        assert_eq!(table.get_column_num(), 3);
         assert_eq!(
-            9,
-            table.slice(1..4).print(&mut StringWriter::new()).unwrap()
+            table.titles.as_ref().as_ref().unwrap()[0].get_content(),
+            "t1"
+        );
+
+        let table = table + &other;
+        assert_eq!(table.len(), 7);
+        assert_eq!(table[4].len(), 3);
+        let mut table = table!(["a"]) + table!(["b"]);
+        table += &table!(["c"]);
+        table += table!(["d"]);
+        assert_eq!(
+            table
+                .column_iter(0)
+                .map(|c| c.get_content())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
         );
     }
 
     #[test]
-    fn test_unicode_separators() {
-        let mut table = Table::new();
-        table.set_format(*FORMAT_BOX_CHARS);
-        table.add_row(Row::new(vec![
-            Cell::new("1"),
-            Cell::new("1"),
-            Cell::new("1"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("2"),
-            Cell::new("2"),
-            Cell::new("2"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
+    fn render() {
+        let table = table!(["a", "bcd"], ["ef", "g"]);
+        let widths = table.column_widths();
+        assert_eq!(widths, vec![2, 3]);
+        let mut out = StringWriter::new();
+        let format = *FORMAT_DEFAULT;
+        format
+            .render_separator(&mut out, &widths, format::LinePosition::Top)
+            .unwrap();
+        for row in table.row_iter() {
+            row.render(&mut out, &format, &widths).unwrap();
+        }
+        assert_eq!(
+            row![H3->"spanning"]
+                .render(&mut out, &format, &widths)
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            row!["x", "y", "z"]
+                .render(&mut out, &format, &widths)
+                .unwrap(),
+            1
+        );
+        format
+            .render_separator(&mut out, &widths, format::LinePosition::Bottom)
+            .unwrap();
+        let expected = "\
++----+-----+
+| a  | bcd |
+| ef | g   |
+| spanning |
+| x  | y   |
++----+-----+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+    }
+
+    #[test]
+    fn extend() {
+        let mut table = table!(["a", "1"]);
+        table.extend((2..4).map(|i| row![format!("r{}", i), i]));
+        table.extend(vec![vec!["b", "4"]]);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[1][0].get_content(), "r2");
+        assert_eq!(table[3][1].get_content(), "4");
+    }
+
+    #[test]
+    fn column_alignment() {
+        let mut table = table!(["a", "1"], [r->"b", "22"]);
+        table.set_titles(row!["name", "value"]);
+        table.set_column_alignment(0, format::Alignment::CENTER);
+        table.set_column_alignment("value", format::Alignment::RIGHT);
+        table.add_row(row!["c", l->"3"]);
+        let mut left = row!["d", "4"];
+        left.align(format::Alignment::LEFT);
+        table.add_row(left);
         let out = "\
-┌────┬────┬────┐
-│ t1 │ t2 │ t3 │
-├────┼────┼────┤
-│ 1  │ 1  │ 1  │
-├────┼────┼────┤
-│ 2  │ 2  │ 2  │
-└────┴────┴────┘
++------+-------+
+| name | value |
++======+=======+
+|  a   |     1 |
++------+-------+
+|    b |    22 |
++------+-------+
+|  c   | 3     |
++------+-------+
+| d    | 4     |
++------+-------+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.unset_column_alignment(0);
+        table.set_column_alignment("missing", format::Alignment::RIGHT);
+        assert_eq!(table.settings.columns.len(), 2);
+        assert_eq!(table.settings.columns[0].align, None);
     }
 
     #[test]
-    fn test_readme_format() {
-        // The below is lifted from the README
+    fn set_column_style() {
+        let mut table = table!(["a", "1"], ["b", Fg->"22"]);
+        table.set_titles(row!["name", "value"]);
+        table.set_column_alignment(1, format::Alignment::CENTER);
+        table.set_column_style("value", "bFrr");
+        assert_eq!(
+            table.settings.columns[1].style,
+            vec![Attr::Bold, Attr::ForegroundColor(color::RED)]
+        );
+        assert_eq!(
+            table[1].inherited(&table.settings, 1).align,
+            Some(format::Alignment::RIGHT)
+        );
+        let mut out = StringWriter::new();
+        table.to_json(&mut out, true).unwrap();
+        assert!(out
+            .as_string()
+            .contains(r##"[{"align":"left"},{"align":"right","bold":true,"color":"#00aa00"}]"##));
+        table.set_column_style(1, "i");
+        assert_eq!(table.settings.columns[1].style, vec![Attr::Italic(true)]);
+        assert_eq!(table.settings.columns[1].align, None);
+    }
 
-        let mut table = Table::new();
-        let format = format::FormatBuilder::new()
-            .column_separator('|')
-            .borders('|')
-            .separators(
-                &[format::LinePosition::Top, format::LinePosition::Bottom],
-                format::LineSeparator::new('-', '+', '+', '+'),
-            )
-            .padding(1, 1)
-            .build();
-        table.set_format(format);
+    #[test]
+    fn column_width() {
+        let mut table = table!(["1", "the quick brown fox", "a"], [H2->"spanning cell", "b"]);
+        table.set_titles(row!["id", "description", "c"]);
+        table.set_column_width(0, ColumnWidth::Fixed(4));
+        table.set_column_width("description", ColumnWidth::Max(9));
+        table.set_column_width(2, ColumnWidth::Min(3));
+        let out = "\
++------+-----------+-----+
+| id   | descript… | c   |
++======+===========+=====+
+| 1    | the quic… | a   |
++------+-----------+-----+
+| spanning cell    | b   |
++------+-----------+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(table.column_widths(), vec![4, 9, 3]);
+        assert_eq!(table[0][1].get_content(), "the quick brown fox");
 
-        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
-        table.add_row(Row::new(vec![Cell::new("Value 1"), Cell::new("Value 2")]));
-        table.add_row(Row::new(vec![
-            Cell::new("Value three"),
-            Cell::new("Value four"),
-        ]));
+        table.set_column_wrap(1, true);
+        let out = "\
++------+-----------+-----+
+| id   | descripti | c   |
+|      | on        |     |
++======+===========+=====+
+| 1    | the quick | a   |
+|      | brown fox |     |
++------+-----------+-----+
+| spanning cell    | b   |
++------+-----------+-----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert_eq!(9, table.print(&mut StringWriter::new()).unwrap());
+
+        table.unset_column_width(1);
+        table.unset_column_width(2);
+        assert_eq!(table.column_widths(), vec![4, 19, 1]);
+
+        // Each bound is kept when setting the other one
+        table.set_column_width(1, ColumnWidth::Min(12));
+        table.set_column_width(1, ColumnWidth::Max(15));
+        table.set_column_width(2, ColumnWidth::Max(5));
+        table.set_column_width(2, ColumnWidth::Min(3));
+        assert_eq!(table.column_widths(), vec![4, 15, 3]);
+        assert_eq!(table.settings.columns[1].min_width, 12);
+        assert_eq!(table.settings.columns[2].max_width, Some(5));
+        // ... and moved when the new one crosses it
+        table.set_column_width(2, ColumnWidth::Fixed(10));
+        table.set_column_width(2, ColumnWidth::Max(5));
+        assert_eq!(table.settings.columns[2].min_width, 5);
+        assert_eq!(table.column_widths(), vec![4, 15, 5]);
+        table.set_column_width(2, ColumnWidth::Min(7));
+        assert_eq!(table.settings.columns[2].max_width, Some(7));
+        assert_eq!(table.column_widths(), vec![4, 15, 7]);
+    }
 
+    #[test]
+    fn max_width() {
+        let mut table = table!(
+            ["1", "the quick brown fox", "jumps over the lazy dog"],
+            ["2", "a", "b"]
+        );
+        table.set_column_width(2, ColumnWidth::Fixed(8));
+        table.set_max_width(Some(32));
         let out = "\
-+-------------+------------+
-| Title 1     | Title 2    |
-| Value 1     | Value 2    |
-| Value three | Value four |
-+-------------+------------+
++---+---------------+----------+
+| 1 | the quick br… | jumps o… |
++---+---------------+----------+
+| 2 | a             | b        |
++---+---------------+----------+
 ";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        // Equal minimum and maximum widths are shrunk to fit, unlike fixed ones
+        table.set_max_width(Some(26));
+        assert_eq!(table.column_widths(), vec![1, 7, 8]);
+        table.set_column_width(2, ColumnWidth::Min(8));
+        table.set_column_width(2, ColumnWidth::Max(8));
+        assert_eq!(table.column_widths(), vec![1, 8, 7]);
 
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(5, table.print(&mut StringWriter::new()).unwrap());
+        table.unset_column_width(2);
+        table.set_max_width(Some(25));
+        table.set_column_wrap(1, true);
+        let out = "\
++---+-------+---------+
+| 1 | the   | jumps … |
+|   | quick |         |
+|   | brown |         |
+|   | fox   |         |
++---+-------+---------+
+| 2 | a     | b       |
++---+-------+---------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        for (_, format) in format::all_presets() {
+            table.set_format(format);
+            for line in table.to_string().lines() {
+                assert!(line.chars().count() <= 25, "{:?}", line);
+            }
+        }
+
+        table.set_max_width(None);
+        assert_eq!(table.column_widths(), vec![1, 19, 23]);
     }
 
     #[test]
-    fn test_readme_format_with_title() {
-        let mut table = Table::new();
-        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    fn max_width_ambiguous_wide() {
+        let mut table = table!(["±1.5 ±2.5", "…"], ["a", "±±±±±±"]);
+        let mut format = *FORMAT_DEFAULT;
+        format.ambiguous_width(format::AmbiguousWidth::Wide);
+        table.set_format(format);
+        table.set_column_width(1, ColumnWidth::Max(6));
+        table.set_column_width(0, ColumnWidth::Max(8));
+        table.set_column_wrap(0, true);
+        // Printed with ambiguous width characters 2 columns wide
+        let out = "\
++-------+--------+
+| ±1.5 | …     |
+| ±2.5 |        |
++-------+--------+
+| a     | ±±… |
++-------+--------+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
 
-        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
-        table.add_row(Row::new(vec![Cell::new("Value 1"), Cell::new("Value 2")]));
-        table.add_row(Row::new(vec![
-            Cell::new("Value three"),
-            Cell::new("Value four"),
-        ]));
+    #[test]
+    fn auto_fit() {
+        let mut table = table!(["a", "the quick brown fox jumps over the lazy dog"]);
+        assert!(table.as_slice().fit_terminal(false).is_none());
+        std::env::set_var("COLUMNS", "20");
+        if let Some(width) = crate::utils::terminal_width() {
+            let settings = table.as_slice().fit_terminal(true).unwrap();
+            assert_eq!(settings.max_width, Some(width));
+            let fitted = TableSlice {
+                settings: &settings,
+                ..table.as_slice()
+            };
+            assert!(fitted
+                .to_string()
+                .lines()
+                .all(|l| l.chars().count() <= width));
+        }
+        table.set_auto_fit(false);
+        assert!(table.as_slice().fit_terminal(true).is_none());
+        table.set_auto_fit(true);
+        table.set_max_width(Some(30));
+        assert!(table.as_slice().fit_terminal(true).is_none());
+    }
 
+    #[test]
+    fn patterned_separator() {
+        let mut table = table!(["abc", "de"]);
+        let mut format = format::TableFormat::new();
+        format.borders('|');
+        format.column_separator('|');
+        let sep = format::LineSeparator::with_pattern("═·", '+', '+', '+').unwrap();
+        format.separators(
+            &[format::LinePosition::Top, format::LinePosition::Bottom],
+            sep,
+        );
+        table.set_titles(row!["a", "b"]);
+        format.separator(
+            format::LinePosition::Title,
+            format::LineSeparator::with_pattern("語", '+', '+', '+').unwrap(),
+        );
+        table.set_format(format);
         let out = "\
-+-------------+------------+
-| Title 1     | Title 2    |
-+-------------+------------+
-| Value 1     | Value 2    |
-| Value three | Value four |
-+-------------+------------+
++═·═+═·+
+|a  |b |
++語 +語+
+|abc|de|
++═·═+═·+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(6, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        assert!(format::LineSeparator::with_pattern("", '+', '+', '+').is_err());
+        assert!(format::LineSeparator::with_pattern("123456789", '+', '+', '+').is_err());
+        assert!(format::LineSeparator::with_pattern("-\u{301}", '+', '+', '+').is_err());
     }
 
     #[test]
-    fn test_empty_table_with_title() {
-        let mut table = Table::new();
-        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    fn format_eq_hash() {
+        use std::collections::HashSet;
+        assert_eq!(format::TableFormat::new(), format::TableFormat::default());
+        assert_ne!(*FORMAT_DEFAULT, format::TableFormat::default());
+        assert_eq!(
+            format::FormatBuilder::from(*FORMAT_DEFAULT).build(),
+            *FORMAT_DEFAULT
+        );
+        // Presets are all different, and equal formats are deduplicated
+        let presets = format::all_presets();
+        let mut formats: HashSet<_> = presets.iter().map(|&(_, f)| f).collect();
+        assert_eq!(formats.len(), presets.len());
+        formats.insert(*FORMAT_DEFAULT);
+        assert_eq!(formats.len(), presets.len());
+        let sep = format::LineSeparator::new('-', '+', '+', '+');
+        assert_eq!(sep, format::LineSeparator::default());
+        assert_ne!(sep, format::LineSeparator::blank());
+        let seps: HashSet<_> =
+            IntoIterator::into_iter([sep, format::LineSeparator::default()]).collect();
+        assert_eq!(seps.len(), 1);
+    }
 
-        table.set_titles(Row::new(vec![Cell::new("Title 1"), Cell::new("Title 2")]));
+    #[test]
+    fn format_spec() {
+        for (name, format) in format::all_presets() {
+            let spec = format.to_spec();
+            assert_eq!(
+                format::TableFormat::from_spec(&spec).unwrap(),
+                format,
+                "{}",
+                name
+            );
+        }
+        let spec =
+            "col=\\s left=\\\\ intern=-\\s+++ footer= pad=0,2 padchar=\\u{3000} indent=1 style=FR trim markers ambiguous=wide newline=crlf";
+        let format = format::TableFormat::from_spec(spec).unwrap();
+        assert_eq!(
+            format.get_column_separator(format::ColumnPosition::Intern),
+            Some(' ')
+        );
+        assert_eq!(
+            format.get_column_separator(format::ColumnPosition::Left),
+            Some('\\')
+        );
+        assert_eq!(
+            format.get_column_separator(format::ColumnPosition::Right),
+            None
+        );
+        assert_eq!(format.get_padding(), (0, 2));
+        assert_eq!(format.get_ambiguous_width(), format::AmbiguousWidth::Wide);
+        assert_eq!(format.get_padding_char(), '\u{3000}');
+        assert_eq!(format.get_newline(), format::Newline::CrLf);
+        assert_eq!(
+            format.get_border_style(),
+            Some(Attr::ForegroundColor(color::BRIGHT_RED))
+        );
+        assert_eq!(format.to_spec(), spec);
+        for spec in &[
+            "col=ab",
+            "top=-++",
+            "pad=x",
+            "style=Fx",
+            "trim=1",
+            "foo=1",
+            "col=\\x",
+            "ambiguous=x",
+            "col=\\u{zz}",
+        ] {
+            assert!(format::TableFormat::from_spec(spec).is_err(), "{}", spec);
+        }
+    }
 
+    #[test]
+    fn justify() {
+        let mut table = table!(["the quick brown fox jumps over the lazy dog"]);
+        table.set_column_alignment(0, format::Alignment::JUSTIFY);
+        table.set_column_width(0, ColumnWidth::Max(16));
+        table.set_column_wrap(0, true);
         let out = "\
-+---------+---------+
-| Title 1 | Title 2 |
-+---------+---------+
-+---------+---------+
++-----------------+
+| the quick brown |
+| fox  jumps over |
+| the lazy dog    |
++-----------------+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(4, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
     }
 
     #[test]
-    fn test_horizontal_span() {
-        let mut table = Table::new();
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2").with_hspan(2),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def").style_spec("H02c"),
-            Cell::new("a"),
-        ]));
+    fn right_to_left() {
+        let mut table = table!(["a", "bc", "d"], ["ef", c->"k", "g"], ["h", "i", "j"]);
+        table.set_titles(row!["t1", "t2", "t3"]);
+        table.add_title_group(row![H2c->"group", "x"]);
+        table.set_footer(row!["f1", "", "f3"]);
+        table.set_column_alignment(1, format::Alignment::RIGHT);
+        table.set_row_numbers(true);
+        table.set_right_to_left(true);
+        let mut format = *FORMAT_DEFAULT;
+        format.padding(0, 2);
+        table.set_format(format);
         let out = "\
-+----+----+-----+
-| t1 | t2       |
-+====+====+=====+
-| a  | bc | def |
-+----+----+-----+
-|   def   | a   |
-+----+----+-----+
++----+---------+---+
+|   x|   group |   |
++----+----+----+---+
+|  t3|  t2|  t1|  #|
++====+====+====+===+
+|   d|  bc|   a|  1|
++----+----+----+---+
+|   g|  k |  ef|  2|
++----+----+----+---+
+|   j|  i |   h|  3|
++====+====+====+===+
+|  f3|    |  f1|   |
++----+----+----+---+
 ";
-        println!("{}", out);
-        println!("____");
-        println!("{}", table.to_string().replace("\r\n", "\n"));
-        assert_eq!(out, table.to_string().replace("\r\n", "\n"));
-        assert_eq!(7, table.print(&mut StringWriter::new()).unwrap());
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
     }
 
     #[test]
-    fn table_html() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("a"),
-            Cell::new("bc"),
-            Cell::new("def"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("def"),
-            Cell::new("bc"),
-            Cell::new("a"),
-        ]));
-        table.set_titles(Row::new(vec![
-            Cell::new("t1"),
-            Cell::new("t2"),
-            Cell::new("t3"),
-        ]));
-        let out = "\
-<table>\
-<th><td style=\"text-align: left;\">t1</td><td style=\"text-align: left;\">t2</td><td style=\"text-align: left;\">t3</td></th>\
-<tr><td style=\"text-align: left;\">a</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">def</td></tr>\
-<tr><td style=\"text-align: left;\">def</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">a</td></tr>\
-</table>";
-        let mut writer = StringWriter::new();
-        assert!(table.print_html(&mut writer).is_ok());
-        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
-        table.unset_titles();
+    fn ascii_borders() {
+        let mut table = table!(["a", "b"], ["c", "d"]);
+        table.set_titles(row!["t1", "t2"]);
+        table.set_format(*FORMAT_BOX_CHARS);
+        table[1].set_format(*FORMAT_DOUBLE_BORDER);
+        table.set_column_title_underline(1, '═');
+        table.set_ascii_borders(true);
         let out = "\
-<table>\
-<tr><td style=\"text-align: left;\">a</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">def</td></tr>\
-<tr><td style=\"text-align: left;\">def</td><td style=\"text-align: left;\">bc</td><td style=\"text-align: left;\">a</td></tr>\
-</table>";
-        let mut writer = StringWriter::new();
-        assert!(table.print_html(&mut writer).is_ok());
-        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
++----+----+
+| t1 | t2 |
++----+====+
+| a  | b  |
++====+====+
+| c  | d  |
++----+----+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+        table.set_ascii_borders(false);
+        assert!(table.to_string().contains('┌'));
     }
 
     #[test]
-    fn table_html_colors() {
-        let mut table = Table::new();
-        table.add_row(Row::new(vec![
-            Cell::new("bold").style_spec("b"),
-            Cell::new("italic").style_spec("i"),
-            Cell::new("underline").style_spec("u"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("left").style_spec("l"),
-            Cell::new("center").style_spec("c"),
-            Cell::new("right").style_spec("r"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("red").style_spec("Fr"),
-            Cell::new("black").style_spec("Fd"),
-            Cell::new("yellow").style_spec("Fy"),
-        ]));
-        table.add_row(Row::new(vec![
-            Cell::new("bright magenta on cyan").style_spec("FMBc"),
-            Cell::new("white on bright green").style_spec("FwBG"),
-            Cell::new("default on blue").style_spec("Bb"),
-        ]));
-        table.set_titles(Row::new(
-            vec![Cell::new("span horizontal").style_spec("H3")],
-        ));
+    fn separator_every() {
+        let mut table = table!(["1"], ["2"], ["3"], ["4"], ["5"]);
+        table.add_row(Row::group_header("g"));
+        table.add_row(row!["6"]);
+        table[0].set_separator_after(true);
+        table[2].set_separator_after(false);
+        table.set_separator_every(Some(2));
+        table.set_format(*FORMAT_NO_LINESEP);
         let out = "\
-<table>\
-<th><td colspan=\"3\" style=\"text-align: left;\">span horizontal</td></th>\
-<tr><td style=\"font-weight: bold;text-align: left;\">bold</td><td style=\"font-style: italic;text-align: left;\">italic</td><td style=\"text-decoration: underline;text-align: left;\">underline</td></tr>\
-<tr><td style=\"text-align: left;\">left</td><td style=\"text-align: center;\">center</td><td style=\"text-align: right;\">right</td></tr>\
-<tr><td style=\"color: #aa0000;text-align: left;\">red</td><td style=\"color: #000000;text-align: left;\">black</td><td style=\"color: #aa5500;text-align: left;\">yellow</td></tr>\
-<tr><td style=\"color: #ff55ff;background-color: #00aaaa;text-align: left;\">bright magenta on cyan</td><td style=\"color: #aaaaaa;background-color: #55ff55;text-align: left;\">white on bright green</td><td style=\"background-color: #0000aa;text-align: left;\">default on blue</td></tr>\
-</table>";
-        let mut writer = StringWriter::new();
-        assert!(table.print_html(&mut writer).is_ok());
-        assert_eq!(writer.as_string().replace("\r\n", "\n"), out);
++---+
+| 1 |
++---+
+| 2 |
++---+
+| 3 |
+| 4 |
++---+
+| 5 |
+| g |
++---+
+| 6 |
++---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
     }
 
     #[test]
-    fn test_panic() {
-        let mut table = Table::new();
-
-        table.add_row(Row::new(vec![Cell::new("\u{1b}[\u{1b}\u{0}\u{0}")]));
-
-        let out = "+--+
-| \u{1b}[\u{1b}\u{0}\u{0} |
-+--+
+    fn markdown() {
+        let mut table = table!([c->"a", r->"1", "x"], ["bcd", "22", "y"]);
+        table.set_format(*FORMAT_MARKDOWN);
+        let out = "\
+|     |    |   |
+|:---:|---:|---|
+|  a  |  1 | x |
+| bcd | 22 | y |
 ";
-
         assert_eq!(table.to_string().replace("\r\n", "\n"), out);
-        assert_eq!(3, table.print(&mut StringWriter::new()).unwrap());
+        table.set_titles(row!["t1", "t2", "t3"]);
+        table.set_column_alignment(2, format::Alignment::CENTER);
+        let out = "\
+| t1  | t2 | t3 |
+|:---:|---:|:--:|
+|  a  |  1 | x  |
+| bcd | 22 | y  |
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), out);
+    }
+
+    #[test]
+    fn all_presets() {
+        let mut table = table!(["a", "bc"], ["def", "g"]);
+        table.set_titles(row!["t1", "t2"]);
+        table.set_footer(row!["f1", "f2"]);
+        let mut out = String::new();
+        for (name, format) in format::all_presets() {
+            table.set_format(format);
+            out.push_str(&format!("{}:\n{}", name, table));
+        }
+        let expected = "\
+FORMAT_DEFAULT:
++-----+----+
+| t1  | t2 |
++=====+====+
+| a   | bc |
++-----+----+
+| def | g  |
++=====+====+
+| f1  | f2 |
++-----+----+
+FORMAT_NO_TITLE:
++-----+----+
+| t1  | t2 |
++-----+----+
+| a   | bc |
++-----+----+
+| def | g  |
++-----+----+
+| f1  | f2 |
++-----+----+
+FORMAT_NO_LINESEP_WITH_TITLE:
++-----+----+
+| t1  | t2 |
++-----+----+
+| a   | bc |
+| def | g  |
++-----+----+
+| f1  | f2 |
++-----+----+
+FORMAT_NO_LINESEP:
++-----+----+
+| t1  | t2 |
+| a   | bc |
+| def | g  |
+| f1  | f2 |
++-----+----+
+FORMAT_NO_COLSEP:
+---------
+ t1   t2 
+=========
+ a    bc 
+---------
+ def  g 
+=========
+ f1   f2 
+---------
+FORMAT_CLEAN:
+ t1   t2 
+ a    bc 
+ def  g 
+ f1   f2 
+FORMAT_BORDERS_ONLY:
++---------+
+| t1   t2 |
++=========+
+| a    bc |
+| def  g  |
++=========+
+| f1   f2 |
++---------+
+FORMAT_NO_BORDER:
+ t1  | t2 
+=====+====
+ a   | bc 
+-----+----
+ def | g 
+=====+====
+ f1  | f2 
+FORMAT_NO_BORDER_LINE_SEPARATOR:
+ t1  | t2 
+-----+----
+ a   | bc 
+ def | g 
+-----+----
+ f1  | f2 
+FORMAT_BOX_CHARS:
+┌─────┬────┐
+│ t1  │ t2 │
+├─────┼────┤
+│ a   │ bc │
+├─────┼────┤
+│ def │ g  │
+├─────┼────┤
+│ f1  │ f2 │
+└─────┴────┘
+FORMAT_ROUNDED:
+╭─────┬────╮
+│ t1  │ t2 │
+├─────┼────┤
+│ a   │ bc │
+├─────┼────┤
+│ def │ g  │
+├─────┼────┤
+│ f1  │ f2 │
+╰─────┴────╯
+FORMAT_DOUBLE_BORDER:
+╔═════╦════╗
+║ t1  ║ t2 ║
+╠═════╬════╣
+║ a   ║ bc ║
+╠═════╬════╣
+║ def ║ g  ║
+╠═════╬════╣
+║ f1  ║ f2 ║
+╚═════╩════╝
+FORMAT_MARKDOWN:
+| t1  | t2 |
+|-----|----|
+| a   | bc |
+| def | g  |
+| f1  | f2 |
+FORMAT_PSQL:
+ t1  | t2
+-----+----
+ a   | bc
+ def | g
+-----+----
+ f1  | f2
+";
+        assert_eq!(out.replace("\r\n", "\n"), expected);
     }
 }
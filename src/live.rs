@@ -0,0 +1,93 @@
+//! This module contains the in-place re-rendering of tables
+use std::io::{self, Error, Stdout, Write};
+
+use super::AsTableSlice;
+
+/// A table printed in place, which is erased and printed again on each update,
+/// for refreshing displays like `top` or `watch`.
+///
+/// It remembers the number of lines it printed, and moves the cursor back up with ANSI escape
+/// sequences before printing the new content, so the output must be an ANSI terminal.
+/// Lines too long for the terminal are wrapped by it, and not accounted for.
+///
+/// # Example
+/// ```no_run
+/// # #[macro_use] extern crate prettytable;
+/// use prettytable::LiveTable;
+/// # fn main() {
+/// let mut live = LiveTable::stdout();
+/// for i in 0..10 {
+///     live.update(&table!(["progress", i * 10])).unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(500));
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LiveTable<W: Write> {
+    out: W,
+    /// Number of lines printed by the last update
+    height: usize,
+}
+
+impl LiveTable<Stdout> {
+    /// Create a live table printed to standard output
+    pub fn stdout() -> LiveTable<Stdout> {
+        LiveTable::new(io::stdout())
+    }
+}
+
+impl<W: Write> LiveTable<W> {
+    /// Create a live table printed to `out`. Nothing is printed before the first update
+    pub fn new(out: W) -> LiveTable<W> {
+        LiveTable { out, height: 0 }
+    }
+
+    /// Replace the table printed by the previous update, if any, with `table`.
+    /// The table is rendered before erasing the previous one, to limit flickering.
+    /// Returns the number of printed lines
+    pub fn update<A: AsTableSlice + ?Sized>(&mut self, table: &A) -> Result<usize, Error> {
+        let mut buffer = Vec::new();
+        let height = table.as_slice().print(&mut buffer)?;
+        if self.height > 0 {
+            // Move up to the first line of the previous table, and erase down to the end
+            write!(self.out, "\x1b[{}A\r\x1b[J", self.height)?;
+        }
+        self.out.write_all(&buffer)?;
+        self.out.flush()?;
+        self.height = height;
+        Ok(height)
+    }
+
+    /// Forget the previously printed table, so that the next update is printed below it
+    pub fn detach(&mut self) {
+        self.height = 0;
+    }
+
+    /// Get the underlying output
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LiveTable;
+    use crate::table;
+
+    #[test]
+    fn update() {
+        let mut live = LiveTable::new(Vec::new());
+        assert_eq!(live.update(&table!(["a"], ["b"])).unwrap(), 5);
+        assert_eq!(live.update(&table!(["c"])).unwrap(), 3);
+        live.detach();
+        live.update(&table!(["d"])).unwrap();
+        let out = String::from_utf8(live.into_inner())
+            .unwrap()
+            .replace("\r\n", "\n");
+        let expected = "\
++---+\n| a |\n+---+\n| b |\n+---+\n\
+\x1b[5A\r\x1b[J+---+\n| c |\n+---+\n\
++---+\n| d |\n+---+\n";
+        assert_eq!(out, expected);
+    }
+}
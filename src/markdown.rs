@@ -0,0 +1,210 @@
+//! This module contains the Markdown export of tables
+use std::io::{Error, Write};
+
+use super::escape::{Escaper, MarkdownEscaper};
+use super::format::Alignment;
+use super::utils::{display_width, NEWLINE};
+use super::{AsTableSlice, Row, Table, TableSlice};
+
+/// Get the content of the cells in `row` escaped with `escaper`, one per column, with the lines of
+/// multi-line cells joined by `<br>`. Spanning cells are followed by empty cells,
+/// and missing cells are empty
+fn row_cells<E: Escaper + ?Sized>(row: &Row, column_num: usize, escaper: &E) -> Vec<String> {
+    let mut cells = Vec::with_capacity(column_num);
+    for cell in row.iter() {
+        let lines = (0..cell.get_height())
+            .map(|i| escaper.escape(cell.get_line(i)).into_owned())
+            .collect::<Vec<_>>();
+        cells.push(lines.join("<br>"));
+        cells.extend((1..cell.get_hspan()).map(|_| String::new()));
+    }
+    cells.resize(column_num, String::new());
+    cells
+}
+
+/// Write a line of the table with the `cells` padded to `widths` according to `aligns`
+fn write_line<T: Write + ?Sized>(
+    out: &mut T,
+    cells: &[String],
+    widths: &[usize],
+    aligns: &[Alignment],
+) -> Result<(), Error> {
+    out.write_all(b"|")?;
+    for ((text, &width), &align) in cells.iter().zip(widths).zip(aligns) {
+        let fill = width - display_width(text);
+        let (left, right) = match align {
+            Alignment::RIGHT => (fill, 0),
+            Alignment::CENTER => (fill / 2, fill - fill / 2),
+            _ => (0, fill),
+        };
+        write!(out, " {}{}{} |", " ".repeat(left), text, " ".repeat(right))?;
+    }
+    out.write_all(NEWLINE)
+}
+
+impl<'a> TableSlice<'a> {
+    /// Write the table to `out` as a GitHub flavored Markdown pipe table,
+    /// whatever the format used to print it in a terminal.
+    ///
+    /// The titles are written as the header of the table, which is left empty if there are none,
+    /// and the footer as its last row. Alignment markers are taken from the columns alignment,
+    /// like for [`FORMAT_MARKDOWN`](format/consts/struct.FORMAT_MARKDOWN.html).
+    /// Pipes are escaped, lines of multi-line cells are joined with `<br>`,
+    /// and spanning cells are followed by empty cells
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// use prettytable::format::Alignment;
+    /// # fn main() {
+    /// let mut table = table!(["a|b", "1"], ["c\nd", "22"]);
+    /// table.set_titles(row!["name", "n"]);
+    /// table.set_column_alignment(1, Alignment::RIGHT);
+    /// let mut out = Vec::new();
+    /// table.to_markdown(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap().replace("\r\n", "\n"),
+    ///     "\
+    /// | name   |   n |
+    /// | ------ | --: |
+    /// | a\\|b   |   1 |
+    /// | c<br>d |  22 |
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn to_markdown<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), Error> {
+        self.to_markdown_with_escaper(out, &MarkdownEscaper)
+    }
+
+    /// Write the table to `out` as a GitHub flavored Markdown pipe table, like `to_markdown()`,
+    /// escaping cells content with `escaper` instead of `MarkdownEscaper`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let table = table!(["a|b", "*c*"]);
+    /// let mut out = Vec::new();
+    /// // Also escape emphasis
+    /// let escaper = |s: &str| s.replace('|', "\\|").replace('*', "\\*");
+    /// table.to_markdown_with_escaper(&mut out, &escaper).unwrap();
+    /// assert!(String::from_utf8(out).unwrap().contains("| a\\|b | \\*c\\* |"));
+    /// # }
+    /// ```
+    pub fn to_markdown_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        if let Some(rendering) = self.export_view() {
+            return rendering.slice(self).to_markdown_with_escaper(out, escaper);
+        }
+        let column_num = self.get_column_num();
+        let aligns = self.column_alignments(column_num);
+        let header = match *self.titles {
+            Some(ref titles) => row_cells(titles, column_num, escaper),
+            None => vec![String::new(); column_num],
+        };
+        let rows = self
+            .exported_rows()
+            .map(|r| row_cells(&r, column_num, escaper))
+            .chain(
+                self.footer
+                    .iter()
+                    .map(|r| row_cells(r, column_num, escaper)),
+            )
+            .collect::<Vec<_>>();
+        // Separator needs at least 3 characters to be valid, markers included
+        let widths = (0..column_num)
+            .map(|i| {
+                rows.iter()
+                    .chain(Some(&header))
+                    .map(|cells| display_width(&cells[i]))
+                    .fold(3, usize::max)
+            })
+            .collect::<Vec<_>>();
+        write_line(out, &header, &widths, &aligns)?;
+        let separator = widths
+            .iter()
+            .zip(&aligns)
+            .map(|(&width, &align)| match align {
+                Alignment::CENTER => format!(":{}:", "-".repeat(width - 2)),
+                Alignment::RIGHT => format!("{}:", "-".repeat(width - 1)),
+                _ => "-".repeat(width),
+            })
+            .collect::<Vec<_>>();
+        write_line(out, &separator, &widths, &aligns)?;
+        for cells in &rows {
+            write_line(out, cells, &widths, &aligns)?;
+        }
+        out.flush()
+    }
+}
+
+impl Table {
+    /// Write the table to `out` as a GitHub flavored Markdown pipe table.
+    /// See [`TableSlice::to_markdown`](struct.TableSlice.html#method.to_markdown) for details
+    pub fn to_markdown<T: Write + ?Sized>(&self, out: &mut T) -> Result<(), Error> {
+        self.as_slice().to_markdown(out)
+    }
+
+    /// Write the table to `out` as a GitHub flavored Markdown pipe table, escaping cells
+    /// content with `escaper`.
+    /// See [`TableSlice::to_markdown_with_escaper`](struct.TableSlice.html#method.to_markdown_with_escaper)
+    /// for details
+    pub fn to_markdown_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        self.as_slice().to_markdown_with_escaper(out, escaper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::escape::NoEscaper;
+    use crate::format::consts::FORMAT_BOX_CHARS;
+    use crate::format::Alignment;
+    use crate::utils::StringWriter;
+    use crate::{row, table};
+
+    #[test]
+    fn to_markdown() {
+        let mut table = table!([c->"a", "b\\c"], [H2->"span"], ["x"]);
+        table.set_format(*FORMAT_BOX_CHARS);
+        let mut out = StringWriter::new();
+        table.to_markdown(&mut out).unwrap();
+        let expected = "\
+|      |      |
+| :--: | ---- |
+|  a   | b\\\\c |
+| span |      |
+|  x   |      |
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        table.set_titles(row!["t1", "t2"]);
+        table.set_footer(row!["f1", r->"f2"]);
+        table.set_column_alignment(1, Alignment::RIGHT);
+        table.set_column_hidden(0, true);
+        let mut out = StringWriter::new();
+        table.to_markdown(&mut out).unwrap();
+        let expected = "\
+|   t2 |
+| ---: |
+| b\\\\c |
+| span |
+|      |
+|   f2 |
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+
+        let mut out = StringWriter::new();
+        table
+            .to_markdown_with_escaper(&mut out, &NoEscaper)
+            .unwrap();
+        assert!(out.as_string().contains("|  b\\c |"));
+    }
+}
@@ -0,0 +1,398 @@
+//! This module contains the views through which tables are printed and exported,
+//! transforming their rows one by one instead of copying the whole table
+use super::format::{self, Alignment, TableFormat};
+use super::{constrain_row, Cell, ColumnSettings, Row, Settings, Table, TableSlice};
+
+/// Transformations applied to the data rows of a slice while it's rendered
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub(crate) struct RowView {
+    /// Grid columns kept, in this order, or `None` to keep all of them
+    columns: Option<Vec<usize>>,
+    /// Whether a leading column numbers the rows
+    numbers: bool,
+    /// Grid columns in reverse order, when the layout is from right to left
+    reversed: Option<Vec<usize>>,
+    /// Whether the row formats get ASCII borders
+    ascii_borders: bool,
+    /// Width of the columns the cells too wide for them are wrapped or truncated to
+    col_width: Option<Vec<usize>>,
+    /// Whether ambiguous width characters are 2 columns wide when constraining the cells
+    wide_ambiguous: bool,
+}
+
+impl RowView {
+    /// Transform `row`, which is the data row number `count`, with the settings of the `columns`
+    pub(crate) fn apply(&self, row: &Row, count: usize, columns: &[ColumnSettings]) -> Row {
+        let mut row = match self.columns {
+            Some(ref visible) => row.project(visible),
+            None => row.clone(),
+        };
+        if self.numbers {
+            row.insert_column(0, Cell::new(&count.to_string()));
+        }
+        if let Some(ref reversed) = self.reversed {
+            row = row.project(reversed);
+            row.mirror_alignment();
+        }
+        if self.ascii_borders {
+            ascii_row_format(&mut row);
+        }
+        match self.col_width {
+            Some(ref col_width) => constrain_row(&row, col_width, columns, self.wide_ambiguous),
+            None => row,
+        }
+    }
+}
+
+/// Replace the box drawing characters of the format of `row`, if any, with ASCII ones
+fn ascii_row_format(row: &mut Row) {
+    if let Some(format) = row.get_format().map(TableFormat::to_ascii) {
+        row.set_format(format);
+    }
+}
+
+/// The parts of a slice modified to render it. Titles, footer, heading and title groups
+/// are copied and transformed right away, while data rows are only transformed with `rows`
+/// when they're rendered
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub(crate) struct Rendering {
+    format: TableFormat,
+    titles: Option<Row>,
+    footer: Option<Row>,
+    settings: Settings,
+    rows: RowView,
+}
+
+impl Rendering {
+    /// Start rendering `slice` as is
+    pub(crate) fn new(slice: &TableSlice) -> Rendering {
+        Rendering {
+            format: *slice.format,
+            titles: slice.titles.clone(),
+            footer: slice.footer.clone(),
+            settings: slice.owned_settings(),
+            rows: RowView::default(),
+        }
+    }
+
+    /// Get a slice over the rows of `slice`, the slice this rendering was started with,
+    /// rendered with the modified parts
+    pub(crate) fn slice<'b>(&'b self, slice: &TableSlice<'b>) -> TableSlice<'b> {
+        TableSlice {
+            format: &self.format,
+            titles: &self.titles,
+            rows: slice.rows,
+            footer: &self.footer,
+            settings: &self.settings,
+            selection: slice.selection,
+            columns: None,
+            view: Some(&self.rows).filter(|&rows| *rows != RowView::default()),
+        }
+    }
+
+    /// Get the transformations applied to the data rows
+    pub(crate) fn row_view(&self) -> &RowView {
+        &self.rows
+    }
+
+    /// Build a table from the modified parts, without any row
+    pub(crate) fn into_table(self) -> Table {
+        Table {
+            format: Box::new(self.format),
+            titles: Box::new(self.titles),
+            rows: Vec::new(),
+            footer: Box::new(self.footer),
+            settings: Box::new(self.settings),
+        }
+    }
+
+    /// Compute the aggregates of `slice` in the footer.
+    /// Return `false` if there's no aggregate
+    pub(crate) fn add_aggregates(&mut self, slice: &TableSlice) -> bool {
+        match slice.aggregated_footer() {
+            Some(footer) => {
+                self.footer = Some(footer);
+                self.settings.aggregates.clear();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Keep the columns which are hidden when they're empty, which can't be known
+    /// before all the rows are available
+    pub(crate) fn keep_empty_columns(&mut self) {
+        self.settings.hide_empty_columns = false;
+        for column in &mut self.settings.columns {
+            column.hide_if_empty = false;
+        }
+    }
+
+    /// Leave out the columns of `slice` which must be hidden when printing or exporting.
+    /// Return `false` if all the columns are visible
+    pub(crate) fn hide_columns(&mut self, slice: &TableSlice) -> bool {
+        let visible = match self.slice(slice).visible_columns() {
+            Some(visible) => visible,
+            None => return false,
+        };
+        let settings = &mut self.settings;
+        settings.columns = visible
+            .iter()
+            .map(|&i| settings.columns.get(i).cloned().unwrap_or_default())
+            .collect();
+        for group in &mut settings.title_groups {
+            *group = group.project(&visible);
+        }
+        self.titles = self.titles.as_ref().map(|r| r.project(&visible));
+        self.footer = self.footer.as_ref().map(|r| r.project(&visible));
+        self.rows.columns = Some(visible);
+        true
+    }
+
+    /// Add a leading column numbering the rows from 1, if enabled.
+    /// Full width rows are not numbered.
+    /// Return `false` if row numbers are not enabled
+    pub(crate) fn add_row_numbers(&mut self) -> bool {
+        let settings = &mut self.settings;
+        if !settings.row_numbers {
+            return false;
+        }
+        settings.row_numbers = false;
+        if let Some(ref mut titles) = self.titles {
+            titles.insert_column(0, Cell::new("#"));
+        }
+        for row in settings.title_groups.iter_mut().chain(self.footer.as_mut()) {
+            row.insert_column(0, Cell::default());
+        }
+        if settings.columns.is_empty() {
+            settings.columns.push(ColumnSettings::default());
+        } else {
+            settings.columns.insert(0, ColumnSettings::default());
+        }
+        settings.columns[0].align = Some(Alignment::RIGHT);
+        self.rows.numbers = true;
+        true
+    }
+
+    /// Lay out the columns of `slice` from right to left, in reverse order, with the padding
+    /// swapped, and left and right alignments exchanged, if enabled.
+    /// Return `false` if the right-to-left layout is not enabled
+    pub(crate) fn lay_out_right_to_left(&mut self, slice: &TableSlice) -> bool {
+        if !self.settings.right_to_left {
+            return false;
+        }
+        let column_num = self.slice(slice).get_column_num();
+        let reversed = (0..column_num).rev().collect::<Vec<_>>();
+        let (left, right) = self.format.get_padding();
+        self.format.padding(right, left);
+        let settings = &mut self.settings;
+        settings.right_to_left = false;
+        settings.titles_align = settings.titles_align.map(Alignment::mirror);
+        settings.columns = reversed
+            .iter()
+            .map(|&i| settings.columns.get(i).cloned().unwrap_or_default())
+            .collect();
+        for column in &mut settings.columns {
+            column.align = Some(column.align.map_or(Alignment::RIGHT, Alignment::mirror));
+        }
+        for group in &mut settings.title_groups {
+            *group = group.project(&reversed);
+        }
+        self.titles = self.titles.as_ref().map(|r| r.project(&reversed));
+        self.footer = self.footer.as_ref().map(|r| r.project(&reversed));
+        let rows = self
+            .titles
+            .iter_mut()
+            .chain(self.footer.iter_mut())
+            .chain(settings.heading.iter_mut())
+            .chain(settings.title_groups.iter_mut());
+        for row in rows {
+            row.mirror_alignment();
+        }
+        self.rows.reversed = Some(reversed);
+        true
+    }
+
+    /// Replace the box drawing characters of the format, of the formats of the rows
+    /// and of the title underlines with ASCII ones, if enabled.
+    /// Return `false` if ASCII borders are not enabled
+    pub(crate) fn use_ascii_borders(&mut self) -> bool {
+        let settings = &mut self.settings;
+        if !settings.ascii_borders {
+            return false;
+        }
+        settings.ascii_borders = false;
+        for column in &mut settings.columns {
+            column.title_line = column.title_line.map(format::ascii_border);
+        }
+        self.format = self.format.to_ascii();
+        let rows = self
+            .titles
+            .iter_mut()
+            .chain(self.footer.iter_mut())
+            .chain(settings.heading.iter_mut())
+            .chain(settings.title_groups.iter_mut());
+        for row in rows {
+            ascii_row_format(row);
+        }
+        self.rows.ascii_borders = true;
+        true
+    }
+
+    /// Wrap or truncate the cells of `slice` too wide for the maximum width of their column,
+    /// or for the maximum width of the table.
+    /// Return `false` if all the cells fit.
+    /// Spanning cells and full width rows are left untouched
+    pub(crate) fn constrain_widths(&mut self, slice: &TableSlice) -> bool {
+        let columns = &self.settings.columns;
+        if self.settings.max_width.is_none() && columns.iter().all(|c| c.max_width.is_none()) {
+            return false;
+        }
+        let rendered = self.slice(slice);
+        // Constrained columns are narrower than their widest cell
+        let col_width = rendered.get_all_column_width();
+        let wide = self.format.is_ambiguous_wide();
+        let max_width = |column: usize| col_width.get(column).copied().unwrap_or(usize::MAX);
+        let too_wide = |row: &Row| {
+            let mut column = 0;
+            row.iter().any(|c| {
+                let max = max_width(column);
+                column += c.get_hspan();
+                c.get_hspan() == 1 && c.get_width_with(wide) > max
+            })
+        };
+        if !rendered
+            .all_rows()
+            .any(|r| !r.is_full_width() && too_wide(&r))
+        {
+            return false;
+        }
+        let settings = &mut self.settings;
+        let columns = &settings.columns;
+        let constrain = |row: &Row| constrain_row(row, &col_width, columns, wide);
+        settings.title_groups = settings.title_groups.iter().map(constrain).collect();
+        self.titles = self.titles.as_ref().map(constrain);
+        self.footer = self.footer.as_ref().map(constrain);
+        self.rows.col_width = Some(col_width);
+        self.rows.wide_ambiguous = wide;
+        true
+    }
+}
+
+impl<'a> TableSlice<'a> {
+    /// Build the parts of this slice modified to export it: the footer with the aggregates,
+    /// and the columns which are not hidden.
+    /// Return `None` if the slice is exported as is
+    pub(crate) fn export_view(&self) -> Option<Rendering> {
+        if self.view.is_some() {
+            return None;
+        }
+        let mut rendering = Rendering::new(self);
+        let aggregates = rendering.add_aggregates(self);
+        let hidden = rendering.hide_columns(self);
+        Some(rendering).filter(|_| aggregates || hidden)
+    }
+
+    /// Build the parts of this slice modified to print it: like for `export_view()`,
+    /// with the row numbers, the right-to-left layout, ASCII borders and cells constrained
+    /// to the width of their column.
+    /// Return `None` if the slice is printed as is
+    pub(crate) fn print_view(&self) -> Option<Rendering> {
+        if self.view.is_some() {
+            return None;
+        }
+        let mut rendering = Rendering::new(self);
+        // Each step applies to the result of the previous ones
+        let steps = [
+            rendering.add_aggregates(self),
+            rendering.hide_columns(self),
+            rendering.add_row_numbers(),
+            rendering.lay_out_right_to_left(self),
+            rendering.use_ascii_borders(),
+            rendering.constrain_widths(self),
+        ];
+        Some(rendering).filter(|_| steps.contains(&true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::consts::FORMAT_BOX_CHARS;
+    use crate::utils::StringWriter;
+    use crate::{row, table, Aggregate, ColumnWidth, Slice};
+
+    #[test]
+    fn print_view() {
+        let mut table = table!(
+            ["1", "apple", "x", "red fruit of trees"],
+            [H4->"fruits"],
+            ["22", "pear", "y", "green"]
+        );
+        table.set_titles(row!["n", "name", "hidden", "notes"]);
+        table.set_footer(row!["", "total"]);
+        table.set_aggregate_footer(&[Aggregate::Sum(0)]);
+        table.set_column_hidden(2, true);
+        table.set_row_numbers(true);
+        table.set_right_to_left(true);
+        table.set_ascii_borders(true);
+        table.set_format(*FORMAT_BOX_CHARS);
+        table.set_column_width(3, ColumnWidth::Max(8));
+        table.set_column_wrap(3, true);
+        let expected = "\
++----------+-------+----+---+
+|    notes |  name |  n | # |
++----------+-------+----+---+
+|      red | apple |  1 | 1 |
+| fruit of |       |    |   |
+|    trees |       |    |   |
++----------+-------+----+---+
+|                fruits | 2 |
++----------+-------+----+---+
+|    green |  pear | 22 | 3 |
++----------+-------+----+---+
+|          | total | 23 |   |
++----------+-------+----+---+
+";
+        assert_eq!(table.to_string().replace("\r\n", "\n"), expected);
+        let expected = "\
++-------+-------+----+---+
+| notes |  name |  n | # |
++-------+-------+----+---+
+|             fruits | 1 |
++-------+-------+----+---+
+| green |  pear | 22 | 2 |
++-------+-------+----+---+
+|       | total | 22 |   |
++-------+-------+----+---+
+";
+        assert_eq!(table.slice(1..).to_string().replace("\r\n", "\n"), expected);
+        let mut out = StringWriter::new();
+        table.print_snipped(&mut out, 1, 0).unwrap();
+        let expected = "\
++----------+-------+----+---+
+|    notes |  name |  n | # |
++----------+-------+----+---+
+|      red | apple |  1 | 1 |
+| fruit of |       |    |   |
+|    trees |       |    |   |
++----------+-------+----+---+
+|        … 2 rows omitted … |
++----------+-------+----+---+
+|          | total | 23 |   |
++----------+-------+----+---+
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+        // Exports only compute the aggregates and hide columns
+        let mut out = StringWriter::new();
+        table.to_markdown(&mut out).unwrap();
+        let expected = "\
+| n      | name  | notes              |
+| ------ | ----- | ------------------ |
+| 1      | apple | red fruit of trees |
+| fruits |       |                    |
+| 22     | pear  | green              |
+| 23     | total |                    |
+";
+        assert_eq!(out.as_string().replace("\r\n", "\n"), expected);
+    }
+}
@@ -1,31 +1,96 @@
 //! This module contains definition of table rows stuff
 use std::io::{Error, Write};
 use std::iter::FromIterator;
+use std::ops::{Deref, Index, IndexMut};
 use std::slice::{Iter, IterMut};
-// use std::vec::IntoIter;
-use std::ops::{Index, IndexMut};
+use std::vec::IntoIter;
 
-use super::Terminal;
+use super::{Attr, Terminal};
 
-use super::format::{ColumnPosition, TableFormat};
-use super::utils::NEWLINE;
-use super::Cell;
+use super::cell::Inherited;
+use super::escape::{Escaper, HtmlEscaper};
+use super::format::{Alignment, BorderPrinter, ColumnPosition, Plain, Styled, TableFormat};
+use super::html::HtmlOptions;
+use super::{Cell, ColumnRef, Settings, Table};
 
 /// Represent a table row made of cells
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Row {
     cells: Vec<Cell>,
+    align: Option<Alignment>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::attrs"))]
+    style: Vec<Attr>,
+    separator_after: Option<bool>,
+    group_header: bool,
+    separator: bool,
+    full_width: bool,
+    hidden: bool,
+    header: bool,
+    format: Option<Box<TableFormat>>,
 }
 
 impl Row {
     /// Create a new `Row` backed with `cells` vector
     pub fn new(cells: Vec<Cell>) -> Row {
-        Row { cells }
+        Row {
+            cells,
+            align: None,
+            style: Vec::new(),
+            separator_after: None,
+            group_header: false,
+            separator: false,
+            full_width: false,
+            hidden: false,
+            header: false,
+            format: None,
+        }
+    }
+
+    /// Create a row made of a single cell containing `content`,
+    /// which spans the whole table width when printed
+    pub fn full_width(content: &str) -> Row {
+        let mut row = Row::new(vec![Cell::new(content)]);
+        row.full_width = true;
+        row
+    }
+
+    /// Check if the row spans the whole table width, like rows created with `Row::full_width`
+    pub fn is_full_width(&self) -> bool {
+        self.full_width
+    }
+
+    /// Create a group header row, labelled with `label`.
+    /// When printed, its single cell spans the whole table width
+    pub fn group_header(label: &str) -> Row {
+        let mut row = Row::full_width(label);
+        row.group_header = true;
+        row
+    }
+
+    /// Check if the row is a group header, created with `Row::group_header`
+    pub fn is_group_header(&self) -> bool {
+        self.group_header
+    }
+
+    /// Create a separator row, printed as a horizontal rule with the internal line separator
+    /// of its format, even if the format doesn't separate rows. No other line separator
+    /// is printed right before or after it.
+    /// Separator rows are left out of the exports having no horizontal rules
+    pub fn separator() -> Row {
+        let mut row = Row::full_width("");
+        row.separator = true;
+        row
+    }
+
+    /// Check if the row is a separator row, created with `Row::separator`
+    pub fn is_separator(&self) -> bool {
+        self.separator
     }
 
     /// Create an row of length `size`, with empty strings stored
     pub fn empty() -> Row {
-        Self::new(vec![Cell::default(); 0])
+        Self::new(Vec::new())
     }
 
     /// Count the number of column required in the table grid.
@@ -49,7 +114,7 @@ impl Row {
 
     /// Get the height of this row
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
-    fn get_height(&self) -> usize {
+    pub(crate) fn get_height(&self) -> usize {
         let mut height = 1; // Minimum height must be 1 to print empty rows
         for cell in &self.cells {
             let h = cell.get_height();
@@ -64,19 +129,25 @@ impl Row {
     /// Return 0 if the cell does not exist in this row
     // #[deprecated(since="0.8.0", note="Will become private in future release. See [issue #87](https://github.com/phsym/prettytable-rs/issues/87)")]
     pub(crate) fn get_column_width(&self, column: usize, format: &TableFormat) -> usize {
+        let format = self.get_format().unwrap_or(format);
+        if self.full_width {
+            // Full width rows span the whole table, their width is handled separately
+            return 0;
+        }
+        let wide = format.is_ambiguous_wide();
         let mut i = 0;
         for c in &self.cells {
             if i + c.get_hspan() > column {
                 if c.get_hspan() == 1 {
-                    return c.get_width();
+                    return c.get_width_with(wide);
                 }
-                let (lp, rp) = format.get_padding();
+                let (lp, rp) = format.get_padding_width();
                 let sep = format
                     .get_column_separator(ColumnPosition::Intern)
                     .map(|_| 1)
                     .unwrap_or_default();
                 let rem = lp + rp + sep;
-                let mut w = c.get_width();
+                let mut w = c.get_width_with(wide);
                 if w > rem {
                     w -= rem;
                 } else {
@@ -89,20 +160,151 @@ impl Row {
         0
     }
 
+    /// Get the index of the cell covering the grid column `column`,
+    /// taking horizontal spanning into account
+    fn cell_index_at_column(&self, column: usize) -> Option<usize> {
+        let mut i = 0;
+        for (idx, c) in self.cells.iter().enumerate() {
+            i += c.get_hspan();
+            if i > column {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    /// Get the cell starting at the grid column `column`.
+    /// Return `None` if the column is covered by a spanning cell starting before it,
+    /// or if the row is too short
+    pub(crate) fn get_cell_at_column(&self, column: usize) -> Option<&Cell> {
+        let mut start = 0;
+        for cell in &self.cells {
+            if start == column {
+                return Some(cell);
+            }
+            start += cell.get_hspan();
+            if start > column {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Insert `cell` at the grid column `column`, padding the row with empty cells if it's shorter.
+    /// A cell spanning over `column` is shifted right. Full width rows are left untouched
+    pub(crate) fn insert_column(&mut self, column: usize, cell: Cell) {
+        if self.full_width {
+            return;
+        }
+        while self.column_count() < column {
+            self.cells.push(Cell::default());
+        }
+        match self.cell_index_at_column(column) {
+            Some(idx) => self.cells.insert(idx, cell),
+            None => self.cells.push(cell),
+        }
+    }
+
+    /// Remove the grid column `column`. A cell spanning over it loses one column of span instead.
+    /// Full width rows are left untouched
+    pub(crate) fn remove_column(&mut self, column: usize) {
+        if self.full_width {
+            return;
+        }
+        if let Some(idx) = self.cell_index_at_column(column) {
+            let hspan = self.cells[idx].get_hspan();
+            if hspan > 1 {
+                self.cells[idx].set_hspan(hspan - 1);
+            } else {
+                self.cells.remove(idx);
+            }
+        }
+    }
+
+    /// Build a copy of this row keeping only the grid columns in `columns`, in the given order.
+    /// A spanning cell covering several of the kept columns keeps spanning over them
+    pub(crate) fn project(&self, columns: &[usize]) -> Row {
+        let mut row = self.clone_settings();
+        if self.full_width {
+            row.cells = self.cells.clone();
+            return row;
+        }
+        let mut last = None;
+        let mut len = 0; // Number of cells to keep, so that trailing missing cells are not added
+        for &col in columns {
+            match self.cell_index_at_column(col) {
+                Some(idx) if last == Some(idx) => {
+                    let cell = row.cells.last_mut().unwrap();
+                    cell.set_hspan(cell.get_hspan() + 1);
+                }
+                Some(idx) => {
+                    row.cells.push(self.cells[idx].clone().with_hspan(1));
+                    len = row.cells.len();
+                    last = Some(idx);
+                }
+                None => {
+                    row.cells.push(Cell::default());
+                    last = None;
+                }
+            }
+        }
+        row.cells.truncate(len);
+        row
+    }
+
+    /// Copy this row's settings, with no cell
+    fn clone_settings(&self) -> Row {
+        Row {
+            cells: Vec::new(),
+            align: self.align,
+            style: self.style.clone(),
+            separator_after: self.separator_after,
+            group_header: self.group_header,
+            separator: self.separator,
+            full_width: self.full_width,
+            hidden: self.hidden,
+            header: self.header,
+            format: self.format.clone(),
+        }
+    }
+
     /// Get the cell at index `idx`
     pub fn get_cell(&self, idx: usize) -> Option<&Cell> {
         self.cells.get(idx)
     }
 
+    /// Get the cell in the column whose title in `table` is `name`.
+    /// It keeps code readable, and robust to columns being reordered.
+    /// Rows obtained with `Table::named_rows()` can also be indexed with `row["hostname"]`
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # fn main() {
+    /// let mut table = table!(["web-1", "10.0.0.1"]);
+    /// table.set_titles(row!["hostname", "ip"]);
+    /// for row in &table {
+    ///     println!("{}", row.get_cell_by_name(&table, "hostname").unwrap().get_content());
+    /// }
+    /// # }
+    /// ```
+    pub fn get_cell_by_name(&self, table: &Table, name: &str) -> Option<&Cell> {
+        self.get_cell(table.column_index(name)?)
+    }
+
     /// Get the mutable cell at index `idx`
     pub fn get_mut_cell(&mut self, idx: usize) -> Option<&mut Cell> {
         self.cells.get_mut(idx)
     }
 
-    /// Set the `cell` in the row at the given `idx` index
-    pub fn set_cell(&mut self, cell: Cell, idx: usize) -> Result<(), &str> {
+    /// Set the `cell` in the row at the given `idx` index.
+    /// Fails with `Error::OutOfBounds` if there's no cell at this index
+    pub fn set_cell(&mut self, cell: Cell, idx: usize) -> Result<(), crate::Error> {
         if idx >= self.len() {
-            return Err("Cannot find cell");
+            return Err(crate::Error::OutOfBounds {
+                index: idx,
+                len: self.len(),
+            });
         }
         self.cells[idx] = cell;
         Ok(())
@@ -113,6 +315,14 @@ impl Row {
         self.cells.push(cell);
     }
 
+    /// Append all the cells yielded by `cells` at the end of the row, keeping their style.
+    ///
+    /// `Row` implements `Extend` for any `ToString` item, which includes `Cell`,
+    /// but it rebuilds cells from their content. Use this method to append styled cells.
+    pub fn extend_from_iter<I: IntoIterator<Item = Cell>>(&mut self, cells: I) {
+        self.cells.extend(cells);
+    }
+
     /// Insert `cell` at position `index`. If `index` is higher than the row length,
     /// the cell will be appended at the end
     pub fn insert_cell(&mut self, index: usize, cell: Cell) {
@@ -130,66 +340,287 @@ impl Row {
         }
     }
 
-    /// Returns an immutable iterator over cells
-    pub fn iter(&self) -> Iter<Cell> {
+    /// Set the default text alignment of the cells in this row.
+    /// Cells with their own alignment are not affected
+    pub fn align(&mut self, align: Alignment) {
+        self.align = Some(align);
+    }
+
+    /// Add a default style attribute to all the cells in this row.
+    /// Cells' own style attributes take precedence
+    pub fn style(&mut self, attr: Attr) {
+        self.style.push(attr);
+    }
+
+    /// Add a default style attribute to all the cells in this row. Can be chained
+    pub fn with_style(mut self, attr: Attr) -> Row {
+        self.style(attr);
+        self
+    }
+
+    /// Remove all the row's default style attributes and alignment.
+    /// Cells' own style is not modified
+    pub fn reset_style(&mut self) {
+        self.style.clear();
+        self.align = None;
+    }
+
+    /// Check if `attr` is part of the row's default style, or of one of its cells' style
+    pub fn has_style(&self, attr: Attr) -> bool {
+        self.style.contains(&attr) || self.cells.iter().any(|c| c.get_style().contains(&attr))
+    }
+
+    /// Set the row's default style by applying the given specifier string.
+    /// Cells' own style attributes and alignment take precedence over it.
+    ///
+    /// See [`Cell::style_spec`](struct.Cell.html#method.style_spec) for the specifier syntax.
+    /// Horizontal spanning specifiers are ignored
+    pub fn style_spec(mut self, spec: &str) -> Row {
+        let spec = Cell::default().style_spec(spec);
+        self.style = spec.get_style().to_vec();
+        self.align = spec.get_explicit_alignment();
+        self
+    }
+
+    /// Force (`true`) or suppress (`false`) the internal line separator printed after this row,
+    /// whatever the table format says. If the format has no internal line separator,
+    /// a forced one is printed using the bottom separator, or the default one.
+    pub fn set_separator_after(&mut self, separator: bool) {
+        self.separator_after = Some(separator);
+    }
+
+    /// Let the table format decide whether a line separator is printed after this row
+    pub fn unset_separator_after(&mut self) {
+        self.separator_after = None;
+    }
+
+    /// Return whether a line separator is forced (`Some(true)`) or suppressed (`Some(false)`)
+    /// after this row, or `None` if it depends on the table format
+    pub fn get_separator_after(&self) -> Option<bool> {
+        self.separator_after
+    }
+
+    /// Hide or show the row. A hidden row is kept in the table,
+    /// but is skipped when printing or exporting it
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    /// Check if the row is hidden
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Set a format used for this row instead of the table's one, like other column separators
+    /// for a total row. It's also used for the line separator printed above the row.
+    /// Columns width is shared by all the rows, so it's better to keep the padding
+    /// and the presence of column separators of the table format, for the row to line up
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::{consts::FORMAT_DEFAULT, FormatBuilder, LinePosition, LineSeparator};
+    /// # fn main() {
+    /// let mut table = table!(["apple", 3], ["pear", 2]);
+    /// let total = FormatBuilder::from(*FORMAT_DEFAULT)
+    ///     .borders('*')
+    ///     .separator(LinePosition::Intern, LineSeparator::new('=', '*', '*', '*'))
+    ///     .build();
+    /// table.add_row(row!["total", 5]).set_format(total);
+    /// assert_eq!(
+    ///     table.to_string().replace("\r\n", "\n"),
+    ///     "\
+    /// +-------+---+
+    /// | apple | 3 |
+    /// +-------+---+
+    /// | pear  | 2 |
+    /// *=======*===*
+    /// * total | 5 *
+    /// +-------+---+
+    /// "
+    /// );
+    /// # }
+    /// ```
+    pub fn set_format(&mut self, format: TableFormat) {
+        self.format = Some(Box::new(format));
+    }
+
+    /// Remove the format of this row, which is then printed with the table format
+    pub fn unset_format(&mut self) {
+        self.format = None;
+    }
+
+    /// Get the format used for this row instead of the table's one, if any
+    pub fn get_format(&self) -> Option<&TableFormat> {
+        self.format.as_deref()
+    }
+
+    /// Exchange the left and right alignments of this row and of its cells,
+    /// for right-to-left layouts. Full width rows without alignment,
+    /// which don't inherit the column ones, are aligned right
+    pub(crate) fn mirror_alignment(&mut self) {
+        self.align = self.align.map(Alignment::mirror);
+        if self.full_width && self.align.is_none() {
+            self.align = Some(Alignment::RIGHT);
+        }
+        for cell in &mut self.cells {
+            if let Some(align) = cell.get_explicit_alignment() {
+                cell.align(align.mirror());
+            }
+        }
+    }
+
+    /// Mark the row as being part of the table header, whose cells' links are printed
+    pub(crate) fn set_header(&mut self, header: bool) {
+        self.header = header;
+    }
+
+    /// Returns an immutable iterator over cells. `Row` can't be iterated by value,
+    /// as it would conflict with `From<T: IntoIterator>`: use `into_cells()` instead
+    pub fn iter(&self) -> Iter<'_, Cell> {
         self.cells.iter()
     }
 
     /// Returns an mutable iterator over cells
-    pub fn iter_mut(&mut self) -> IterMut<Cell> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, Cell> {
         self.cells.iter_mut()
     }
 
+    /// Consume the row and return an iterator over its cells,
+    /// so they can be moved without being cloned
+    pub fn into_cells(self) -> IntoIter<Cell> {
+        self.cells.into_iter()
+    }
+
+    /// Style and alignment inherited by the cell of this row starting at the grid column `column`,
+    /// from the table and column defaults in `settings`, and from this row.
+    /// Full width rows don't inherit any column default, and header rows
+    /// are aligned like titles before being aligned like their column
+    pub(crate) fn inherited<'a>(&'a self, settings: &'a Settings, column: usize) -> Inherited<'a> {
+        let column = settings.columns.get(column).filter(|_| !self.full_width);
+        let titles_align = settings.titles_align.filter(|_| self.header);
+        Inherited {
+            align: self
+                .align
+                .or(titles_align)
+                .or_else(|| column.and_then(|c| c.align)),
+            style: [
+                &settings.style,
+                column.map(|c| &c.style[..]).unwrap_or(&[]),
+                &self.style,
+            ],
+            links: self.header,
+            wide_ambiguous: false,
+            fill: None,
+        }
+    }
+
     /// Internal only
-    fn __print<T: Write + ?Sized, F>(
+    fn __print<T: Write + ?Sized, F, B>(
         &self,
         out: &mut T,
         format: &TableFormat,
         col_width: &[usize],
+        settings: &Settings,
         f: F,
+        borders: &B,
     ) -> Result<usize, Error>
     where
-        F: Fn(&Cell, &mut T, usize, usize, bool) -> Result<(), Error>,
+        F: Fn(&Cell, &mut T, usize, usize, bool, &Inherited) -> Result<(), Error>,
+        B: BorderPrinter<T>,
     {
+        let format = self.get_format().unwrap_or(format);
+        if self.separator {
+            return format.print_forced_intern_separator(out, col_width, borders);
+        }
         let height = self.get_height();
+        // Without right border, lines may have to stop at their last visible character
+        let trim = format.get_trim_trailing_whitespace()
+            && format.get_column_separator(ColumnPosition::Right).is_none();
         for i in 0..height {
+            // Number of cells to print on this line. Without column separator,
+            // trailing empty cells would only print spaces
+            let end = match format.get_column_separator(ColumnPosition::Intern) {
+                None if trim => self
+                    .cells
+                    .iter()
+                    .rposition(|c| !c.get_line(i).is_empty())
+                    .map_or(0, |p| p + 1),
+                _ => usize::MAX,
+            };
+            if end == 0 && format.get_column_separator(ColumnPosition::Left).is_none() {
+                out.write_all(format.newline_bytes())?;
+                continue;
+            }
             //TODO: Wrap this into dedicated function one day
             out.write_all(&vec![b' '; format.get_indent()])?;
-            format.print_column_separator(out, ColumnPosition::Left)?;
+            format.print_column_separator(out, ColumnPosition::Left, borders)?;
             let (lp, rp) = format.get_padding();
+            let (lw, rw) = format.get_padding_width();
             let mut j = 0;
             let mut hspan = 0; // The additional offset caused by cell's horizontal spanning
-            while j + hspan < col_width.len() {
-                out.write_all(&vec![b' '; lp])?; // Left padding
-                                                 // skip_r_fill skip filling the end of the last cell if there's no character
-                                                 // delimiting the end of the table
-                let skip_r_fill = (j == col_width.len() - 1)
-                    && format.get_column_separator(ColumnPosition::Right).is_none();
-                match self.get_cell(j) {
+            while j + hspan < col_width.len() && j < end {
+                let cell = self.get_cell(j);
+                // Spans are limited to the remaining columns
+                let span = match cell {
+                    Some(_) if self.full_width => col_width.len(),
+                    Some(c) => c.get_hspan(),
+                    None => 1,
+                }
+                .min(col_width.len() - j - hspan);
+                // Last cell printed on this line when trimming
+                let last = trim && (j + hspan + span >= col_width.len() || j + 1 == end);
+                let skip = last && cell.map(|c| c.get_line(i)).unwrap_or("").is_empty();
+                if !skip {
+                    format.print_padding(out, lp)?; // Left padding
+                }
+                // skip_r_fill skip filling the end of the last cell if there's no character
+                // delimiting the end of the table
+                let skip_r_fill = last
+                    || (j == col_width.len() - 1)
+                        && format.get_column_separator(ColumnPosition::Right).is_none();
+                let inherited = Inherited {
+                    wide_ambiguous: format.is_ambiguous_wide(),
+                    fill: Some(format.get_padding_char()),
+                    ..self.inherited(settings, j + hspan)
+                };
+                match cell {
                     Some(c) => {
                         // In case of horizontal spanning, width is the sum of all spanned columns' width
-                        let mut w = col_width[j + hspan..j + hspan + c.get_hspan()].iter().sum();
-                        let real_span = c.get_hspan() - 1;
-                        w += real_span * (lp + rp)
+                        let mut w = col_width[j + hspan..j + hspan + span].iter().sum();
+                        let real_span = span - 1;
+                        w += real_span * (lw + rw)
                             + real_span
                                 * format
                                     .get_column_separator(ColumnPosition::Intern)
                                     .map(|_| 1)
                                     .unwrap_or_default();
                         // Print cell content
-                        f(c, out, i, w, skip_r_fill)?;
+                        if !skip {
+                            f(c, out, i, w, skip_r_fill, &inherited)?;
+                        }
                         hspan += real_span; // Add span to offset
                     }
-                    None => f(&Cell::default(), out, i, col_width[j + hspan], skip_r_fill)?,
+                    None if !skip => f(
+                        &Cell::default(),
+                        out,
+                        i,
+                        col_width[j + hspan],
+                        skip_r_fill,
+                        &inherited,
+                    )?,
+                    None => (),
                 };
-                out.write_all(&vec![b' '; rp])?; // Right padding
+                if !last {
+                    format.print_padding(out, rp)?; // Right padding
+                }
                 if j + hspan < col_width.len() - 1 {
-                    format.print_column_separator(out, ColumnPosition::Intern)?;
+                    format.print_column_separator(out, ColumnPosition::Intern, borders)?;
                 }
                 j += 1;
             }
-            format.print_column_separator(out, ColumnPosition::Right)?;
-            out.write_all(NEWLINE)?;
+            format.print_column_separator(out, ColumnPosition::Right, borders)?;
+            out.write_all(format.newline_bytes())?;
         }
         Ok(height)
     }
@@ -202,8 +633,9 @@ impl Row {
         out: &mut T,
         format: &TableFormat,
         col_width: &[usize],
+        settings: &Settings,
     ) -> Result<usize, Error> {
-        self.__print(out, format, col_width, Cell::print)
+        self.__print(out, format, col_width, settings, Cell::print, &Plain)
     }
 
     /// Print the row to terminal `out`, with `separator` as column separator, and `col_width`
@@ -214,21 +646,114 @@ impl Row {
         out: &mut T,
         format: &TableFormat,
         col_width: &[usize],
+        settings: &Settings,
     ) -> Result<usize, Error> {
-        self.__print(out, format, col_width, Cell::print_term)
+        self.__print(out, format, col_width, settings, Cell::print_term, &Styled)
+    }
+
+    /// Render the row to `out`, with columns of the given widths laid out according to `format`,
+    /// like when it's printed as part of a table. Returns the number of printed lines.
+    ///
+    /// Together with [`TableFormat::render_separator`](format/struct.TableFormat.html#method.render_separator),
+    /// it allows building custom table-like layouts with the same look as tables.
+    /// Widths may be computed with [`Table::column_widths`](struct.Table.html#method.column_widths).
+    /// Cells in excess, and spans going beyond the last column, are cut
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::format::{consts::FORMAT_DEFAULT, LinePosition};
+    /// # fn main() {
+    /// let widths = [5, 3];
+    /// let mut out = Vec::new();
+    /// FORMAT_DEFAULT.render_separator(&mut out, &widths, LinePosition::Top).unwrap();
+    /// row!["first", "a"].render(&mut out, &FORMAT_DEFAULT, &widths).unwrap();
+    /// row!["b", "c"].render(&mut out, &FORMAT_DEFAULT, &widths).unwrap();
+    /// FORMAT_DEFAULT.render_separator(&mut out, &widths, LinePosition::Bottom).unwrap();
+    /// // +-------+-----+
+    /// // | first | a   |
+    /// // | b     | c   |
+    /// // +-------+-----+
+    /// # }
+    /// ```
+    pub fn render<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        format: &TableFormat,
+        col_width: &[usize],
+    ) -> Result<usize, Error> {
+        self.print(out, format, col_width, &Settings::default())
+    }
+
+    /// Render the row to terminal `out`, applying style when needed.
+    /// See [`render`](#method.render) for details
+    pub fn render_term<T: Terminal + ?Sized>(
+        &self,
+        out: &mut T,
+        format: &TableFormat,
+        col_width: &[usize],
+    ) -> Result<usize, Error> {
+        self.print_term(out, format, col_width, &Settings::default())
     }
 
     /// Print the row in HTML format to `out`.
     ///
     /// If the row is has fewer columns than `col_num`, the row is padded with empty cells.
     pub fn print_html<T: Write + ?Sized>(&self, out: &mut T, col_num: usize) -> Result<(), Error> {
+        self.print_html_with_escaper(out, col_num, &HtmlEscaper)
+    }
+
+    /// Print the row in HTML format to `out`, escaping cells content with `escaper`.
+    ///
+    /// If the row is has fewer columns than `col_num`, the row is padded with empty cells.
+    pub fn print_html_with_escaper<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        col_num: usize,
+        escaper: &E,
+    ) -> Result<(), Error> {
+        self.print_html_with_settings(
+            out,
+            col_num,
+            escaper,
+            &Settings::default(),
+            &HtmlOptions::new(),
+        )
+    }
+
+    /// Print the row in HTML format to `out`, escaping cells content with `escaper`,
+    /// and applying the table and column defaults from `settings` and the HTML `options`
+    pub(crate) fn print_html_with_settings<T: Write + ?Sized, E: Escaper + ?Sized>(
+        &self,
+        out: &mut T,
+        col_num: usize,
+        escaper: &E,
+        settings: &Settings,
+        options: &HtmlOptions,
+    ) -> Result<(), Error> {
+        if self.full_width {
+            let cell = self.get_cell(0).cloned().unwrap_or_default();
+            cell.with_hspan(col_num).print_html_inherited(
+                out,
+                escaper,
+                &self.inherited(settings, 0),
+                options,
+            )?;
+            return Ok(());
+        }
         let mut printed_columns = 0;
         for cell in self.iter() {
-            printed_columns += cell.print_html(out)?;
+            let inherited = self.inherited(settings, printed_columns);
+            printed_columns += cell.print_html_inherited(out, escaper, &inherited, options)?;
         }
         // Pad with empty cells, if target width is not reached
-        for _ in 0..col_num - printed_columns {
-            Cell::default().print_html(out)?;
+        for column in printed_columns..col_num {
+            Cell::default().print_html_inherited(
+                out,
+                escaper,
+                &self.inherited(settings, column),
+                options,
+            )?;
         }
         Ok(())
     }
@@ -280,14 +805,6 @@ impl<'a> IntoIterator for &'a Row {
     }
 }
 
-// impl IntoIterator for Row {
-//     type Item = Cell;
-//     type IntoIter = IntoIter<Cell>;
-//     fn into_iter(self) -> Self::IntoIter {
-//         self.cells.into_iter()
-//     }
-// }
-
 impl<'a> IntoIterator for &'a mut Row {
     type Item = &'a mut Cell;
     type IntoIter = IterMut<'a, Cell>;
@@ -309,6 +826,133 @@ impl<S: ToString> Extend<S> for Row {
 //     }
 // }
 
+/// A builder to create a `Row` cell by cell, as an alternative to the `row!` macro
+/// when rows are assembled dynamically
+///
+/// # Example
+/// ```
+/// # use prettytable::{format::Alignment, RowBuilder};
+/// let row = RowBuilder::new()
+///     .cell("disk")
+///     .styled(93, "Frb")
+///     .aligned("%", Alignment::RIGHT)
+///     .build();
+/// assert_eq!(row.len(), 3);
+/// ```
+#[derive(Default)]
+pub struct RowBuilder {
+    row: Row,
+}
+
+impl RowBuilder {
+    /// Creates a new builder
+    pub fn new() -> RowBuilder {
+        RowBuilder { row: Row::empty() }
+    }
+
+    /// Append a cell containing `value`
+    pub fn cell<T: ToString>(mut self, value: T) -> Self {
+        self.row.add_cell(Cell::new(&value.to_string()));
+        self
+    }
+
+    /// Append a cell containing `value`, styled with the style specifier `spec`.
+    /// See [`Cell::style_spec`](struct.Cell.html#method.style_spec) for the specifier syntax
+    pub fn styled<T: ToString>(mut self, value: T, spec: &str) -> Self {
+        self.row
+            .add_cell(Cell::new(&value.to_string()).style_spec(spec));
+        self
+    }
+
+    /// Append a cell containing `value`, aligned with `align`
+    pub fn aligned<T: ToString>(mut self, value: T, align: Alignment) -> Self {
+        self.row
+            .add_cell(Cell::new_align(&value.to_string(), align));
+        self
+    }
+
+    /// Append an already built `cell`
+    pub fn with_cell(mut self, cell: Cell) -> Self {
+        self.row.add_cell(cell);
+        self
+    }
+
+    /// Return the generated `Row`
+    pub fn build(self) -> Row {
+        self.row
+    }
+}
+
+/// A row of a table bound to the titles of this table, so that its cells can be
+/// looked up by column title, like `row["hostname"]`. Code reading rows stays readable,
+/// and robust to columns being reordered.
+/// It's obtained with [`Table::named_rows`](struct.Table.html#method.named_rows)
+/// or [`Table::named_row`](struct.Table.html#method.named_row), and dereferences to the `Row`
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// # fn main() {
+/// let mut table = table!(["web-1", "10.0.0.1"], ["web-2", "10.0.0.2"]);
+/// table.set_titles(row!["hostname", "ip"]);
+/// for row in table.named_rows() {
+///     println!("{} is at {}", row["hostname"].get_content(), row["ip"].get_content());
+/// }
+/// assert_eq!(table.named_row(1).unwrap()["ip"].get_content(), "10.0.0.2");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NamedRow<'a> {
+    row: &'a Row,
+    titles: Option<&'a Row>,
+}
+
+impl<'a> NamedRow<'a> {
+    pub(crate) fn new(row: &'a Row, titles: Option<&'a Row>) -> NamedRow<'a> {
+        NamedRow { row, titles }
+    }
+
+    /// Get the cell in the column whose title is `name`,
+    /// or `None` if there's no such column or no cell in it
+    pub fn get(&self, name: &str) -> Option<&'a Cell> {
+        let column = ColumnRef::Name(name).position(self.titles)?;
+        self.row.get_cell(column)
+    }
+
+    /// Get the row
+    pub fn row(&self) -> &'a Row {
+        self.row
+    }
+}
+
+impl<'a> Deref for NamedRow<'a> {
+    type Target = Row;
+    fn deref(&self) -> &Row {
+        self.row
+    }
+}
+
+impl<'a, 'b> Index<&'b str> for NamedRow<'a> {
+    type Output = Cell;
+    /// Get the cell in the column whose title is `name`.
+    ///
+    /// # Panics
+    /// Panics if there's no such column or no cell in it. Use `get()` to handle this case
+    fn index(&self, name: &'b str) -> &Cell {
+        match self.get(name) {
+            Some(cell) => cell,
+            None => panic!("no cell in a column titled {:?}", name),
+        }
+    }
+}
+
+impl<'a> Index<usize> for NamedRow<'a> {
+    type Output = Cell;
+    fn index(&self, idx: usize) -> &Cell {
+        &self.row[idx]
+    }
+}
+
 /// This macro simplifies `Row` creation
 ///
 /// The syntax support style spec
@@ -348,7 +992,8 @@ macro_rules! row {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use Cell;
+    use crate::color;
+    use crate::utils::StringWriter;
 
     #[test]
     fn row_default_empty() {
@@ -400,6 +1045,36 @@ mod tests {
         assert_eq!(row.get_cell(1).unwrap().get_content(), "foobar");
     }
 
+    #[test]
+    fn row_style() {
+        let row =
+            Row::new(vec![Cell::new("a"), Cell::new("b").style_spec("lFg")]).style_spec("rFr");
+        assert_eq!(row.align, Some(Alignment::RIGHT));
+        assert_eq!(row.style, vec![Attr::ForegroundColor(color::RED)]);
+
+        let mut out = StringWriter::new();
+        row.print(&mut out, &TableFormat::new(), &[3, 3], &Settings::default())
+            .unwrap();
+        assert_eq!(out.as_string().replace("\r\n", "\n"), "  ab\n");
+
+        let mut out = StringWriter::new();
+        row.print_html(&mut out, 2).unwrap();
+        assert_eq!(
+            out.as_string(),
+            "<td style=\"color: #aa0000;text-align: right;\">a</td>\
+             <td style=\"color: #aa0000;color: #00aa00;text-align: left;\">b</td>"
+        );
+
+        assert!(row.has_style(Attr::ForegroundColor(color::GREEN)));
+        assert!(!row.has_style(Attr::Bold));
+        let mut row = row.with_style(Attr::Bold);
+        assert!(row.has_style(Attr::Bold));
+        assert_eq!(row.style.len(), 2);
+        row.reset_style();
+        assert!(row.style.is_empty());
+        assert_eq!(row.align, None);
+    }
+
     #[test]
     fn extend_row() {
         let mut row = Row::from(vec!["foo", "bar", "foobar"]);
@@ -409,4 +1084,75 @@ mod tests {
         assert_eq!(row.get_cell(4).unwrap().get_content(), "B");
         assert_eq!(row.get_cell(5).unwrap().get_content(), "C");
     }
+
+    #[test]
+    fn project() {
+        let row = Row::new(vec![
+            Cell::new("a"),
+            Cell::new("bc").with_hspan(2),
+            Cell::new("d"),
+        ]);
+        let p = row.project(&[1, 2, 3]);
+        assert_eq!(p.len(), 2);
+        assert_eq!(p[0].get_content(), "bc");
+        assert_eq!(p[0].get_hspan(), 2);
+        let p = row.project(&[3, 0, 2]);
+        assert_eq!(p.column_count(), 3);
+        assert_eq!(p[0].get_content(), "d");
+        assert_eq!(p[2].get_hspan(), 1);
+        assert!(row.project(&[0, 5]).len() == 1);
+    }
+
+    #[test]
+    fn extend_from_iter() {
+        let mut row = Row::from(vec!["foo"]);
+        row.extend_from_iter((0..3).map(|i| Cell::new(&i.to_string()).with_hspan(2)));
+        assert_eq!(row.len(), 4);
+        assert_eq!(row.column_count(), 7);
+        assert_eq!(row[3].get_content(), "2");
+    }
+
+    #[test]
+    fn into_cells() {
+        let row = Row::from(vec!["a", "b"]);
+        let cells: Vec<Cell> = row.into_cells().collect();
+        assert_eq!(cells, vec![Cell::new("a"), Cell::new("b")]);
+    }
+
+    #[test]
+    fn row_builder() {
+        let row = RowBuilder::new()
+            .cell(1)
+            .styled("b", "Fgc")
+            .aligned("c", Alignment::RIGHT)
+            .with_cell(Cell::new("d").with_hspan(2))
+            .build();
+        assert_eq!(row.len(), 4);
+        assert_eq!(row.column_count(), 5);
+        assert_eq!(row[0].get_content(), "1");
+        assert_eq!(row[1].get_style(), &[Attr::ForegroundColor(color::GREEN)]);
+        assert_eq!(row[1].get_alignment(), Alignment::CENTER);
+        assert_eq!(row[2].get_alignment(), Alignment::RIGHT);
+    }
+
+    #[test]
+    fn named_row() {
+        let titles = Row::from(vec!["hostname", "ip"]);
+        let row = Row::from(vec!["web-1"]);
+        let named = NamedRow::new(&row, Some(&titles));
+        assert_eq!(named["hostname"].get_content(), "web-1");
+        assert_eq!(named[0].get_content(), "web-1");
+        assert_eq!(named.len(), 1);
+        assert!(named.get("ip").is_none());
+        assert!(named.get("port").is_none());
+        assert!(NamedRow::new(&row, None).get("hostname").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "no cell in a column titled \"port\"")]
+    fn named_row_missing() {
+        let titles = Row::from(vec!["hostname"]);
+        let row = Row::from(vec!["web-1"]);
+        let _ = &NamedRow::new(&row, Some(&titles))["port"];
+    }
 }
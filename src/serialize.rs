@@ -0,0 +1,93 @@
+//! This module contains the serde support for style attributes, enabled with the `serde` feature
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{color, Attr};
+
+/// Mirror of `term::Attr`, which doesn't implement the serde traits
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Attr")]
+enum AttrDef {
+    Bold,
+    Dim,
+    Italic(bool),
+    Underline(bool),
+    Blink,
+    Standout(bool),
+    Reverse,
+    Secure,
+    ForegroundColor(color::Color),
+    BackgroundColor(color::Color),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Style(#[serde(with = "AttrDef")] Attr);
+
+/// (De)serialize a list of style attributes, for use with `#[serde(with = "...")]`
+pub(crate) mod attrs {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        attrs: &[Attr],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(attrs.iter().map(|&a| Style(a)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Attr>, D::Error> {
+        let styles = Vec::<Style>::deserialize(deserializer)?;
+        Ok(styles.into_iter().map(|Style(a)| a).collect())
+    }
+}
+
+/// (De)serialize an optional style attribute, for use with `#[serde(with = "...")]`
+pub(crate) mod attr {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        attr: &Option<Attr>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        attr.map(Style).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Attr>, D::Error> {
+        let style = Option::<Style>::deserialize(deserializer)?;
+        Ok(style.map(|Style(a)| a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::consts::FORMAT_BOX_CHARS;
+    use crate::{color, row, table, Attr, Cell, ColumnWidth, Table};
+
+    #[test]
+    fn round_trip() {
+        let mut table = table!(["a", "1"]);
+        table.set_titles(row!["name", "n"]);
+        table.add_row(row![
+            Cell::new("b\nc").with_style(Attr::ForegroundColor(color::RED)),
+            Cell::new("2").with_hspan(2)
+        ]);
+        table.set_format(*FORMAT_BOX_CHARS);
+        table.set_column_width(0, ColumnWidth::Max(3));
+        table.set_column_hidden(1, true);
+        let json = serde_json::to_string(&table).unwrap();
+        let copy: Table = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy, table);
+        assert_eq!(copy.to_string(), table.to_string());
+    }
+
+    #[test]
+    fn partial_cell() {
+        let cell: Cell =
+            serde_json::from_str(r#"{"content":["abc","de"],"style":["Bold"]}"#).unwrap();
+        assert_eq!(cell, Cell::new("abc\nde").with_style(Attr::Bold));
+        assert_eq!(cell.get_width(), 3);
+    }
+}
@@ -1,6 +1,6 @@
 //! Internal only utilities
-use std::fmt;
 use std::io::{Error, ErrorKind, Write};
+use std::process::{Command, Stdio};
 use std::str;
 
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
@@ -15,6 +15,8 @@ pub static NEWLINE: &[u8] = b"\r\n";
 /// Internal utility for writing data into a string
 pub struct StringWriter {
     string: String,
+    limit: Option<usize>,
+    exceeded: bool,
 }
 
 impl StringWriter {
@@ -22,13 +24,35 @@ impl StringWriter {
     pub fn new() -> StringWriter {
         StringWriter {
             string: String::new(),
+            limit: None,
+            exceeded: false,
         }
     }
 
+    /// Create a new `StringWriter` failing to write more than `limit` bytes.
+    /// `capacity` bytes are reserved upfront
+    pub fn with_limit(limit: usize, capacity: usize) -> StringWriter {
+        StringWriter {
+            string: String::with_capacity(capacity.min(limit)),
+            limit: Some(limit),
+            exceeded: false,
+        }
+    }
+
+    /// Consume the writer and return the written `String`
+    pub fn into_string(self) -> String {
+        self.string
+    }
+
     /// Return a reference to the internally written `String`
     pub fn as_string(&self) -> &str {
         &self.string
     }
+
+    /// Check whether a write failed because it would have exceeded the limit
+    pub fn limit_exceeded(&self) -> bool {
+        self.exceeded
+    }
 }
 
 impl Write for StringWriter {
@@ -42,6 +66,15 @@ impl Write for StringWriter {
                 ))
             }
         };
+        if let Some(limit) = self.limit {
+            if self.string.len() + string.len() > limit {
+                self.exceeded = true;
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("Rendered table exceeds the maximum size of {} bytes", limit),
+                ));
+            }
+        }
         self.string.push_str(string);
         Ok(data.len())
     }
@@ -54,7 +87,8 @@ impl Write for StringWriter {
 
 /// Align/fill a string and print it to `out`
 /// If `skip_right_fill` is set to `true`, then no space will be added after the string
-/// to complete alignment
+/// to complete alignment. Ambiguous width characters are 2 columns wide if `wide_ambiguous`
+/// is set to `true`
 pub fn print_align<T: Write + ?Sized>(
     out: &mut T,
     align: Alignment,
@@ -62,21 +96,60 @@ pub fn print_align<T: Write + ?Sized>(
     fill: char,
     size: usize,
     skip_right_fill: bool,
+    wide_ambiguous: bool,
 ) -> Result<(), Error> {
-    let text_len = display_width(text);
-    let mut nfill = if text_len < size { size - text_len } else { 0 };
+    let text_len = display_width_with(text, wide_ambiguous);
+    let mut nfill = size.saturating_sub(text_len);
+    if align == Alignment::JUSTIFY && text.contains(' ') {
+        return print_justified(out, text, fill, nfill);
+    }
     let n = match align {
-        Alignment::LEFT => 0,
+        Alignment::LEFT | Alignment::JUSTIFY => 0,
         Alignment::RIGHT => nfill,
         Alignment::CENTER => nfill / 2,
     };
     if n > 0 {
-        out.write_all(&vec![fill as u8; n])?;
+        print_fill(out, fill, n)?;
         nfill -= n;
     }
     out.write_all(text.as_bytes())?;
     if nfill > 0 && !skip_right_fill {
-        out.write_all(&vec![fill as u8; nfill])?;
+        print_fill(out, fill, nfill)?;
+    }
+    Ok(())
+}
+
+/// Fill `width` columns with `fill` characters, completed with spaces when `fill`
+/// is a wide character which doesn't fit anymore
+fn print_fill<T: Write + ?Sized>(out: &mut T, fill: char, width: usize) -> Result<(), Error> {
+    if fill.is_ascii() {
+        return out.write_all(&vec![fill as u8; width]);
+    }
+    let w = UnicodeWidthChar::width(fill).unwrap_or(1).max(1);
+    let mut buf = [0; 4];
+    let fill = fill.encode_utf8(&mut buf);
+    for _ in 0..width / w {
+        out.write_all(fill.as_bytes())?;
+    }
+    out.write_all(&vec![b' '; width % w])
+}
+
+/// Print `text` with `nfill` additional `fill` characters spread between its words,
+/// the leftmost gaps getting one more when they can't be spread evenly
+fn print_justified<T: Write + ?Sized>(
+    out: &mut T,
+    text: &str,
+    fill: char,
+    nfill: usize,
+) -> Result<(), Error> {
+    let gaps = text.matches(' ').count();
+    for (i, word) in text.split(' ').enumerate() {
+        if i > 0 {
+            let n = nfill / gaps + usize::from(i <= nfill % gaps);
+            out.write_all(b" ")?;
+            print_fill(out, fill, n)?;
+        }
+        out.write_all(word.as_bytes())?;
     }
     Ok(())
 }
@@ -84,6 +157,13 @@ pub fn print_align<T: Write + ?Sized>(
 /// Return the display width of a unicode string.
 /// This functions takes ANSI-escaped color codes into account.
 pub fn display_width(text: &str) -> usize {
+    display_width_with(text, false)
+}
+
+/// Return the display width of a unicode string, East Asian ambiguous width characters
+/// being 2 columns wide if `wide_ambiguous` is `true`, and 1 column otherwise.
+/// This functions takes ANSI-escaped color codes into account.
+pub fn display_width_with(text: &str, wide_ambiguous: bool) -> usize {
     #[derive(PartialEq, Eq, Clone, Copy)]
     enum State {
         /// We are not inside any terminal escape.
@@ -96,7 +176,11 @@ pub fn display_width(text: &str) -> usize {
         AfterEscape,
     }
 
-    let width = UnicodeWidthStr::width(text);
+    let width = if wide_ambiguous {
+        UnicodeWidthStr::width_cjk(text)
+    } else {
+        UnicodeWidthStr::width(text)
+    };
     let mut state = State::Normal;
     let mut hidden = 0;
 
@@ -114,6 +198,7 @@ pub fn display_width(text: &str) -> usize {
         if matches!(state, State::OpenBracket | State::AfterEscape) {
             // but if we see an escape char *inside* the ANSI escape, we should ignore it.
             if UnicodeWidthChar::width(c).unwrap_or(0) > 0 {
+                // Escape sequences are made of ASCII characters, never ambiguous
                 hidden += 1;
             }
         }
@@ -134,40 +219,241 @@ pub fn display_width(text: &str) -> usize {
     width - hidden
 }
 
-/// Wrapper struct which will emit the HTML-escaped version of the contained
-/// string when passed to a format string.
-pub struct HtmlEscape<'a>(pub &'a str);
-
-impl<'a> fmt::Display for HtmlEscape<'a> {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        // Because the internet is always right, turns out there's not that many
-        // characters to escape: http://stackoverflow.com/questions/7381974
-        let HtmlEscape(s) = *self;
-        let pile_o_bits = s;
-        let mut last = 0;
-        for (i, ch) in s.bytes().enumerate() {
-            match ch as char {
-                '<' | '>' | '&' | '\'' | '"' => {
-                    fmt.write_str(&pile_o_bits[last..i])?;
-                    let s = match ch as char {
-                        '>' => "&gt;",
-                        '<' => "&lt;",
-                        '&' => "&amp;",
-                        '\'' => "&#39;",
-                        '"' => "&quot;",
-                        _ => unreachable!(),
-                    };
-                    fmt.write_str(s)?;
-                    last = i + 1;
+/// Return the display width of `c`, East Asian ambiguous width characters
+/// being 2 columns wide if `wide_ambiguous` is `true`
+fn char_width(c: char, wide_ambiguous: bool) -> usize {
+    if wide_ambiguous {
+        UnicodeWidthChar::width_cjk(c).unwrap_or(0)
+    } else {
+        UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+}
+
+/// Shorten `text` so that its display width doesn't exceed `width`,
+/// replacing the end of the string with an ellipsis.
+/// Ambiguous width characters, including the ellipsis, are 2 columns wide if `wide_ambiguous` is `true`
+pub fn truncate(text: &str, width: usize, wide_ambiguous: bool) -> String {
+    if display_width_with(text, wide_ambiguous) <= width {
+        return text.to_owned();
+    }
+    let mut out = String::new();
+    let mut len = 0;
+    let ellipsis = char_width('…', wide_ambiguous);
+    for c in text.chars() {
+        let w = char_width(c, wide_ambiguous);
+        if len + w + ellipsis > width {
+            break;
+        }
+        len += w;
+        out.push(c);
+    }
+    if width >= ellipsis {
+        out.push('…');
+    }
+    out
+}
+
+/// Split `text` into lines whose display width doesn't exceed `width`, breaking lines
+/// between words when possible, and inside words longer than `width` otherwise.
+/// Existing line breaks are kept.
+/// Ambiguous width characters are 2 columns wide if `wide_ambiguous` is `true`
+pub fn wrap(text: &str, width: usize, wide_ambiguous: bool) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let mut current = String::new();
+        let mut len = 0;
+        for word in line.split_whitespace() {
+            let word_len = display_width_with(word, wide_ambiguous);
+            if len > 0 && len + 1 + word_len <= width {
+                current.push(' ');
+                current.push_str(word);
+                len += 1 + word_len;
+                continue;
+            }
+            if len > 0 {
+                lines.push(current);
+                current = String::new();
+                len = 0;
+            }
+            // Break the word if it doesn't fit on a line on its own
+            for c in word.chars() {
+                let w = char_width(c, wide_ambiguous);
+                if len > 0 && len + w > width {
+                    lines.push(current);
+                    current = String::new();
+                    len = 0;
                 }
-                _ => {}
+                current.push(c);
+                len += w;
             }
         }
+        lines.push(current);
+    }
+    lines
+}
+
+/// Get the size of the terminal attached to the standard output, as `(columns, rows)`
+#[cfg(unix)]
+fn terminal_size() -> Option<(usize, usize)> {
+    // SAFETY: `winsize` is plain data, and is only written by `ioctl` on success
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let res = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    if res == 0 {
+        Some((size.ws_col as usize, size.ws_row as usize))
+    } else {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+fn terminal_size() -> Option<(usize, usize)> {
+    None
+}
+
+/// Parse the positive number in the environment variable `name`
+fn env_size(name: &str) -> Option<usize> {
+    std::env::var(name)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|&n| n > 0)
+}
+
+/// Get the locale used for characters, as given by the first non-empty variable among
+/// `LC_ALL`, `LC_CTYPE` and `LANG`, in lower case
+fn locale() -> Option<String> {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .map(|value| value.to_ascii_lowercase())
+}
+
+/// Check whether the locale uses UTF-8. It's assumed to be the case when it's not set,
+/// and on platforms other than unix
+pub fn locale_supports_utf8() -> bool {
+    if cfg!(not(unix)) {
+        return true;
+    }
+    match locale() {
+        Some(locale) => locale.contains("utf-8") || locale.contains("utf8"),
+        None => true,
+    }
+}
+
+/// Check whether the locale is a Chinese, Japanese or Korean one, whose terminals
+/// usually print East Asian ambiguous width characters 2 columns wide
+pub fn locale_is_cjk() -> bool {
+    match locale() {
+        Some(locale) => ["zh", "ja", "ko"].iter().any(|l| locale.starts_with(l)),
+        None => false,
+    }
+}
+
+/// Get the width of the terminal attached to the standard output, if any.
+/// The `COLUMNS` environment variable is used when the terminal can't be queried
+pub fn terminal_width() -> Option<usize> {
+    terminal_size()
+        .map(|(cols, _)| cols)
+        .filter(|&w| w > 0)
+        .or_else(|| env_size("COLUMNS"))
+}
+
+/// Get the height of the terminal attached to the standard output, if any.
+/// The `LINES` environment variable is used when the terminal can't be queried
+pub fn terminal_height() -> Option<usize> {
+    terminal_size()
+        .map(|(_, rows)| rows)
+        .filter(|&h| h > 0)
+        .or_else(|| env_size("LINES"))
+}
+
+/// Exit status of a shell which can't find the command to run
+const COMMAND_NOT_FOUND: i32 = 127;
+
+/// Build the command running `pager`. On unix, it's run by `sh`, like git does, so it
+/// may contain quotes or variables. Elsewhere, it's split on whitespace, and the first
+/// word is the program run with the other words as arguments
+fn pager_command(pager: &str) -> Option<Command> {
+    if cfg!(unix) {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(pager);
+        Some(cmd)
+    } else {
+        let mut args = pager.split_whitespace();
+        let mut cmd = Command::new(args.next()?);
+        cmd.args(args);
+        Some(cmd)
+    }
+}
+
+/// Write `output` to stdout
+fn write_stdout(output: &[u8]) -> Result<(), Error> {
+    let mut out = std::io::stdout();
+    out.write_all(output)?;
+    out.flush()
+}
 
-        if last < s.len() {
-            fmt.write_str(&pile_o_bits[last..])?;
+/// Write `output` to the pager given by the `PAGER` environment variable, or `less -R`.
+/// On unix, the pager is run by `sh -c`, so `PAGER` may contain quoted arguments.
+/// The output is written to the standard output instead if the pager can't be started
+pub fn page(output: &[u8]) -> Result<(), Error> {
+    let pager = std::env::var("PAGER")
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or_else(|| "less -R".to_string());
+    let child = pager_command(&pager).and_then(|mut cmd| cmd.stdin(Stdio::piped()).spawn().ok());
+    let mut child = match child {
+        Some(child) => child,
+        None => return write_stdout(output),
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager may be closed before reading everything
+        match stdin.write_all(output) {
+            Err(ref e) if e.kind() == ErrorKind::BrokenPipe => (),
+            res => res?,
         }
-        Ok(())
+    }
+    let status = child.wait()?;
+    // The shell runs even if the pager can't be found
+    if cfg!(unix) && status.code() == Some(COMMAND_NOT_FOUND) {
+        return write_stdout(output);
+    }
+    Ok(())
+}
+
+/// Ignore `res` failing because the output was closed, like when piping into `head`
+pub fn ignore_broken_pipe<T>(res: Result<T, Error>) -> Result<(), Error> {
+    match res {
+        Err(ref e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        res => res.map(|_| ()),
+    }
+}
+
+/// Minimal seeded pseudo-random number generator (SplitMix64),
+/// giving reproducible results without pulling a dependency
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a generator initialized with `seed`
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    /// Return the next random number
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a random number lower than `bound`, which must not be 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
     }
 }
 
@@ -190,45 +476,134 @@ mod tests {
     #[test]
     fn fill_align() {
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::RIGHT, "foo", '*', 10, false).unwrap();
+        print_align(&mut out, Alignment::RIGHT, "foo", '*', 10, false, false).unwrap();
         assert_eq!(out.as_string(), "*******foo");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::LEFT, "foo", '*', 10, false).unwrap();
+        print_align(&mut out, Alignment::LEFT, "foo", '*', 10, false, false).unwrap();
         assert_eq!(out.as_string(), "foo*******");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::CENTER, "foo", '*', 10, false).unwrap();
+        print_align(&mut out, Alignment::CENTER, "foo", '*', 10, false, false).unwrap();
         assert_eq!(out.as_string(), "***foo****");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::CENTER, "foo", '*', 1, false).unwrap();
+        print_align(&mut out, Alignment::CENTER, "foo", '*', 1, false, false).unwrap();
+        assert_eq!(out.as_string(), "foo");
+    }
+
+    #[test]
+    fn ambiguous_width() {
+        assert_eq!(display_width("±°"), 2);
+        assert_eq!(display_width_with("±°", true), 4);
+        assert_eq!(display_width_with("\u{1b}[31m±\u{1b}[0m", true), 2);
+        let mut out = StringWriter::new();
+        print_align(&mut out, Alignment::RIGHT, "±", '*', 4, false, true).unwrap();
+        assert_eq!(out.as_string(), "**±");
+    }
+
+    #[test]
+    fn wide_fill() {
+        let mut out = StringWriter::new();
+        print_align(
+            &mut out,
+            Alignment::CENTER,
+            "a",
+            '\u{3000}',
+            6,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(out.as_string(), "\u{3000}a\u{3000} ");
+    }
+
+    #[test]
+    fn justify() {
+        let mut out = StringWriter::new();
+        print_align(
+            &mut out,
+            Alignment::JUSTIFY,
+            "a bc d",
+            '*',
+            11,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(out.as_string(), "a ***bc **d");
+
+        let mut out = StringWriter::new();
+        print_align(&mut out, Alignment::JUSTIFY, "foo", '*', 10, true, false).unwrap();
         assert_eq!(out.as_string(), "foo");
     }
 
     #[test]
     fn skip_right_fill() {
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::RIGHT, "foo", '*', 10, true).unwrap();
+        print_align(&mut out, Alignment::RIGHT, "foo", '*', 10, true, false).unwrap();
         assert_eq!(out.as_string(), "*******foo");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::LEFT, "foo", '*', 10, true).unwrap();
+        print_align(&mut out, Alignment::LEFT, "foo", '*', 10, true, false).unwrap();
         assert_eq!(out.as_string(), "foo");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::CENTER, "foo", '*', 10, true).unwrap();
+        print_align(&mut out, Alignment::CENTER, "foo", '*', 10, true, false).unwrap();
         assert_eq!(out.as_string(), "***foo");
 
         let mut out = StringWriter::new();
-        print_align(&mut out, Alignment::CENTER, "foo", '*', 1, false).unwrap();
+        print_align(&mut out, Alignment::CENTER, "foo", '*', 1, false, false).unwrap();
         assert_eq!(out.as_string(), "foo");
     }
 
+    #[test]
+    fn string_writer_limit() {
+        let mut out = StringWriter::with_limit(5, 100);
+        out.write_all(b"foo").unwrap();
+        assert!(!out.limit_exceeded());
+        assert!(out.write_all(b"bar").is_err());
+        assert!(out.limit_exceeded());
+        out.write_all(b"ba").unwrap();
+        assert_eq!(out.into_string(), "fooba");
+    }
+
+    #[test]
+    fn wrap_text() {
+        assert_eq!(
+            wrap("the quick brown fox", 9, false),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(
+            wrap("abcdefgh ij", 3, false),
+            vec!["abc", "def", "gh", "ij"]
+        );
+        assert_eq!(wrap("a\n\nb c", 1, false), vec!["a", "", "b", "c"]);
+        assert_eq!(wrap("日本語", 3, false), vec!["日", "本", "語"]);
+        assert_eq!(wrap("±1 ±2", 3, false), vec!["±1", "±2"]);
+        assert_eq!(wrap("±1 ±2", 3, true), vec!["±1", "±2"]);
+        assert_eq!(wrap("±±±", 3, true), vec!["±", "±", "±"]);
+        assert_eq!(truncate("abcdef", 4, false), "abc…");
+        assert_eq!(truncate("abc", 4, false), "abc");
+        assert_eq!(truncate("±±±", 4, false), "±±±");
+        assert_eq!(truncate("±±±", 4, true), "±…");
+        assert_eq!(truncate("abcdef", 1, true), "");
+    }
+
     #[test]
     fn utf8_error() {
         let mut out = StringWriter::new();
         let res = out.write_all(&[0, 255]);
         assert!(res.is_err());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn pager_command_shell() {
+        let out = pager_command("printf '%s|' \"a  b\" c")
+            .unwrap()
+            .output()
+            .unwrap();
+        assert_eq!(out.stdout, b"a  b|c|");
+    }
 }
@@ -0,0 +1,173 @@
+//! This module contains the layout validation of tables
+use std::fmt;
+
+use super::{AsTableSlice, Row, Table, TableSlice};
+
+/// A layout inconsistency found by `Table::validate()`
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The row at index `row` covers fewer grid columns than the table
+    RowColumnCount {
+        /// Index of the row in the table
+        row: usize,
+        /// Number of columns in the table
+        expected: usize,
+        /// Number of columns covered by the row's cells, including spans
+        found: usize,
+    },
+    /// The titles row covers fewer grid columns than the table
+    TitlesColumnCount {
+        /// Number of columns in the table
+        expected: usize,
+        /// Number of columns covered by the titles
+        found: usize,
+    },
+    /// The footer row covers fewer grid columns than the table
+    FooterColumnCount {
+        /// Number of columns in the table
+        expected: usize,
+        /// Number of columns covered by the footer
+        found: usize,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Diagnostic::RowColumnCount {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} columns, expected {}",
+                row, found, expected
+            ),
+            Diagnostic::TitlesColumnCount { expected, found } => {
+                write!(f, "titles have {} columns, expected {}", found, expected)
+            }
+            Diagnostic::FooterColumnCount { expected, found } => {
+                write!(f, "footer has {} columns, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+/// Get the number of grid columns covered by `row`, or `None` if it spans the whole table
+fn row_columns(row: &Row) -> Option<usize> {
+    if row.is_full_width() {
+        None
+    } else {
+        Some(row.column_count())
+    }
+}
+
+impl<'a> TableSlice<'a> {
+    /// Check the table layout for inconsistencies, like rows covering fewer columns than the others
+    /// once horizontal spans are taken into account, or titles not matching the rows.
+    /// Such tables are still printed, missing cells being filled with blanks,
+    /// but this lets the inconsistencies be caught beforehand.
+    /// Full width rows are not checked.
+    ///
+    /// Returns all the diagnostics found, if any
+    pub fn validate(&self) -> Result<(), Vec<Diagnostic>> {
+        let expected = self.get_column_num();
+        let mut diagnostics = Vec::new();
+        if let Some(found) = self.titles.as_ref().and_then(row_columns) {
+            if found < expected {
+                diagnostics.push(Diagnostic::TitlesColumnCount { expected, found });
+            }
+        }
+        for (row, r) in self.rows.iter().enumerate() {
+            match row_columns(r) {
+                Some(found) if found < expected => diagnostics.push(Diagnostic::RowColumnCount {
+                    row,
+                    expected,
+                    found,
+                }),
+                _ => (),
+            }
+        }
+        if let Some(found) = self.footer.as_ref().and_then(row_columns) {
+            if found < expected {
+                diagnostics.push(Diagnostic::FooterColumnCount { expected, found });
+            }
+        }
+        if diagnostics.is_empty() {
+            Ok(())
+        } else {
+            Err(diagnostics)
+        }
+    }
+}
+
+impl Table {
+    /// Check the table layout for inconsistencies, like rows covering fewer columns than the others
+    /// once horizontal spans are taken into account, or titles not matching the rows.
+    /// Such tables are still printed, missing cells being filled with blanks,
+    /// but this lets the inconsistencies be caught beforehand.
+    /// Full width rows are not checked.
+    ///
+    /// Returns all the diagnostics found, if any
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate prettytable;
+    /// # use prettytable::Diagnostic;
+    /// # fn main() {
+    /// let table = table!([H2->"spanning", "c"], ["a", "b"]);
+    /// assert_eq!(
+    ///     table.validate(),
+    ///     Err(vec![Diagnostic::RowColumnCount { row: 1, expected: 3, found: 2 }])
+    /// );
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<Diagnostic>> {
+        self.as_slice().validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diagnostic;
+    use crate::{row, table, Row};
+
+    #[test]
+    fn validate() {
+        let mut table = table!(["a", "b"], ["c", "d"]);
+        assert_eq!(table.validate(), Ok(()));
+        table.add_row(Row::full_width("group"));
+        table.set_titles(row!["title"]);
+        table.set_footer(row![H2->"total"]);
+        let errors = table.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![Diagnostic::TitlesColumnCount {
+                expected: 2,
+                found: 1
+            }]
+        );
+        assert_eq!(errors[0].to_string(), "titles have 1 columns, expected 2");
+        table.add_row(row!["e", H2->"f"]);
+        table.set_titles(row!["t1", "t2", "t3"]);
+        assert_eq!(
+            table.validate(),
+            Err(vec![
+                Diagnostic::RowColumnCount {
+                    row: 0,
+                    expected: 3,
+                    found: 2
+                },
+                Diagnostic::RowColumnCount {
+                    row: 1,
+                    expected: 3,
+                    found: 2
+                },
+                Diagnostic::FooterColumnCount {
+                    expected: 3,
+                    found: 2
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! This module contains a read-only table view over borrowed string data
+use std::fmt;
+use std::io::{self, Error, Write};
+use std::iter::FromIterator;
+
+use super::format::{consts, Alignment, ColumnPosition, LinePosition, Plain, TableFormat};
+use super::utils::{display_width, print_align, StringWriter};
+
+/// A read-only table borrowing its cells content from caller-owned storage.
+///
+/// Unlike `Table`, no `String` is allocated per cell : cells are kept as `&str`
+/// pointing into the caller's buffer (eg. a line of a log file, or a parsed CSV record),
+/// which makes it suitable for high-throughput formatting.
+/// A `TableView` has no styling or spanning support.
+///
+/// # Examples
+/// ```rust
+/// use prettytable::TableView;
+///
+/// let buffer = String::from("alice,42\nbob,7");
+/// let mut view = TableView::new();
+/// view.set_titles(vec!["name", "age"]);
+/// for line in buffer.lines() {
+///     view.add_row(line.split(','));
+/// }
+/// view.printstd();
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TableView<'a> {
+    format: TableFormat,
+    titles: Option<Vec<&'a str>>,
+    /// Cells of all rows, stored contiguously
+    cells: Vec<&'a str>,
+    /// Index in `cells` of the end of each row
+    ends: Vec<usize>,
+}
+
+impl<'a> TableView<'a> {
+    /// Create an empty view, with the default format
+    pub fn new() -> TableView<'a> {
+        TableView {
+            format: *consts::FORMAT_DEFAULT,
+            titles: None,
+            cells: Vec::new(),
+            ends: Vec::new(),
+        }
+    }
+
+    /// Change the view format
+    pub fn set_format(&mut self, format: TableFormat) {
+        self.format = format;
+    }
+
+    /// Get a mutable reference to the internal format
+    pub fn get_format(&mut self) -> &mut TableFormat {
+        &mut self.format
+    }
+
+    /// Set the titles line
+    pub fn set_titles<I: IntoIterator<Item = &'a str>>(&mut self, titles: I) {
+        self.titles = Some(titles.into_iter().collect());
+    }
+
+    /// Unset the titles line
+    pub fn unset_titles(&mut self) {
+        self.titles = None;
+    }
+
+    /// Append a row made of the given cells
+    pub fn add_row<I: IntoIterator<Item = &'a str>>(&mut self, row: I) {
+        self.cells.extend(row);
+        self.ends.push(self.cells.len());
+    }
+
+    /// Get the number of rows
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Check if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Get the cells of the row at index `idx`
+    pub fn get_row(&self, idx: usize) -> Option<&[&'a str]> {
+        let end = *self.ends.get(idx)?;
+        let start = if idx == 0 { 0 } else { self.ends[idx - 1] };
+        Some(&self.cells[start..end])
+    }
+
+    /// Returns an iterator over rows
+    pub fn row_iter(&self) -> impl Iterator<Item = &[&'a str]> {
+        (0..self.len()).filter_map(move |i| self.get_row(i))
+    }
+
+    /// Get the width of all columns
+    fn get_all_column_width(&self) -> Vec<usize> {
+        let mut col_width = Vec::new();
+        for row in self.titles.as_deref().into_iter().chain(self.row_iter()) {
+            if row.len() > col_width.len() {
+                col_width.resize(row.len(), 0);
+            }
+            for (w, cell) in col_width.iter_mut().zip(row) {
+                *w = cell.lines().map(display_width).fold(*w, usize::max);
+            }
+        }
+        col_width
+    }
+
+    /// Print a single row, returning the number of printed lines
+    fn print_row<T: Write + ?Sized>(
+        &self,
+        out: &mut T,
+        row: &[&str],
+        col_width: &[usize],
+    ) -> Result<usize, Error> {
+        let format = &self.format;
+        let height = row.iter().map(|c| c.lines().count()).max().unwrap_or(0);
+        let height = height.max(1); // Minimum height must be 1 to print empty rows
+        let (lp, rp) = format.get_padding();
+        for i in 0..height {
+            out.write_all(&vec![b' '; format.get_indent()])?;
+            format.print_column_separator(out, ColumnPosition::Left, &Plain)?;
+            for (j, width) in col_width.iter().enumerate() {
+                format.print_padding(out, lp)?;
+                let skip_r_fill = (j == col_width.len() - 1)
+                    && format.get_column_separator(ColumnPosition::Right).is_none();
+                let content = row.get(j).and_then(|c| c.lines().nth(i)).unwrap_or("");
+                print_align(
+                    out,
+                    Alignment::LEFT,
+                    content,
+                    format.get_padding_char(),
+                    *width,
+                    skip_r_fill,
+                    false,
+                )?;
+                format.print_padding(out, rp)?;
+                if j < col_width.len() - 1 {
+                    format.print_column_separator(out, ColumnPosition::Intern, &Plain)?;
+                }
+            }
+            format.print_column_separator(out, ColumnPosition::Right, &Plain)?;
+            out.write_all(format.newline_bytes())?;
+        }
+        Ok(height)
+    }
+
+    /// Print the view to `out` and returns the number
+    /// of lines printed, or an error
+    pub fn print<T: Write + ?Sized>(&self, out: &mut T) -> Result<usize, Error> {
+        let mut height = 0;
+        let col_width = self.get_all_column_width();
+        height += self
+            .format
+            .print_line_separator(out, &col_width, LinePosition::Top, &Plain)?;
+        if let Some(ref t) = self.titles {
+            height += self.print_row(out, t, &col_width)?;
+            height +=
+                self.format
+                    .print_line_separator(out, &col_width, LinePosition::Title, &Plain)?;
+        }
+        let mut iter = self.row_iter().peekable();
+        while let Some(r) = iter.next() {
+            height += self.print_row(out, r, &col_width)?;
+            if iter.peek().is_some() {
+                height += self.format.print_line_separator(
+                    out,
+                    &col_width,
+                    LinePosition::Intern,
+                    &Plain,
+                )?;
+            }
+        }
+        height +=
+            self.format
+                .print_line_separator(out, &col_width, LinePosition::Bottom, &Plain)?;
+        out.flush()?;
+        Ok(height)
+    }
+
+    /// Print the view to standard output.
+    /// Any failure to print is ignored. For better control, use `print()`.
+    pub fn printstd(&self) {
+        let _ = self.print(&mut io::stdout()); // Ignore result
+    }
+}
+
+impl<'a> Default for TableView<'a> {
+    fn default() -> Self {
+        TableView::new()
+    }
+}
+
+impl<'a> fmt::Display for TableView<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut writer = StringWriter::new();
+        if self.print(&mut writer).is_err() {
+            return Err(fmt::Error);
+        }
+        fmt.write_str(writer.as_string())
+    }
+}
+
+impl<'a, R: IntoIterator<Item = &'a str>> FromIterator<R> for TableView<'a> {
+    fn from_iter<T>(iterator: T) -> TableView<'a>
+    where
+        T: IntoIterator<Item = R>,
+    {
+        let mut view = TableView::new();
+        view.extend(iterator);
+        view
+    }
+}
+
+impl<'a, R: IntoIterator<Item = &'a str>> Extend<R> for TableView<'a> {
+    fn extend<T: IntoIterator<Item = R>>(&mut self, iter: T) {
+        for row in iter {
+            self.add_row(row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TableView;
+    use crate::format::consts::FORMAT_CLEAN;
+    use crate::utils::StringWriter;
+    use crate::{row, table};
+
+    #[test]
+    fn same_as_table() {
+        let data = "a,bc,def\ndef,bc,a\n1";
+        let mut view: TableView = data.lines().map(|l| l.split(',')).collect();
+        view.set_titles(vec!["t1", "t2", "t3"]);
+        let mut table = table!(["a", "bc", "def"], ["def", "bc", "a"], ["1"]);
+        table.set_titles(row!["t1", "t2", "t3"]);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.get_row(1), Some(&["def", "bc", "a"][..]));
+        assert_eq!(view.get_row(3), None);
+        assert_eq!(view.to_string(), table.to_string());
+        assert_eq!(9, view.print(&mut StringWriter::new()).unwrap());
+    }
+
+    #[test]
+    fn multiline() {
+        let mut view = TableView::new();
+        view.set_format(*FORMAT_CLEAN);
+        view.add_row(vec!["a\nb", "c"]);
+        view.add_row(vec![]);
+        let out = " a  c \n b   \n     \n";
+        assert_eq!(view.to_string().replace("\r\n", "\n"), out);
+    }
+}
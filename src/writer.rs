@@ -0,0 +1,292 @@
+//! This module contains the streaming output of tables
+use std::io::{Error, Write};
+
+use super::format::Plain;
+use super::render::{Rendering, RowView};
+use super::{constrain_row, AsTableSlice, Row, Table};
+
+/// Print a table row by row, as soon as each row is available, instead of buffering the whole
+/// table before printing it. It's useful for long-running commands producing rows over time.
+///
+/// Columns width can't change once the header is printed, so it's either given, or computed
+/// from a template table. Cells too wide for their column are then wrapped or truncated,
+/// like with `Table::set_column_width()`.
+/// Rows are numbered, laid out from right to left and printed with ASCII borders
+/// like the rows of the template, if enabled. Columns hidden when empty are only hidden
+/// if they're empty in all the rows of the template, and are kept if it has none.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate prettytable;
+/// use prettytable::{ColumnWidth, Table, TableWriter};
+/// # fn main() {
+/// let mut template = Table::new();
+/// template.set_titles(row!["id", "status"]);
+/// template.set_column_width(1, ColumnWidth::Fixed(8));
+/// let mut writer = TableWriter::new(Vec::new(), &template).unwrap();
+/// for id in 1..=2 {
+///     writer.write_row(row![id, "done"]).unwrap();
+/// }
+/// let out = writer.finish().unwrap();
+/// assert_eq!(
+///     String::from_utf8(out).unwrap().replace("\r\n", "\n"),
+///     "\
+/// +----+----------+
+/// | id | status   |
+/// +====+==========+
+/// | 1  | done     |
+/// +----+----------+
+/// | 2  | done     |
+/// +----+----------+
+/// "
+/// );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TableWriter<W: Write> {
+    out: W,
+    /// Format, titles, footer and settings of the table, without any row
+    template: Table,
+    col_width: Vec<usize>,
+    /// Transformations applied to the rows before printing them
+    view: RowView,
+    /// Number of printed rows which are not full width, as shown by the row numbers
+    numbered: usize,
+    /// Last printed row, deciding the separator before the next one
+    last: Option<Row>,
+    /// Number of printed rows, deciding when the titles are repeated
+    count: usize,
+}
+
+impl<W: Write> TableWriter<W> {
+    /// Start writing a table to `out`, with the format, titles, footer and settings of `template`.
+    /// Columns width is computed from the content of `template`, and from the constraints set
+    /// with `Table::set_column_width()`.
+    /// The header is printed immediately, followed by the rows already in `template`
+    pub fn new(out: W, template: &Table) -> Result<TableWriter<W>, Error> {
+        Self::start(out, template, None)
+    }
+
+    /// Start writing a table to `out`, like `new()`, with the given width for each column.
+    /// Hidden columns have no width, `col_width` giving the width of the printed columns
+    pub fn with_widths(
+        out: W,
+        template: &Table,
+        col_width: &[usize],
+    ) -> Result<TableWriter<W>, Error> {
+        Self::start(out, template, Some(col_width))
+    }
+
+    fn start(out: W, template: &Table, col_width: Option<&[usize]>) -> Result<Self, Error> {
+        let slice = template.as_slice();
+        let mut rendering = Rendering::new(&slice);
+        if slice.visible_rows().next().is_none() {
+            rendering.keep_empty_columns();
+        }
+        // Each step applies to the result of the previous ones
+        rendering.hide_columns(&slice);
+        rendering.add_row_numbers();
+        rendering.lay_out_right_to_left(&slice);
+        rendering.use_ascii_borders();
+        let view = rendering.row_view().clone();
+        let col_width = match col_width {
+            Some(col_width) => col_width.to_vec(),
+            None => rendering.slice(&slice).get_all_column_width(),
+        };
+        let mut template = rendering.into_table();
+        let columns = &template.settings.columns;
+        let wide = template.format.is_ambiguous_wide();
+        let titles = template
+            .titles
+            .as_ref()
+            .as_ref()
+            .map(|r| constrain_row(r, &col_width, columns, wide));
+        let footer = template
+            .footer
+            .as_ref()
+            .as_ref()
+            .map(|r| constrain_row(r, &col_width, columns, wide));
+        let groups = template
+            .settings
+            .title_groups
+            .iter()
+            .map(|r| constrain_row(r, &col_width, columns, wide))
+            .collect();
+        *template.titles = titles;
+        *template.footer = footer;
+        template.settings.title_groups = groups;
+        let mut writer = TableWriter {
+            out,
+            template,
+            col_width,
+            view,
+            numbered: 0,
+            last: None,
+            count: 0,
+        };
+        writer
+            .template
+            .as_slice()
+            .print_header(&mut writer.out, &Plain, &writer.col_width)?;
+        for row in slice.visible_rows() {
+            writer.print_row(row)?;
+        }
+        writer.out.flush()?;
+        Ok(writer)
+    }
+
+    /// Print `row`, preceded by the line separator between it and the previous row.
+    /// Hidden rows are not printed.
+    /// Returns the number of printed lines
+    pub fn write_row(&mut self, row: Row) -> Result<usize, Error> {
+        if row.is_hidden() {
+            return Ok(0);
+        }
+        let height = self.print_row(&row)?;
+        self.out.flush()?;
+        Ok(height)
+    }
+
+    fn print_row(&mut self, row: &Row) -> Result<usize, Error> {
+        if !row.is_full_width() {
+            self.numbered += 1;
+        }
+        let columns = &self.template.settings.columns;
+        let row = self.view.apply(row, self.numbered, columns);
+        let wide = self.template.format.is_ambiguous_wide();
+        let row = constrain_row(&row, &self.col_width, columns, wide);
+        let slice = self.template.as_slice();
+        let mut height = 0;
+        if let Some(ref last) = self.last {
+            let (out, col_width, count) = (&mut self.out, &self.col_width[..], self.count);
+            height += match slice.repeated_titles(count) {
+                Some(titles) => {
+                    slice.print_repeated_titles(out, &Plain, last, count, titles, col_width)?
+                }
+                None => slice.print_row_separator(out, &Plain, last, count, &row, col_width)?,
+            };
+        }
+        height += row.print(
+            &mut self.out,
+            &self.template.format,
+            &self.col_width,
+            &self.template.settings,
+        )?;
+        self.last = Some(row);
+        self.count += 1;
+        Ok(height)
+    }
+
+    /// Print the footer of the template and the bottom border, then return the output
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.template
+            .as_slice()
+            .print_footer(&mut self.out, &Plain, &self.col_width)?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TableWriter;
+    use crate::{row, Row, Table};
+
+    #[test]
+    fn write_rows() {
+        let mut template = Table::new();
+        template.set_titles(row!["name", "notes", "size"]);
+        template.set_footer(row!["total", "", "6"]);
+        template.set_column_hidden(1, true);
+        template.add_row(row!["a", "x", "1"]);
+        let mut writer = TableWriter::with_widths(Vec::new(), &template, &[4, 4]).unwrap();
+        let mut row = row!["bcdefg", "y", "2"];
+        row.set_separator_after(false);
+        assert_eq!(writer.write_row(row).unwrap(), 2);
+        assert_eq!(writer.write_row(row!["c", "z", "3"]).unwrap(), 1);
+        let mut hidden = Row::from(vec!["d"]);
+        hidden.set_hidden(true);
+        assert_eq!(writer.write_row(hidden).unwrap(), 0);
+        let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let expected = "\
++------+------+
+| name | size |
++======+======+
+| a    | 1    |
++------+------+
+| bcd… | 2    |
+| c    | 3    |
++======+======+
+| tot… | 6    |
++------+------+
+";
+        assert_eq!(out.replace("\r\n", "\n"), expected);
+    }
+
+    #[test]
+    fn repeat_titles() {
+        let mut template = Table::new();
+        template.set_titles(row!["n"]);
+        template.set_repeat_titles(Some(2));
+        let mut writer = TableWriter::with_widths(Vec::new(), &template, &[1]).unwrap();
+        for n in 1..=3 {
+            writer.write_row(row![n]).unwrap();
+        }
+        let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let expected = "\
++---+
+| n |
++===+
+| 1 |
++---+
+| 2 |
++---+
+| n |
++===+
+| 3 |
++---+
+";
+        assert_eq!(out.replace("\r\n", "\n"), expected);
+    }
+
+    #[test]
+    fn empty_template_keeps_empty_columns() {
+        let mut template = Table::new();
+        template.set_titles(row!["a", "b"]);
+        template.set_column_hide_if_empty(1, true);
+        let mut writer = TableWriter::with_widths(Vec::new(), &template, &[1, 1]).unwrap();
+        writer.write_row(row!["x", "y"]).unwrap();
+        let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let expected = "\
++---+---+
+| a | b |
++===+===+
+| x | y |
++---+---+
+";
+        assert_eq!(out.replace("\r\n", "\n"), expected);
+    }
+
+    #[test]
+    fn rendered_rows() {
+        let mut template = Table::new();
+        template.set_titles(row!["a", "b"]);
+        template.set_row_numbers(true);
+        template.set_right_to_left(true);
+        template.set_ascii_borders(true);
+        template.add_row(row!["x", "y"]);
+        let mut writer = TableWriter::with_widths(Vec::new(), &template, &[1, 1, 1]).unwrap();
+        writer.write_row(row!["z", "w"]).unwrap();
+        let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let expected = "\
++---+---+---+
+| b | a | # |
++===+===+===+
+| y | x | 1 |
++---+---+---+
+| w | z | 2 |
++---+---+---+
+";
+        assert_eq!(out.replace("\r\n", "\n"), expected);
+    }
+}